@@ -42,53 +42,27 @@ fn main() {
 
 #[cfg(target_os = "macos")]
 fn add_native_view(split_view: &dyn tauri_nssplitview::SplitView) {
-    // Use the re-exported types from tauri_nssplitview
-    use tauri_nssplitview::objc2;
-    use tauri_nssplitview::objc2::rc::Retained;
-    use tauri_nssplitview::objc2::runtime::AnyObject;
-    use tauri_nssplitview::objc2_app_kit::NSView;
     use tauri_nssplitview::objc2_foundation::{NSPoint, NSRect, NSSize};
+    use tauri_nssplitview::MainThreadMarker;
 
-    unsafe {
-        // Get the NSSplitView
-        let ns_split_view = split_view.as_split_view();
+    let mtm = MainThreadMarker::new().expect("setup runs on the main thread");
 
-        // Create a simple native NSView with purple background
-        let native_view: Retained<NSView> = {
-            let alloc: *mut AnyObject = objc2::msg_send![objc2::class!(NSView), alloc];
-            let frame = NSRect {
-                origin: NSPoint { x: 0.0, y: 0.0 },
-                size: NSSize {
-                    width: 400.0,
-                    height: 600.0,
-                },
-            };
-            let view: *mut AnyObject = objc2::msg_send![alloc, initWithFrame: frame];
-            Retained::retain(view as *mut NSView).unwrap()
-        };
+    // Create a simple native NSView with purple background (RGB: 0.4, 0.3, 0.6)
+    let frame = NSRect {
+        origin: NSPoint { x: 0.0, y: 0.0 },
+        size: NSSize {
+            width: 400.0,
+            height: 600.0,
+        },
+    };
+    let native_view = BasicSplitView::make_colored_view(mtm, frame, (0.4, 0.3, 0.6, 1.0));
 
-        // Set purple background using layer
-        let _: () = objc2::msg_send![&*native_view, setWantsLayer: true];
-        let layer: *mut AnyObject = objc2::msg_send![&*native_view, layer];
+    // Add it as a pane of the split view
+    split_view
+        .add_pane(&native_view)
+        .expect("Failed to add native pane");
 
-        // Create purple color (RGB: 0.4, 0.3, 0.6)
-        let color_space: *mut AnyObject =
-            objc2::msg_send![objc2::class!(NSColorSpace), deviceRGBColorSpace];
-        let components: [f64; 4] = [0.4, 0.3, 0.6, 1.0];
-        let color: *mut AnyObject = objc2::msg_send![
-            objc2::class!(NSColor),
-            colorWithColorSpace: color_space,
-            components: components.as_ptr(),
-            count: 4usize
-        ];
-        let cg_color: *mut AnyObject = objc2::msg_send![color, CGColor];
-        let _: () = objc2::msg_send![layer, setBackgroundColor: cg_color];
-
-        // Add it as a subview to the split view
-        let _: () = objc2::msg_send![ns_split_view, addSubview: &*native_view];
-
-        println!("  ✓ Added native macOS NSView with purple background");
-    }
+    println!("  ✓ Added native macOS NSView with purple background");
 }
 
 #[cfg(not(target_os = "macos"))]
@@ -1,8 +1,20 @@
 use std::sync::Arc;
 
+#[cfg(target_os = "macos")]
+use objc2::rc::Retained;
+#[cfg(target_os = "macos")]
+use objc2::MainThreadOnly;
+#[cfg(target_os = "macos")]
+use objc2_app_kit::{NSScreen, NSView, NSWindow};
+#[cfg(target_os = "macos")]
+use objc2_foundation::{MainThreadMarker, NSRect};
 use tauri::{AppHandle, Position, Runtime, Size, WebviewUrl, WebviewWindowBuilder};
 
-use crate::{FromWindow, SplitView, WebviewWindowExt};
+#[cfg(target_os = "macos")]
+use crate::splitview::pane_view_from_config;
+#[cfg(target_os = "macos")]
+use crate::{DividerStyle, WebviewWindowExt};
+use crate::{Error, FromWindow, SplitView};
 
 /// Type alias for window configuration function
 type WindowConfigFn<'a, R> = Box<
@@ -11,6 +23,14 @@ type WindowConfigFn<'a, R> = Box<
     ) -> WebviewWindowBuilder<'a, R, AppHandle<R>>,
 >;
 
+/// Type alias for a closure producing a native pane's view at build time
+#[cfg(target_os = "macos")]
+type NativePaneFn = Box<dyn FnOnce(MainThreadMarker) -> Retained<NSView>>;
+
+/// Type alias for a closure wrapping the window's original content view
+#[cfg(target_os = "macos")]
+type WrapContentFn = Box<dyn FnOnce(Retained<NSView>) -> Retained<NSView>>;
+
 /// Orientation for split views
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SplitViewOrientation {
@@ -34,20 +54,31 @@ impl Default for SplitViewOrientation {
 }
 
 /// Configuration for a pane in the split view
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum PaneConfig {
     /// A webview pane with a URL
     Webview { url: WebviewUrl },
-    /// A native NSView pane (placeholder for now)
+    /// An empty native NSView pane with `identifier` as its identifier, for the app to fill in
     Native { identifier: String },
 }
 
 /// Configuration for the split view
 #[derive(Default)]
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
 pub(crate) struct SplitViewConfig {
     pub orientation: Option<SplitViewOrientation>,
     pub divider_thickness: Option<f64>,
     pub panes: Vec<PaneConfig>,
+    pub initial_focus_pane: Option<usize>,
+    #[cfg(target_os = "macos")]
+    pub native_panes: Vec<NativePaneFn>,
+    pub max_panes: Option<usize>,
+    pub divider_color: Option<(f64, f64, f64, f64)>,
+    #[cfg(target_os = "macos")]
+    pub divider_style: Option<DividerStyle>,
+    pub original_content_pane_index: Option<usize>,
+    #[cfg(target_os = "macos")]
+    pub wrap_original_content: Option<WrapContentFn>,
 }
 
 /// Builder for creating split views with Tauri-like API
@@ -61,6 +92,7 @@ pub(crate) struct SplitViewConfig {
 ///
 /// # Example
 /// ```rust
+/// use objc2_app_kit::NSView;
 /// use tauri_nssplitview::{SplitViewBuilder, SplitViewOrientation, PaneConfig};
 /// use tauri::WebviewUrl;
 ///
@@ -69,17 +101,18 @@ pub(crate) struct SplitViewConfig {
 ///     .add_pane(PaneConfig::Webview {
 ///         url: WebviewUrl::App("left.html".into())
 ///     })
-///     .add_pane(PaneConfig::Webview {
-///         url: WebviewUrl::App("right.html".into())
-///     })
+///     .add_native_pane_with(|mtm| NSView::new(mtm))
 ///     .build()?;
 /// ```
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
 pub struct SplitViewBuilder<'a, R: Runtime, T: FromWindow<R> + 'static> {
     handle: &'a AppHandle<R>,
     label: String,
     title: Option<String>,
     position: Option<Position>,
     size: Option<Size>,
+    visible: Option<bool>,
+    clamp_to_screen: bool,
     pub(crate) split_view_config: SplitViewConfig,
     window_fn: Option<WindowConfigFn<'a, R>>,
     _phantom: std::marker::PhantomData<T>,
@@ -94,12 +127,45 @@ impl<'a, R: Runtime + 'a, T: FromWindow<R> + 'static> SplitViewBuilder<'a, R, T>
             title: None,
             position: None,
             size: None,
+            visible: None,
+            clamp_to_screen: false,
             split_view_config: SplitViewConfig::default(),
             window_fn: None,
             _phantom: std::marker::PhantomData,
         }
     }
 
+    /// Create a SplitViewBuilder from a layout spec
+    ///
+    /// The spec is an orientation, `v` or `h`, followed by panes in brackets separated
+    /// by `|`. Panes are `web:<url>` for webview panes, where the URL is either absolute
+    /// or a path to an app asset, and `native:<identifier>` for native panes.
+    ///
+    /// Each pane is added with [`add_pane`](Self::add_pane), so the spec's panes are built
+    /// in order when the split view is.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tauri_nssplitview::SplitViewBuilder;
+    ///
+    /// let split_view =
+    ///     SplitViewBuilder::from_spec(&app, "editor", "v[ web:left.html | web:right.html ]")?
+    ///         .title("Editor")
+    ///         .build()?;
+    /// ```
+    pub fn from_spec(
+        handle: &'a AppHandle<R>,
+        label: impl Into<String>,
+        spec: &str,
+    ) -> Result<Self, Error> {
+        let (orientation, panes) = parse_spec(spec)?;
+        let mut builder = Self::new(handle, label).orientation(orientation);
+        for pane in panes {
+            builder = builder.add_pane(pane);
+        }
+        Ok(builder)
+    }
+
     /// Set the window title
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
@@ -118,6 +184,24 @@ impl<'a, R: Runtime + 'a, T: FromWindow<R> + 'static> SplitViewBuilder<'a, R, T>
         self
     }
 
+    /// Set whether the window is shown when the split view is built
+    ///
+    /// With `false`, the window stays ordered out while the panes are added and laid
+    /// out, and appears without an empty frame when [`SplitView::show`] is called.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Move and shrink the window as needed to fit on its screen once built
+    ///
+    /// The frame is kept within the screen's visible frame, which excludes the menu
+    /// bar and Dock.
+    pub fn clamp_to_screen(mut self, clamp: bool) -> Self {
+        self.clamp_to_screen = clamp;
+        self
+    }
+
     /// Set the split view orientation (vertical or horizontal)
     pub fn orientation(mut self, orientation: SplitViewOrientation) -> Self {
         self.split_view_config.orientation = Some(orientation);
@@ -125,17 +209,111 @@ impl<'a, R: Runtime + 'a, T: FromWindow<R> + 'static> SplitViewBuilder<'a, R, T>
     }
 
     /// Add a pane to the split view
+    ///
+    /// If the first pane added is a webview pane, its URL is loaded as the window's
+    /// original content. Every other pane is created when the split view is built, after
+    /// the original content and in the order the panes were added: webview panes as new
+    /// webviews and native panes as empty views.
     pub fn add_pane(mut self, pane: PaneConfig) -> Self {
         self.split_view_config.panes.push(pane);
         self
     }
 
+    /// Add a native pane whose view is produced by `build` when the split view is built
+    ///
+    /// The closure runs on the main thread after the window has been converted, and the
+    /// returned view is added as a pane in the order the closures were registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use objc2_app_kit::NSView;
+    /// use tauri_nssplitview::SplitViewBuilder;
+    ///
+    /// SplitViewBuilder::new(&app, "my-splitview")
+    ///     .add_native_pane_with(|mtm| NSView::new(mtm))
+    ///     .build()?;
+    /// ```
+    #[cfg(target_os = "macos")]
+    pub fn add_native_pane_with(
+        mut self,
+        build: impl FnOnce(MainThreadMarker) -> Retained<NSView> + 'static,
+    ) -> Self {
+        self.split_view_config.native_panes.push(Box::new(build));
+        self
+    }
+
+    /// Limit the split view to `max` panes, including the window's original content
+    pub fn max_panes(mut self, max: usize) -> Self {
+        self.split_view_config.max_panes = Some(max);
+        self
+    }
+
     /// Set the divider thickness
     pub fn divider_thickness(mut self, thickness: f64) -> Self {
         self.split_view_config.divider_thickness = Some(thickness);
         self
     }
 
+    /// Draw the dividers in the given sRGB color
+    pub fn divider_color(mut self, red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        self.split_view_config.divider_color = Some((red, green, blue, alpha));
+        self
+    }
+
+    /// Set the visual style of the dividers
+    #[cfg(target_os = "macos")]
+    pub fn divider_style(mut self, style: DividerStyle) -> Self {
+        self.split_view_config.divider_style = Some(style);
+        self
+    }
+
+    /// Place the window's original content at `index` once the other panes are added
+    ///
+    /// The original content is pane 0 by default; panes added by the builder before
+    /// `index` end up in front of it. [`build`](Self::build) fails with
+    /// [`Error::InvalidPaneIndex`] before creating the window if `index` is past the last pane.
+    pub fn original_content_pane_index(mut self, index: usize) -> Self {
+        self.split_view_config.original_content_pane_index = Some(index);
+        self
+    }
+
+    /// Wrap the window's original content view before it becomes a pane
+    ///
+    /// The closure receives the original content view and returns the view to use as
+    /// pane 0 instead, such as a container adding insets or a background around it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use objc2_app_kit::NSView;
+    /// use tauri_nssplitview::{BasicSplitView, SplitViewBuilder};
+    ///
+    /// SplitViewBuilder::new(&app, "my-splitview")
+    ///     .wrap_original_content(|content| {
+    ///         let frame = content.frame();
+    ///         let container =
+    ///             BasicSplitView::make_colored_view(content.mtm(), frame, (0.1, 0.1, 0.1, 1.0));
+    ///         container.addSubview(&content);
+    ///         container
+    ///     })
+    ///     .build()?;
+    /// ```
+    #[cfg(target_os = "macos")]
+    pub fn wrap_original_content(
+        mut self,
+        f: impl FnOnce(Retained<NSView>) -> Retained<NSView> + 'static,
+    ) -> Self {
+        self.split_view_config.wrap_original_content = Some(Box::new(f));
+        self
+    }
+
+    /// Give keyboard focus to the pane at `index` once the split view is built
+    ///
+    /// An out-of-range index is ignored with a warning instead of failing the build.
+    pub fn initial_focus_pane(mut self, index: usize) -> Self {
+        self.split_view_config.initial_focus_pane = Some(index);
+        self
+    }
+
     /// Apply a custom configuration function to the WebviewWindowBuilder
     ///
     /// This allows access to any Tauri window configuration not exposed by the split view builder.
@@ -170,20 +348,21 @@ impl<'a, R: Runtime + 'a, T: FromWindow<R> + 'static> SplitViewBuilder<'a, R, T>
     ///
     /// Creates a Tauri window using the configured properties, converts it to
     /// a split view, and applies all split-view-specific settings.
+    #[cfg(target_os = "macos")]
     pub fn build(self) -> tauri::Result<Arc<dyn SplitView<R>>> {
-        // For now, create a basic window
-        // TODO: Implement actual NSSplitView creation
+        // A leading webview pane is the window's content and becomes pane 0
+        let mut panes = self.split_view_config.panes.into_iter().peekable();
+        let url = match panes.next_if(|pane| matches!(pane, PaneConfig::Webview { .. })) {
+            Some(PaneConfig::Webview { url }) => url,
+            _ => WebviewUrl::App("index.html".into()),
+        };
 
-        // Use the first pane's URL if available, otherwise use default
-        let url = self
-            .split_view_config
-            .panes
-            .first()
-            .and_then(|pane| match pane {
-                PaneConfig::Webview { url } => Some(url.clone()),
-                _ => None,
-            })
-            .unwrap_or(WebviewUrl::App("index.html".into()));
+        // Check the index before a window exists that would be left behind
+        if let Some(index) = self.split_view_config.original_content_pane_index {
+            if index > panes.len() + self.split_view_config.native_panes.len() {
+                return Err(Error::InvalidPaneIndex(index).into());
+            }
+        }
 
         let mut window_builder = WebviewWindowBuilder::new(self.handle, &self.label, url);
 
@@ -213,6 +392,10 @@ impl<'a, R: Runtime + 'a, T: FromWindow<R> + 'static> SplitViewBuilder<'a, R, T>
             }
         }
 
+        if let Some(visible) = self.visible {
+            window_builder = window_builder.visible(visible);
+        }
+
         // Apply custom configuration if provided
         if let Some(window_fn) = self.window_fn {
             window_builder = window_fn(window_builder);
@@ -221,14 +404,322 @@ impl<'a, R: Runtime + 'a, T: FromWindow<R> + 'static> SplitViewBuilder<'a, R, T>
         // Build the window
         let window = window_builder.build()?;
 
+        // Swap in the wrapper so it's the content view the conversion turns into pane 0
+        if let Some(wrap) = self.split_view_config.wrap_original_content {
+            let ns_window = window.ns_window()? as *mut objc2::runtime::AnyObject;
+            unsafe {
+                let content: Option<Retained<NSView>> = objc2::msg_send![ns_window, contentView];
+                if let Some(content) = content {
+                    let wrapper = wrap(content);
+                    let _: () = objc2::msg_send![ns_window, setContentView: &*wrapper];
+                }
+            }
+        }
+
         // Convert to split view
         let split_view = window.to_split_view::<T>()?;
 
-        // TODO: Apply split view configuration
-        // - Set orientation
-        // - Add panes
-        // - Configure dividers
+        split_view.set_max_panes(self.split_view_config.max_panes);
+
+        if let Some(orientation) = self.split_view_config.orientation {
+            split_view.set_orientation_animated(orientation, 0.0);
+        }
+        if let Some(thickness) = self.split_view_config.divider_thickness {
+            split_view.set_divider_thickness(thickness);
+        }
+        if let Some(style) = self.split_view_config.divider_style {
+            split_view.set_divider_style(style);
+        }
+        if let Some((red, green, blue, alpha)) = self.split_view_config.divider_color {
+            split_view.set_divider_color(red, green, blue, alpha);
+        }
+
+        let mtm = split_view.as_split_view().mtm();
+        for pane in panes {
+            match pane {
+                PaneConfig::Webview { url } => {
+                    split_view.add_webview_pane(url)?;
+                }
+                pane => {
+                    let view = pane_view_from_config(self.handle, &self.label, &pane, mtm)?;
+                    split_view.add_pane(&view)?;
+                }
+            }
+        }
+        for build_pane in self.split_view_config.native_panes {
+            let view = build_pane(mtm);
+            split_view.add_pane(&view)?;
+        }
+
+        if let Some(index) = self.split_view_config.original_content_pane_index {
+            split_view.move_pane(0, index)?;
+        }
+
+        if self.clamp_to_screen {
+            if let Some(window) = split_view.window() {
+                clamp_window_to_screen(&window);
+            }
+        }
+
+        if let Some(index) = self.split_view_config.initial_focus_pane {
+            if let Err(error) = split_view.focus_pane(index) {
+                log::warn!("Ignoring initial focus pane {}: {}", index, error);
+            }
+        }
 
         Ok(split_view)
     }
+
+    /// Build the split view
+    ///
+    /// Split views are only supported on macOS, so this always fails with
+    /// [`Error::UnsupportedPlatform`](crate::Error::UnsupportedPlatform) without
+    /// creating a window.
+    #[cfg(not(target_os = "macos"))]
+    pub fn build(self) -> tauri::Result<Arc<dyn SplitView<R>>> {
+        Err(crate::Error::UnsupportedPlatform.into())
+    }
+}
+
+/// Move and shrink `window` to fit within its screen's visible frame
+#[cfg(target_os = "macos")]
+fn clamp_window_to_screen(window: &NSWindow) {
+    unsafe {
+        // Windows that are entirely off-screen have no screen, so use the main screen
+        let screen: Option<Retained<NSScreen>> = objc2::msg_send![window, screen];
+        let screen = match screen {
+            Some(screen) => screen,
+            None => match NSScreen::mainScreen(window.mtm()) {
+                Some(screen) => screen,
+                None => return,
+            },
+        };
+
+        let visible: NSRect = objc2::msg_send![&*screen, visibleFrame];
+        let frame: NSRect = objc2::msg_send![window, frame];
+        let frame = clamp_frame(frame, visible);
+
+        let _: () = objc2::msg_send![window, setFrame: frame, display: true];
+    }
+}
+
+/// Move and shrink `frame` as needed to lie within `bounds`
+#[cfg(target_os = "macos")]
+fn clamp_frame(mut frame: NSRect, bounds: NSRect) -> NSRect {
+    frame.size.width = frame.size.width.min(bounds.size.width);
+    frame.size.height = frame.size.height.min(bounds.size.height);
+    frame.origin.x = frame.origin.x.clamp(
+        bounds.origin.x,
+        bounds.origin.x + bounds.size.width - frame.size.width,
+    );
+    frame.origin.y = frame.origin.y.clamp(
+        bounds.origin.y,
+        bounds.origin.y + bounds.size.height - frame.size.height,
+    );
+    frame
+}
+
+/// Parse a layout spec such as `v[ web:left.html | native:inspector ]`
+fn parse_spec(spec: &str) -> Result<(SplitViewOrientation, Vec<PaneConfig>), Error> {
+    let spec = spec.trim();
+
+    let orientation = match spec.chars().next() {
+        Some('v') => SplitViewOrientation::Vertical,
+        Some('h') => SplitViewOrientation::Horizontal,
+        Some(other) => {
+            return Err(Error::InvalidSpec(format!(
+                "expected orientation `v` or `h`, found `{}`",
+                other
+            )))
+        }
+        None => return Err(Error::InvalidSpec("spec is empty".into())),
+    };
+
+    let body = spec[1..]
+        .trim()
+        .strip_prefix('[')
+        .and_then(|body| body.strip_suffix(']'))
+        .ok_or_else(|| Error::InvalidSpec("expected panes enclosed in `[` and `]`".into()))?;
+
+    let panes = body
+        .split('|')
+        .map(|pane| {
+            let pane = pane.trim();
+            match pane.split_once(':') {
+                Some(("web", url)) if !url.trim().is_empty() => {
+                    let url = url.trim();
+                    let url = match url.parse::<tauri::Url>() {
+                        Ok(url) => WebviewUrl::External(url),
+                        Err(_) => WebviewUrl::App(url.into()),
+                    };
+                    Ok(PaneConfig::Webview { url })
+                }
+                Some(("native", identifier)) if !identifier.trim().is_empty() => {
+                    Ok(PaneConfig::Native {
+                        identifier: identifier.trim().to_string(),
+                    })
+                }
+                _ => Err(Error::InvalidSpec(format!(
+                    "expected `web:<url>` or `native:<identifier>`, found `{}`",
+                    pane
+                ))),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((orientation, panes))
+}
+
+#[cfg(test)]
+mod tests {
+    use tauri::WebviewUrl;
+
+    use super::{parse_spec, PaneConfig, SplitViewBuilder, SplitViewOrientation};
+    use crate::{BasicSplitView, Error};
+
+    fn app_pane(path: &str) -> PaneConfig {
+        PaneConfig::Webview {
+            url: WebviewUrl::App(path.into()),
+        }
+    }
+
+    #[test]
+    fn parses_orientation_and_panes() {
+        let (orientation, panes) =
+            parse_spec("v[ web:left.html | native:inspector | web:https://tauri.app ]").unwrap();
+
+        assert_eq!(orientation, SplitViewOrientation::Vertical);
+        assert_eq!(
+            panes,
+            [
+                app_pane("left.html"),
+                PaneConfig::Native {
+                    identifier: "inspector".into()
+                },
+                PaneConfig::Webview {
+                    url: WebviewUrl::External("https://tauri.app".parse().unwrap())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_surrounding_whitespace() {
+        let (orientation, panes) = parse_spec("  h [web:index.html]  ").unwrap();
+
+        assert_eq!(orientation, SplitViewOrientation::Horizontal);
+        assert_eq!(panes, [app_pane("index.html")]);
+    }
+
+    #[test]
+    fn rejects_malformed_specs() {
+        for spec in [
+            "",
+            "x[web:index.html]",
+            "v web:index.html",
+            "v[web:index.html",
+            "v[]",
+            "v[web:]",
+            "v[file:index.html]",
+            "v[web:left.html || web:right.html]",
+        ] {
+            assert!(
+                matches!(parse_spec(spec), Err(Error::InvalidSpec(_))),
+                "accepted `{}`",
+                spec
+            );
+        }
+    }
+
+    #[test]
+    fn from_spec_adds_every_pane() {
+        let app = tauri::test::mock_app();
+        let builder = SplitViewBuilder::<_, BasicSplitView<_>>::from_spec(
+            app.handle(),
+            "main",
+            "v[ web:left.html | web:right.html | native:inspector ]",
+        )
+        .unwrap();
+
+        let config = &builder.split_view_config;
+        assert_eq!(config.orientation, Some(SplitViewOrientation::Vertical));
+        assert_eq!(
+            config.panes,
+            [
+                app_pane("left.html"),
+                app_pane("right.html"),
+                PaneConfig::Native {
+                    identifier: "inspector".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn from_spec_rejects_malformed_specs() {
+        let app = tauri::test::mock_app();
+        for spec in ["v[web:a.html |]", "v(web:a.html)"] {
+            let result =
+                SplitViewBuilder::<_, BasicSplitView<_>>::from_spec(app.handle(), "main", spec);
+            assert!(
+                matches!(result, Err(Error::InvalidSpec(_))),
+                "accepted `{}`",
+                spec
+            );
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[test]
+    fn building_off_macos_is_unsupported() {
+        use tauri::Manager;
+
+        let app = tauri::test::mock_app();
+        let result = SplitViewBuilder::<_, BasicSplitView<_>>::new(app.handle(), "main").build();
+
+        assert!(result.is_err());
+        assert!(app.get_webview_window("main").is_none());
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn out_of_range_original_content_index_fails_before_creating_the_window() {
+        use tauri::Manager;
+
+        let app = tauri::test::mock_app();
+        let result = SplitViewBuilder::<_, BasicSplitView<_>>::new(app.handle(), "main")
+            .original_content_pane_index(1)
+            .build();
+
+        assert!(result.is_err());
+        assert!(app.get_webview_window("main").is_none());
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn clamped_frames_fit_within_the_bounds() {
+        use objc2_foundation::{NSPoint, NSRect, NSSize};
+
+        use super::clamp_frame;
+
+        let rect =
+            |x, y, width, height| NSRect::new(NSPoint::new(x, y), NSSize::new(width, height));
+        let visible = rect(0.0, 25.0, 1440.0, 850.0);
+
+        // Frames already on screen are left alone
+        let inside = rect(100.0, 100.0, 800.0, 600.0);
+        assert_eq!(clamp_frame(inside, visible), inside);
+
+        // Frames hanging off an edge move back on screen
+        assert_eq!(
+            clamp_frame(rect(1000.0, -50.0, 800.0, 600.0), visible),
+            rect(640.0, 25.0, 800.0, 600.0)
+        );
+
+        // Frames larger than the screen shrink to fit
+        assert_eq!(
+            clamp_frame(rect(-10.0, 0.0, 2000.0, 1000.0), visible),
+            visible
+        );
+    }
 }
@@ -0,0 +1,81 @@
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, ProtocolObject, Sel};
+use objc2::{define_class, msg_send, DefinedClass, MainThreadOnly};
+use objc2_app_kit::{NSWindow, NSWindowDelegate};
+use objc2_foundation::{MainThreadMarker, NSObject, NSObjectProtocol};
+
+pub(crate) struct CloseGuardDelegateIvars {
+    /// The window delegate that was installed before the guard
+    original: Option<Retained<ProtocolObject<dyn NSWindowDelegate>>>,
+    should_close: Box<dyn Fn() -> bool>,
+}
+
+define_class!(
+    /// Window delegate that consults a close guard and forwards everything else
+    #[unsafe(super(NSObject))]
+    #[name = "TauriNSSplitViewCloseGuard"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = CloseGuardDelegateIvars]
+    pub(crate) struct CloseGuardDelegate;
+
+    unsafe impl NSObjectProtocol for CloseGuardDelegate {}
+
+    impl CloseGuardDelegate {
+        #[unsafe(method(respondsToSelector:))]
+        fn responds_to_selector(&self, selector: Sel) -> bool {
+            let responds: bool = unsafe { msg_send![super(self), respondsToSelector: selector] };
+            responds
+                || self.ivars().original.as_ref().is_some_and(|original| unsafe {
+                    msg_send![&**original, respondsToSelector: selector]
+                })
+        }
+
+        #[unsafe(method(forwardingTargetForSelector:))]
+        fn forwarding_target_for_selector(&self, _selector: Sel) -> *mut AnyObject {
+            match &self.ivars().original {
+                Some(original) => Retained::as_ptr(original) as *mut AnyObject,
+                None => std::ptr::null_mut(),
+            }
+        }
+    }
+
+    unsafe impl NSWindowDelegate for CloseGuardDelegate {
+        #[unsafe(method(windowShouldClose:))]
+        fn window_should_close(&self, sender: &NSWindow) -> bool {
+            if !(self.ivars().should_close)() {
+                return false;
+            }
+
+            match &self.ivars().original {
+                Some(original) => unsafe {
+                    let responds: bool = msg_send![
+                        &**original,
+                        respondsToSelector: objc2::sel!(windowShouldClose:)
+                    ];
+                    !responds || msg_send![&**original, windowShouldClose: sender]
+                },
+                None => true,
+            }
+        }
+    }
+);
+
+impl CloseGuardDelegate {
+    /// Create a delegate that vetoes closing when `should_close` returns false
+    pub(crate) fn new(
+        mtm: MainThreadMarker,
+        original: Option<Retained<ProtocolObject<dyn NSWindowDelegate>>>,
+        should_close: Box<dyn Fn() -> bool>,
+    ) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(CloseGuardDelegateIvars {
+            original,
+            should_close,
+        });
+        unsafe { msg_send![super(this), init] }
+    }
+
+    /// Get the window delegate that was installed before the guard
+    pub(crate) fn original(&self) -> Option<Retained<ProtocolObject<dyn NSWindowDelegate>>> {
+        self.ivars().original.clone()
+    }
+}
@@ -0,0 +1,1177 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::time::Duration;
+
+use objc2::rc::{Retained, Weak};
+use objc2::runtime::{AnyObject, ProtocolObject};
+use objc2::{ClassType, MainThreadOnly};
+use objc2_app_kit::{
+    NSSplitView, NSSplitViewController, NSSplitViewDidResizeSubviewsNotification, NSSplitViewItem,
+    NSView, NSViewController, NSWindow, NSWindowCollectionBehavior, NSWindowDelegate,
+};
+use objc2_foundation::{NSArray, NSNotificationCenter, NSPoint, NSRect, NSSize};
+use tauri::{AppHandle, Manager, Runtime, WebviewUrl, WebviewWindow};
+
+use crate::delegate::divider_span;
+use crate::main_thread::check_main_thread;
+use crate::pane_frame_observer::PaneFrameObserver;
+use crate::splitview::{
+    catch_objc_exception, check_hierarchy, fitting_extent, non_nil, panes_sized,
+    prepare_hosting_view, restore_collapsed, take_webview_view,
+};
+use crate::tauri_split_view::TauriSplitView;
+use crate::trace::trace_operation;
+use crate::{
+    AnimationSuppression, BasicSplitView, Density, DividerInfo, DividerPosition, DividerStyle,
+    Edge, Error, FromWindow, ManagerExt, PaneConfig, PaneConstraints, PaneKind, PanePresentation,
+    ResizeMode, SplitView, SplitViewEvent, SplitViewHandle, SplitViewOrientation,
+    SplitViewSnapshot,
+};
+
+/// Behavior of an item in a [`ControllerSplitView`]
+///
+/// Maps to `NSSplitViewItemBehavior`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SplitViewItemBehavior {
+    /// A regular pane
+    #[default]
+    Default,
+    /// A sidebar with native collapse animations and vibrancy
+    Sidebar,
+    /// A content list, such as a list of messages next to a sidebar
+    ContentList,
+}
+
+/// A split view backed by `NSSplitViewController` and `NSSplitViewItem`
+///
+/// Each pane is owned by a split view item, which gives access to AppKit's
+/// per-item collapse behavior and sidebar integration. The controller acts as
+/// the split view's delegate, so delegate-based features like
+/// [`SplitView::set_resize_mode`] are not available.
+pub struct ControllerSplitView<R: Runtime = tauri::Wry> {
+    controller: Retained<NSSplitViewController>,
+    inner: BasicSplitView<R>,
+}
+
+// SAFETY: See the Send + Sync note on BasicSplitView. The same main thread
+// requirements apply to the split view controller.
+unsafe impl<R: Runtime> Send for ControllerSplitView<R> {}
+unsafe impl<R: Runtime> Sync for ControllerSplitView<R> {}
+
+/// Create a split view item whose view controller manages `view`
+fn make_split_view_item(view: &NSView) -> Retained<NSSplitViewItem> {
+    unsafe {
+        let view_controller: Retained<NSViewController> =
+            objc2::msg_send![NSViewController::alloc(view.mtm()), init];
+        let _: () = objc2::msg_send![&*view_controller, setView: view];
+
+        objc2::msg_send![
+            NSSplitViewItem::class(),
+            splitViewItemWithViewController: &*view_controller
+        ]
+    }
+}
+
+/// Get the behavior an item was created with
+fn item_behavior(item: &NSSplitViewItem) -> SplitViewItemBehavior {
+    let behavior: isize = unsafe { objc2::msg_send![item, behavior] };
+
+    match behavior {
+        1 => SplitViewItemBehavior::Sidebar,
+        2 => SplitViewItemBehavior::ContentList,
+        _ => SplitViewItemBehavior::Default,
+    }
+}
+
+/// Create a split view item with `behavior` for `view_controller`
+fn split_view_item_with_behavior(
+    view_controller: &NSViewController,
+    behavior: SplitViewItemBehavior,
+) -> Result<Retained<NSSplitViewItem>, Error> {
+    let class = NSSplitViewItem::class();
+    let item: Option<Retained<NSSplitViewItem>> = unsafe {
+        match behavior {
+            SplitViewItemBehavior::Default => {
+                objc2::msg_send![class, splitViewItemWithViewController: view_controller]
+            }
+            SplitViewItemBehavior::Sidebar => {
+                objc2::msg_send![class, sidebarWithViewController: view_controller]
+            }
+            SplitViewItemBehavior::ContentList => {
+                objc2::msg_send![class, contentListWithViewController: view_controller]
+            }
+        }
+    };
+    non_nil(item, "splitViewItemWithViewController:")
+}
+
+impl<R: Runtime> ControllerSplitView<R> {
+    /// Get a reference to the underlying NSSplitViewController
+    pub fn as_split_view_controller(&self) -> &NSSplitViewController {
+        check_main_thread("as_split_view_controller");
+        &self.controller
+    }
+
+    /// Run a closure with animated changes applied instantly
+    ///
+    /// Useful when restoring a saved layout, where animating each change would flicker.
+    /// Calls can be nested, and animations resume even if the closure panics.
+    pub fn without_animations<T>(&self, f: impl FnOnce(&Self) -> T) -> T {
+        self.inner.without_animations(|_| f(self))
+    }
+
+    /// Get the split view item managing the pane at `index`
+    pub fn split_view_item(&self, index: usize) -> Option<Retained<NSSplitViewItem>> {
+        check_main_thread("split_view_item");
+        unsafe {
+            let items: Retained<NSArray<NSSplitViewItem>> =
+                objc2::msg_send![&*self.controller, splitViewItems];
+            let count: usize = objc2::msg_send![&*items, count];
+
+            if index < count {
+                Some(objc2::msg_send![&*items, objectAtIndex: index])
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Get the behavior of the item at `index`
+    pub fn item_behavior(&self, index: usize) -> Option<SplitViewItemBehavior> {
+        let item = self.split_view_item(index)?;
+        Some(item_behavior(&item))
+    }
+
+    /// Set the behavior of the item at `index`
+    ///
+    /// AppKit only assigns a behavior when an item is created, so this replaces the
+    /// item with a new one for the same view controller. Item settings such as
+    /// minimum thickness are reset to the defaults for the new behavior.
+    pub fn set_item_behavior(
+        &self,
+        index: usize,
+        behavior: SplitViewItemBehavior,
+    ) -> Result<(), Error> {
+        trace_operation!("set_item_behavior", self.label(), index, behavior);
+        let item = self
+            .split_view_item(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        unsafe {
+            let view_controller: Retained<NSViewController> =
+                objc2::msg_send![&*item, viewController];
+            let replacement = split_view_item_with_behavior(&view_controller, behavior)?;
+
+            let _: () = objc2::msg_send![&*self.controller, removeSplitViewItem: &*item];
+            let _: () = objc2::msg_send![
+                &*self.controller,
+                insertSplitViewItem: &*replacement,
+                atIndex: index as isize
+            ];
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: Runtime> SplitView<R> for ControllerSplitView<R> {
+    fn show(&self) {
+        self.inner.show()
+    }
+
+    fn hide(&self) {
+        self.inner.hide()
+    }
+
+    fn to_window(&self) -> Option<WebviewWindow<R>> {
+        self.inner.to_window()
+    }
+
+    fn app_handle(&self) -> AppHandle<R> {
+        self.inner.app_handle()
+    }
+
+    fn as_split_view(&self) -> &NSSplitView {
+        self.inner.as_split_view()
+    }
+
+    fn label(&self) -> &str {
+        self.inner.label()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn set_event_handler(&self, handler: Option<&ProtocolObject<dyn NSWindowDelegate>>) {
+        self.inner.set_event_handler(handler)
+    }
+
+    fn events(&self) -> Receiver<SplitViewEvent> {
+        // The controller is the split view's delegate, so divider moves and collapses
+        // aren't reported
+        self.inner.subscribe()
+    }
+
+    fn emit_pane_geometry_events(&self, event_name: &str) {
+        // The controller is the split view's delegate, so layout passes aren't reported
+        log::warn!(
+            "Ignoring pane geometry event `{}` for controller split view `{}`",
+            event_name,
+            self.label()
+        );
+    }
+
+    fn set_user_data(&self, data: Box<dyn Any + Send + Sync>) {
+        self.inner.set_user_data(data)
+    }
+
+    fn user_data(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.inner.user_data()
+    }
+
+    fn is_visible(&self) -> bool {
+        self.inner.is_visible()
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.inner.is_vertical()
+    }
+
+    fn pane_count(&self) -> usize {
+        self.inner.pane_count()
+    }
+
+    fn tracked_pane_count(&self) -> usize {
+        self.inner.tracked_pane_count()
+    }
+
+    fn is_content_view(&self) -> bool {
+        // The window's content view is the controller's view, which is the split view
+        self.inner.is_content_view()
+    }
+
+    fn describe_hierarchy(&self) -> String {
+        self.inner.describe_hierarchy()
+    }
+
+    fn set_divider_position(&self, divider_index: usize, position: f64) -> f64 {
+        self.inner.set_divider_position(divider_index, position)
+    }
+
+    fn set_divider(&self, divider_index: usize, position: DividerPosition) {
+        self.inner.set_divider(divider_index, position)
+    }
+
+    fn get_divider_position(&self, divider_index: usize) -> f64 {
+        self.inner.get_divider_position(divider_index)
+    }
+
+    fn set_cursor_region_padding(&self, _px: f64) {
+        // The controller is the split view's delegate and owns the divider's effective rect
+        log::warn!(
+            "Ignoring cursor region padding for controller split view `{}`",
+            self.label()
+        );
+    }
+
+    fn set_divider_snap_positions(
+        &self,
+        divider_index: usize,
+        _positions: Vec<f64>,
+        _snap_threshold: f64,
+    ) {
+        // The controller is the split view's delegate and owns divider constraints
+        log::warn!(
+            "Ignoring snap positions for divider {} of controller split view `{}`",
+            divider_index,
+            self.label()
+        );
+    }
+
+    fn divider_drag_range(&self, divider_index: usize) -> Option<(f64, f64)> {
+        check_main_thread("divider_drag_range");
+        let (start, end) = divider_span(self.as_split_view(), divider_index)?;
+        let leading = self.split_view_item(divider_index)?;
+        let trailing = self.split_view_item(divider_index + 1)?;
+
+        // Items constrain their thickness rather than divider positions, and collapsible
+        // items can be dragged down to nothing
+        unsafe {
+            let thickness = |item: &NSSplitViewItem| -> (f64, Option<f64>) {
+                let collapses: bool = objc2::msg_send![item, canCollapse];
+                let min: f64 = objc2::msg_send![item, minimumThickness];
+                let max: f64 = objc2::msg_send![item, maximumThickness];
+                let min = if collapses { 0.0 } else { min.max(0.0) };
+                (min, (max >= 0.0).then_some(max))
+            };
+            let (leading_min, leading_max) = thickness(&leading);
+            let (trailing_min, trailing_max) = thickness(&trailing);
+
+            let min = (start + leading_min).max(trailing_max.map_or(start, |max| end - max));
+            let max = (end - trailing_min).min(leading_max.map_or(end, |max| start + max));
+            Some((min, max))
+        }
+    }
+
+    fn divider_info(&self) -> Vec<DividerInfo> {
+        self.inner.divider_info()
+    }
+
+    fn active_divider(&self) -> Option<usize> {
+        self.inner.active_divider()
+    }
+
+    fn set_user_interface_layout_direction(&self, rtl: bool) {
+        self.inner.set_user_interface_layout_direction(rtl)
+    }
+
+    fn set_dividers_visible(&self, visible: bool) {
+        self.inner.set_dividers_visible(visible)
+    }
+
+    fn dividers_visible(&self) -> bool {
+        self.inner.dividers_visible()
+    }
+
+    fn clear_tracking_areas(&self) {
+        self.inner.clear_tracking_areas()
+    }
+
+    fn set_divider_style(&self, style: DividerStyle) {
+        self.inner.set_divider_style(style)
+    }
+
+    fn divider_style(&self) -> DividerStyle {
+        self.inner.divider_style()
+    }
+
+    fn set_divider_color(&self, red: f64, green: f64, blue: f64, alpha: f64) {
+        self.inner.set_divider_color(red, green, blue, alpha)
+    }
+
+    fn divider_color(&self) -> Option<(f64, f64, f64, f64)> {
+        self.inner.divider_color()
+    }
+
+    fn set_divider_image(&self, divider_index: usize, image_bytes: &[u8]) -> Result<(), Error> {
+        self.inner.set_divider_image(divider_index, image_bytes)
+    }
+
+    fn clear_divider_image(&self, divider_index: usize) {
+        self.inner.clear_divider_image(divider_index)
+    }
+
+    fn set_divider_keyboard_resizable(&self, divider_index: usize, step: f64) {
+        self.inner
+            .set_divider_keyboard_resizable(divider_index, step)
+    }
+
+    fn set_divider_thickness(&self, thickness: f64) {
+        self.inner.set_divider_thickness(thickness)
+    }
+
+    fn set_density(&self, density: Density) {
+        self.inner.set_density(density)
+    }
+
+    fn divider_thickness(&self) -> f64 {
+        self.inner.divider_thickness()
+    }
+
+    fn add_pane(&self, view: &NSView) -> Result<usize, Error> {
+        trace_operation!("add_pane", self.label(), view);
+        check_hierarchy(self.as_split_view(), view)?;
+        self.inner.check_pane_capacity(1)?;
+
+        let item = make_split_view_item(view);
+        catch_objc_exception(|| unsafe {
+            let _: () = objc2::msg_send![&*self.controller, addSplitViewItem: &*item];
+        })?;
+        self.inner.track_native_pane(view);
+        self.inner.panes_did_change();
+
+        let index = self.pane_count() - 1;
+        self.inner.emit(SplitViewEvent::PaneAdded(index));
+        Ok(index)
+    }
+
+    fn add_panes(&self, views: Vec<Retained<NSView>>) -> Result<Vec<usize>, Error> {
+        trace_operation!("add_panes", self.label(), views);
+        for view in &views {
+            check_hierarchy(self.as_split_view(), view)?;
+        }
+        self.inner.check_pane_capacity(views.len())?;
+
+        // The controller lays out its items once per run loop pass, so adding them in turn is fine
+        views.iter().map(|view| self.add_pane(view)).collect()
+    }
+
+    fn pane_at_index(&self, index: usize) -> Option<Retained<NSView>> {
+        self.inner.pane_at_index(index)
+    }
+
+    fn set_pane_tag(&self, index: usize, tag: &str) -> Result<(), Error> {
+        self.inner.set_pane_tag(index, tag)
+    }
+
+    fn pane_index_for_tag(&self, tag: &str) -> Option<usize> {
+        self.inner.pane_index_for_tag(tag)
+    }
+
+    fn pane_view_for_tag(&self, tag: &str) -> Option<Retained<NSView>> {
+        self.inner.pane_view_for_tag(tag)
+    }
+
+    fn add_webview_pane(&self, url: WebviewUrl) -> tauri::Result<usize> {
+        trace_operation!("add_webview_pane", self.label(), url);
+        let label = self.inner.next_webview_pane_label();
+        let view = take_webview_view(&self.app_handle(), &label, url)?;
+        let index = self.add_pane(&view)?;
+        self.inner.track_webview_pane(view, label);
+
+        Ok(index)
+    }
+
+    fn webview_pane_label(&self, index: usize) -> Option<String> {
+        self.inner.webview_pane_label(index)
+    }
+
+    fn webview_panes(&self) -> Vec<(usize, WebviewWindow<R>)> {
+        self.inner.webview_panes()
+    }
+
+    fn reload_all_webview_panes(&self) {
+        self.inner.reload_all_webview_panes()
+    }
+
+    fn replace_pane_view(&self, index: usize, view: &NSView) -> Result<(), Error> {
+        trace_operation!("replace_pane_view", self.label(), index, view);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        let item = self
+            .split_view_item(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        check_hierarchy(self.as_split_view(), view)?;
+
+        // Items can't change their view, so the swap replaces the item with one that
+        // has the same behavior
+        let controller = self.controller.clone();
+        let view = view.retain();
+        self.inner.queue_pane_change(
+            pane,
+            Box::new(move || unsafe {
+                // The pane may have been removed while the swap was pending
+                let items: Retained<NSArray<NSSplitViewItem>> =
+                    objc2::msg_send![&*controller, splitViewItems];
+                let count: usize = objc2::msg_send![&*items, count];
+                let Some(index) = (0..count).find(|&i| {
+                    let candidate: Retained<NSSplitViewItem> =
+                        objc2::msg_send![&*items, objectAtIndex: i];
+                    candidate == item
+                }) else {
+                    return;
+                };
+
+                let view_controller: Retained<NSViewController> =
+                    objc2::msg_send![NSViewController::alloc(view.mtm()), init];
+                let _: () = objc2::msg_send![&*view_controller, setView: &*view];
+                match split_view_item_with_behavior(&view_controller, item_behavior(&item)) {
+                    Ok(replacement) => {
+                        let _: () = objc2::msg_send![&*controller, removeSplitViewItem: &*item];
+                        let _: () = objc2::msg_send![
+                            &*controller,
+                            insertSplitViewItem: &*replacement,
+                            atIndex: index as isize
+                        ];
+                    }
+                    Err(error) => log::warn!("Failed to replace pane {}: {}", index, error),
+                }
+            }),
+        );
+        Ok(())
+    }
+
+    fn navigate_pane(&self, index: usize, url: tauri::Url) -> Result<(), Error> {
+        self.inner.navigate_pane(index, url)
+    }
+
+    fn pane_kind(&self, index: usize) -> Option<PaneKind> {
+        self.inner.pane_kind(index)
+    }
+
+    fn add_pane_deferred(&self, view: &NSView) -> Result<usize, Error> {
+        // The controller already defers laying out its items to the next run loop pass
+        self.add_pane(view)
+    }
+
+    fn commit_layout(&self) {
+        self.inner.commit_layout()
+    }
+
+    fn add_hosting_pane(&self, hosting_view: Retained<NSView>) -> Result<usize, Error> {
+        trace_operation!("add_hosting_pane", self.label(), hosting_view);
+        prepare_hosting_view(&hosting_view)?;
+        self.add_pane(&hosting_view)
+    }
+
+    fn set_max_panes(&self, max: Option<usize>) {
+        self.inner.set_max_panes(max)
+    }
+
+    fn move_pane(&self, from: usize, to: usize) -> Result<(), Error> {
+        trace_operation!("move_pane", self.label(), from, to);
+        let item = self
+            .split_view_item(from)
+            .ok_or(Error::InvalidPaneIndex(from))?;
+        if to >= self.pane_count() {
+            return Err(Error::InvalidPaneIndex(to));
+        }
+
+        catch_objc_exception(|| unsafe {
+            let _: () = objc2::msg_send![&*self.controller, removeSplitViewItem: &*item];
+            let _: () = objc2::msg_send![
+                &*self.controller,
+                insertSplitViewItem: &*item,
+                atIndex: to as isize
+            ];
+        })?;
+        self.inner.panes_did_change();
+        self.inner.emit(SplitViewEvent::PaneMoved { from, to });
+        Ok(())
+    }
+
+    fn bring_pane_to_front(&self, index: usize) -> Result<(), Error> {
+        self.inner.bring_pane_to_front(index)
+    }
+
+    fn send_pane_to_back(&self, index: usize) -> Result<(), Error> {
+        self.inner.send_pane_to_back(index)
+    }
+
+    fn set_pane_presentation(&self, _index: usize, _mode: PanePresentation) -> Result<(), Error> {
+        // The controller owns the arranged subviews; use a sidebar item for overlay behavior
+        Err(Error::Unsupported("set_pane_presentation"))
+    }
+
+    fn pane_presentation(&self, index: usize) -> Option<PanePresentation> {
+        self.pane_at_index(index).map(|_| PanePresentation::Inline)
+    }
+
+    fn set_pane_edge_accessory(
+        &self,
+        index: usize,
+        edge: Edge,
+        view: Retained<NSView>,
+        thickness: f64,
+    ) -> Result<(), Error> {
+        self.inner
+            .set_pane_edge_accessory(index, edge, view, thickness)
+    }
+
+    fn clear_pane_edge_accessory(&self, index: usize, edge: Edge) -> Result<(), Error> {
+        self.inner.clear_pane_edge_accessory(index, edge)
+    }
+
+    fn on_pane_click(
+        &self,
+        index: usize,
+        callback: Box<dyn Fn(NSPoint) + Send + 'static>,
+    ) -> Result<(), Error> {
+        self.inner.on_pane_click(index, callback)
+    }
+
+    fn clear_pane_click(&self, index: usize) -> Result<(), Error> {
+        self.inner.clear_pane_click(index)
+    }
+
+    fn on_pane_hover(
+        &self,
+        index: usize,
+        callback: Box<dyn Fn(bool) + Send + 'static>,
+    ) -> Result<(), Error> {
+        self.inner.on_pane_hover(index, callback)
+    }
+
+    fn set_pane_tooltip(&self, index: usize, text: &str) -> Result<(), Error> {
+        self.inner.set_pane_tooltip(index, text)
+    }
+
+    fn pane_tooltip(&self, index: usize) -> Option<String> {
+        self.inner.pane_tooltip(index)
+    }
+
+    fn observe_pane_content_size(
+        &self,
+        index: usize,
+        callback: Box<dyn Fn(NSSize) + Send + 'static>,
+    ) -> Result<(), Error> {
+        self.inner.observe_pane_content_size(index, callback)
+    }
+
+    fn animate_pane_zoom(
+        &self,
+        index: usize,
+        from_scale: f64,
+        to_scale: f64,
+        duration_secs: f64,
+    ) -> Result<(), Error> {
+        self.inner
+            .animate_pane_zoom(index, from_scale, to_scale, duration_secs)
+    }
+
+    fn freeze_pane(&self, index: usize) -> Result<(), Error> {
+        self.inner.freeze_pane(index)
+    }
+
+    fn unfreeze_pane(&self, index: usize) -> Result<(), Error> {
+        self.inner.unfreeze_pane(index)
+    }
+
+    fn add_mirror_pane(&self, source_index: usize) -> Result<usize, Error> {
+        trace_operation!("add_mirror_pane", self.label(), source_index);
+        let source = self
+            .pane_at_index(source_index)
+            .ok_or(Error::InvalidPaneIndex(source_index))?;
+        let mirror = self.inner.mirror_view(&source)?;
+        self.add_pane(&mirror)
+    }
+
+    fn add_region_split_view(
+        &self,
+        index: usize,
+        label: &str,
+        region: NSRect,
+        orientation: SplitViewOrientation,
+    ) -> Result<SplitViewHandle<R>, Error> {
+        self.inner
+            .add_region_split_view(index, label, region, orientation)
+    }
+
+    fn split_pane_preserving_webview(
+        &self,
+        _index: usize,
+        _orientation: SplitViewOrientation,
+        _new_pane: PaneConfig,
+    ) -> Result<SplitViewHandle<R>, Error> {
+        // Panes are the views of split view items, which can't be swapped for a nested split view
+        Err(Error::Unsupported("split_pane_preserving_webview"))
+    }
+
+    fn set_pane_collapsible(&self, index: usize, collapsible: bool) {
+        trace_operation!("set_pane_collapsible", self.label(), index, collapsible);
+        if let Some(item) = self.split_view_item(index) {
+            unsafe {
+                let _: () = objc2::msg_send![&*item, setCanCollapse: collapsible];
+            }
+        }
+    }
+
+    fn is_pane_collapsed(&self, index: usize) -> bool {
+        check_main_thread("is_pane_collapsed");
+        match self.split_view_item(index) {
+            Some(item) => unsafe { objc2::msg_send![&*item, isCollapsed] },
+            None => false,
+        }
+    }
+
+    fn collapse_pane(&self, index: usize) -> Result<(), Error> {
+        trace_operation!("collapse_pane", self.label(), index);
+        let item = self
+            .split_view_item(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        unsafe {
+            let _: () = objc2::msg_send![&*item, setCollapsed: true];
+        }
+        Ok(())
+    }
+
+    fn expand_pane(&self, index: usize) -> Result<(), Error> {
+        trace_operation!("expand_pane", self.label(), index);
+        let item = self
+            .split_view_item(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        unsafe {
+            let _: () = objc2::msg_send![&*item, setCollapsed: false];
+        }
+        Ok(())
+    }
+
+    fn ensure_pane_visible(
+        &self,
+        index: usize,
+        min_extent: f64,
+        animated: bool,
+    ) -> Result<(), Error> {
+        self.inner.ensure_pane_visible(index, min_extent, animated)
+    }
+
+    fn toggle_sidebar(&self, sidebar_index: usize) -> Result<(), Error> {
+        trace_operation!("toggle_sidebar", self.label(), sidebar_index);
+        let item = self
+            .split_view_item(sidebar_index)
+            .ok_or(Error::InvalidPaneIndex(sidebar_index))?;
+
+        unsafe {
+            let collapsed: bool = objc2::msg_send![&*item, isCollapsed];
+            // Split view items animate collapsing through their animator with the
+            // standard sidebar behaviour
+            if self.inner.animations_enabled() {
+                let animator: Retained<AnyObject> = objc2::msg_send![&*item, animator];
+                let _: () = objc2::msg_send![&*animator, setCollapsed: !collapsed];
+            } else {
+                let _: () = objc2::msg_send![&*item, setCollapsed: !collapsed];
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_pane_constraints(
+        &self,
+        index: usize,
+        constraints: PaneConstraints,
+    ) -> Result<(), Error> {
+        trace_operation!("apply_pane_constraints", self.label(), index, constraints);
+        let item = self
+            .split_view_item(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        if let Some(tag) = &constraints.tag {
+            self.set_pane_tag(index, tag)?;
+        }
+        if let Some(min) = constraints.min {
+            self.set_pane_min_size(index, min);
+        }
+        if let Some(max) = constraints.max {
+            self.set_pane_max_size(index, max);
+        }
+        if let Some(collapsible) = constraints.collapsible {
+            self.set_pane_collapsible(index, collapsible);
+        }
+        if let Some(priority) = constraints.holding_priority {
+            unsafe {
+                let _: () = objc2::msg_send![&*item, setHoldingPriority: priority];
+            }
+        }
+        Ok(())
+    }
+
+    fn set_pane_collapse_header(
+        &self,
+        _index: usize,
+        _header_view: Retained<NSView>,
+        _height: f64,
+    ) -> Result<(), Error> {
+        // Split view items collapse completely; use a sidebar item's minimum thickness instead
+        Err(Error::Unsupported("set_pane_collapse_header"))
+    }
+
+    fn set_pane_min_size(&self, index: usize, size: f64) {
+        trace_operation!("set_pane_min_size", self.label(), index, size);
+        if let Some(item) = self.split_view_item(index) {
+            unsafe {
+                let _: () = objc2::msg_send![&*item, setMinimumThickness: size];
+            }
+        }
+    }
+
+    fn set_pane_max_size(&self, index: usize, size: f64) {
+        trace_operation!("set_pane_max_size", self.label(), index, size);
+        if let Some(item) = self.split_view_item(index) {
+            unsafe {
+                let _: () = objc2::msg_send![&*item, setMaximumThickness: size];
+            }
+        }
+    }
+
+    fn set_pane_min_aspect(&self, index: usize, _min_ratio: f64) {
+        // The controller is the split view's delegate and owns divider constraints
+        log::warn!(
+            "Ignoring minimum aspect ratio for pane {} of controller split view `{}`",
+            index,
+            self.label()
+        );
+    }
+
+    fn set_pane_max_aspect(&self, index: usize, _max_ratio: f64) {
+        // The controller is the split view's delegate and owns divider constraints
+        log::warn!(
+            "Ignoring maximum aspect ratio for pane {} of controller split view `{}`",
+            index,
+            self.label()
+        );
+    }
+
+    fn set_pane_pixel_aligned(&self, index: usize, _aligned: bool) {
+        // The controller is the split view's delegate and owns divider constraints
+        log::warn!(
+            "Ignoring pixel alignment for pane {} of controller split view `{}`",
+            index,
+            self.label()
+        );
+    }
+
+    fn minimum_content_extent(&self) -> f64 {
+        check_main_thread("minimum_content_extent");
+        let count = self.pane_count();
+        let panes: f64 = (0..count)
+            .filter_map(|index| self.split_view_item(index))
+            .map(|item| {
+                let thickness: f64 = unsafe { objc2::msg_send![&*item, minimumThickness] };
+                // NSSplitViewItemUnspecifiedDimension is negative
+                thickness.max(0.0)
+            })
+            .sum();
+
+        panes + self.divider_thickness() * count.saturating_sub(1) as f64
+    }
+
+    fn maximum_content_extent(&self) -> Option<f64> {
+        check_main_thread("maximum_content_extent");
+        let count = self.pane_count();
+        let panes = (0..count)
+            .map(|index| {
+                let item = self.split_view_item(index)?;
+                let thickness: f64 = unsafe { objc2::msg_send![&*item, maximumThickness] };
+                // NSSplitViewItemUnspecifiedDimension is negative
+                (thickness >= 0.0).then_some(thickness)
+            })
+            .sum::<Option<f64>>()?;
+
+        Some(panes + self.divider_thickness() * count.saturating_sub(1) as f64)
+    }
+
+    fn recommended_pane_width(&self, index: usize) -> Option<f64> {
+        check_main_thread("recommended_pane_width");
+        let pane = self.pane_at_index(index)?;
+        let mut extent = fitting_extent(&pane, self.is_vertical())?;
+        if let Some(item) = self.split_view_item(index) {
+            let (min, max): (f64, f64) = unsafe {
+                (
+                    objc2::msg_send![&*item, minimumThickness],
+                    objc2::msg_send![&*item, maximumThickness],
+                )
+            };
+            // NSSplitViewItemUnspecifiedDimension is negative
+            if min >= 0.0 {
+                extent = extent.max(min);
+            }
+            if max >= 0.0 {
+                extent = extent.min(max);
+            }
+        }
+        Some(extent)
+    }
+
+    fn set_pane_ignores_intrinsic_size(&self, index: usize, ignore: bool) -> Result<(), Error> {
+        self.inner.set_pane_ignores_intrinsic_size(index, ignore)
+    }
+
+    fn focus_pane(&self, index: usize) -> Result<(), Error> {
+        self.inner.focus_pane(index)
+    }
+
+    fn focused_pane_index(&self) -> Option<usize> {
+        self.inner.focused_pane_index()
+    }
+
+    fn is_pane_focused(&self, index: usize) -> bool {
+        self.inner.is_pane_focused(index)
+    }
+
+    fn set_accepts_first_responder(&self, accepts: bool) {
+        self.inner.set_accepts_first_responder(accepts)
+    }
+
+    fn window(&self) -> Option<Retained<NSWindow>> {
+        self.inner.window()
+    }
+
+    fn set_window_aspect_ratio_from_panes(&self, enabled: bool) {
+        self.inner.set_window_aspect_ratio_from_panes(enabled)
+    }
+
+    fn set_close_guard(&self, guard: Box<dyn Fn(&dyn SplitView<R>) -> bool + Send + 'static>) {
+        self.inner.set_close_guard(guard)
+    }
+
+    fn traffic_light_inset(&self) -> f64 {
+        self.inner.traffic_light_inset()
+    }
+
+    fn apply_traffic_light_inset_to_pane(&self, index: usize) -> Result<(), Error> {
+        self.inner.apply_traffic_light_inset_to_pane(index)
+    }
+
+    fn set_full_height_content(&self, enabled: bool) {
+        self.inner.set_full_height_content(enabled)
+    }
+
+    fn zoom(&self) {
+        self.inner.zoom()
+    }
+
+    fn is_zoomed(&self) -> bool {
+        self.inner.is_zoomed()
+    }
+
+    fn size_window_to_fit_panes(&self, cross_axis_extent: f64) {
+        self.inner.size_window_to_fit_panes(cross_axis_extent)
+    }
+
+    fn set_window_collection_behavior(&self, behavior: NSWindowCollectionBehavior) {
+        self.inner.set_window_collection_behavior(behavior)
+    }
+
+    fn window_collection_behavior(&self) -> NSWindowCollectionBehavior {
+        self.inner.window_collection_behavior()
+    }
+
+    fn set_can_join_all_spaces(&self, enabled: bool) {
+        self.inner.set_can_join_all_spaces(enabled)
+    }
+
+    fn set_full_screen_allows_tiling(&self, enabled: bool) {
+        self.inner.set_full_screen_allows_tiling(enabled)
+    }
+
+    fn set_resize_mode(&self, mode: ResizeMode) {
+        // The controller is the split view's delegate and owns resizing
+        log::warn!(
+            "Ignoring resize mode {:?} for controller split view `{}`",
+            mode,
+            self.label()
+        );
+    }
+
+    fn snapshot(&self) -> SplitViewSnapshot {
+        check_main_thread("snapshot");
+        SplitViewSnapshot {
+            collapsed: (0..self.pane_count())
+                .map(|index| self.is_pane_collapsed(index))
+                .collect(),
+            ..self.inner.snapshot()
+        }
+    }
+
+    fn apply_snapshot(&self, snapshot: &SplitViewSnapshot) {
+        trace_operation!("apply_snapshot", self.label(), snapshot);
+        self.inner.apply_snapshot_positions(snapshot);
+        restore_collapsed(self, &snapshot.collapsed);
+    }
+
+    fn enable_autosave_to_path(&self, _path: PathBuf) -> Result<(), Error> {
+        // The controller is the split view's delegate, so layout passes aren't reported
+        Err(Error::Unsupported("enable_autosave_to_path"))
+    }
+
+    fn restore_from_path(&self, path: &Path) -> Result<(), Error> {
+        self.inner.restore_from_path(path)
+    }
+
+    fn set_layout_locked(&self, locked: bool) {
+        // The controller is the split view's delegate and owns resizing
+        log::warn!(
+            "Ignoring layout lock {} for controller split view `{}`",
+            locked,
+            self.label()
+        );
+    }
+
+    fn equalize_panes(&self) {
+        self.inner.equalize_panes()
+    }
+
+    fn set_auto_equalize(&self, enabled: bool) {
+        // The controller is the split view's delegate and owns resizing
+        log::warn!(
+            "Ignoring auto equalize {} for controller split view `{}`",
+            enabled,
+            self.label()
+        );
+    }
+
+    fn set_layout_margins(&self, _top: f64, _left: f64, _bottom: f64, _right: f64) {
+        // The controller's view is the split view, so it can't be moved into a container
+        log::warn!(
+            "Ignoring layout margins for controller split view `{}`",
+            self.label()
+        );
+    }
+
+    fn layout_margins(&self) -> (f64, f64, f64, f64) {
+        (0.0, 0.0, 0.0, 0.0)
+    }
+
+    fn suppress_animations(&self) -> AnimationSuppression {
+        self.inner.suppress_animations()
+    }
+
+    fn set_panes_reversed(&self, reversed: bool) {
+        // The controller keeps the subviews in the order of its split view items
+        log::warn!(
+            "Ignoring reversed panes {} for controller split view `{}`",
+            reversed,
+            self.label()
+        );
+    }
+
+    fn set_orientation_animated(&self, orientation: SplitViewOrientation, duration_secs: f64) {
+        self.inner
+            .set_orientation_animated(orientation, duration_secs)
+    }
+
+    fn last_layout_duration(&self) -> Option<Duration> {
+        self.inner.last_layout_duration()
+    }
+
+    fn on_first_layout(&self, callback: Box<dyn FnOnce(&dyn SplitView<R>) + Send + 'static>) {
+        trace_operation!("on_first_layout", self.label());
+
+        // The callback looks the split view up when it runs, since it can't hold a reference to it
+        let app_handle = self.app_handle();
+        let label = self.label().to_string();
+        let callback = move || {
+            if let Ok(split_view) = app_handle.get_split_view(&label) {
+                callback(&*split_view);
+            }
+        };
+
+        let split_view = self.as_split_view();
+        if split_view.window().is_some() && panes_sized(split_view) {
+            callback();
+            return;
+        }
+
+        // The controller is the split view's delegate, but the split view still posts the
+        // resize notification. The observer keeps itself alive until the panes are sized.
+        let callback = Cell::new(Some(callback));
+        let observer_slot: Rc<RefCell<Option<Retained<PaneFrameObserver>>>> = Rc::default();
+        let weak_split_view = Weak::new(split_view);
+        let slot = observer_slot.clone();
+        let observer = PaneFrameObserver::new(
+            split_view.mtm(),
+            Box::new(move |_| {
+                let Some(split_view) = weak_split_view.load() else {
+                    return;
+                };
+                if split_view.window().is_none() || !panes_sized(&split_view) {
+                    return;
+                }
+
+                if let Some(observer) = slot.borrow_mut().take() {
+                    unsafe {
+                        let center = NSNotificationCenter::defaultCenter();
+                        let _: () = objc2::msg_send![&*center, removeObserver: &*observer];
+                    }
+                    // Released once the notification has been delivered
+                    let _ = Retained::autorelease_ptr(observer);
+                }
+                if let Some(callback) = callback.take() {
+                    callback();
+                }
+            }),
+        );
+        *observer_slot.borrow_mut() = Some(observer.clone());
+
+        unsafe {
+            let center = NSNotificationCenter::defaultCenter();
+            let _: () = objc2::msg_send![
+                &*center,
+                addObserver: &*observer,
+                selector: objc2::sel!(frameDidChange:),
+                name: NSSplitViewDidResizeSubviewsNotification,
+                object: split_view
+            ];
+        }
+    }
+
+    fn set_opaque(&self, opaque: bool) {
+        self.inner.set_opaque(opaque)
+    }
+
+    fn set_layer_contents_scale(&self, scale: f64) {
+        self.inner.set_layer_contents_scale(scale)
+    }
+
+    fn set_pane_background_color(
+        &self,
+        index: usize,
+        red: f64,
+        green: f64,
+        blue: f64,
+        alpha: f64,
+    ) -> Result<(), Error> {
+        self.inner
+            .set_pane_background_color(index, red, green, blue, alpha)
+    }
+
+    fn pane_background_color(&self, index: usize) -> Option<(f64, f64, f64, f64)> {
+        self.inner.pane_background_color(index)
+    }
+
+    fn set_pane_clips_to_bounds(&self, index: usize, clips: bool) -> Result<(), Error> {
+        self.inner.set_pane_clips_to_bounds(index, clips)
+    }
+
+    fn pane_backing_scale_factor(&self, index: usize) -> Option<f64> {
+        self.inner.pane_backing_scale_factor(index)
+    }
+}
+
+impl<R: Runtime> FromWindow<R> for ControllerSplitView<R> {
+    fn from_window(window: WebviewWindow<R>, label: String) -> tauri::Result<Self> {
+        check_main_thread("from_window");
+        // Raised exceptions surface as errors rather than aborting the app
+        catch_objc_exception(|| unsafe {
+            // Get the NSWindow as a raw pointer
+            let ns_window_ptr = window.ns_window().map_err(|e| {
+                tauri::Error::Io(std::io::Error::other(format!(
+                    "Failed to get NSWindow: {:?}",
+                    e
+                )))
+            })?;
+            let ns_window = ns_window_ptr as *mut AnyObject;
+
+            // Get the current content view before we replace it
+            let original_content_view: Option<Retained<NSView>> =
+                objc2::msg_send![ns_window, contentView];
+            let original_content_view = non_nil(original_content_view, "contentView")?;
+            let mtm = original_content_view.mtm();
+
+            let content_frame: NSRect = objc2::msg_send![&*original_content_view, frame];
+            let window_frame: NSRect = objc2::msg_send![ns_window, frame];
+
+            // Wrap the original content view in a view controller for the first item
+            let item = make_split_view_item(&original_content_view);
+
+            let controller: Retained<NSSplitViewController> =
+                objc2::msg_send![NSSplitViewController::alloc(mtm), init];
+            let _: () = objc2::msg_send![&*controller, addSplitViewItem: &*item];
+
+            // Swap in the crate's split view subclass before the controller loads its view
+            let split_view = TauriSplitView::new(mtm, content_frame).into_super();
+            let _: () = objc2::msg_send![&*controller, setSplitView: &*split_view];
+            let _: () = objc2::msg_send![&*split_view, setVertical: true];
+
+            // Size the controller's view to the existing content before installing it
+            let controller_view: Retained<NSView> = objc2::msg_send![&*controller, view];
+            let _: () = objc2::msg_send![&*controller_view, setFrame: content_frame];
+            let _: () = objc2::msg_send![ns_window, setContentViewController: &*controller];
+
+            // Installing a content view controller can resize the window to fit, so restore it
+            let _: () = objc2::msg_send![ns_window, setFrame: window_frame, display: true];
+
+            let inner = BasicSplitView::new(split_view, label, window.app_handle().clone());
+            // The original content view shows the window's webview
+            inner.track_webview_pane(original_content_view, window.label().to_string());
+
+            Ok(ControllerSplitView { controller, inner })
+        })?
+    }
+}
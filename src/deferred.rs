@@ -0,0 +1,63 @@
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, Sel};
+use objc2::{define_class, msg_send, sel, ClassType, DefinedClass, MainThreadOnly};
+use objc2_foundation::{MainThreadMarker, NSObject, NSObjectProtocol};
+
+pub(crate) struct DeferredCallbackIvars {
+    callback: Box<dyn Fn()>,
+}
+
+define_class!(
+    /// Target that runs a callback on a later turn of the run loop
+    #[unsafe(super(NSObject))]
+    #[name = "TauriDeferredCallback"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = DeferredCallbackIvars]
+    pub(crate) struct DeferredCallback;
+
+    unsafe impl NSObjectProtocol for DeferredCallback {}
+
+    impl DeferredCallback {
+        #[unsafe(method(run))]
+        fn run(&self) {
+            (self.ivars().callback)();
+        }
+    }
+);
+
+impl DeferredCallback {
+    /// Create a target that calls `callback` each time a scheduled run fires
+    pub(crate) fn new(mtm: MainThreadMarker, callback: Box<dyn Fn()>) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(DeferredCallbackIvars { callback });
+        unsafe { msg_send![super(this), init] }
+    }
+
+    /// Run on the next turn of the run loop, replacing a run that hasn't fired yet
+    ///
+    /// Scheduling repeatedly within one turn coalesces into a single run.
+    pub(crate) fn schedule(&self) {
+        self.schedule_after(0.0);
+    }
+
+    /// Run once `delay_secs` have passed, replacing a run that hasn't fired yet
+    ///
+    /// The run loop retains the target until the run fires.
+    pub(crate) fn schedule_after(&self, delay_secs: f64) {
+        let selector: Sel = sel!(run);
+        let object: Option<&AnyObject> = None;
+        unsafe {
+            let _: () = msg_send![
+                NSObject::class(),
+                cancelPreviousPerformRequestsWithTarget: self,
+                selector: selector,
+                object: object
+            ];
+            let _: () = msg_send![
+                self,
+                performSelector: selector,
+                withObject: object,
+                afterDelay: delay_secs
+            ];
+        }
+    }
+}
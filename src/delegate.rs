@@ -0,0 +1,837 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use objc2::rc::Retained;
+use objc2::{class, define_class, msg_send, DefinedClass, MainThreadOnly, Message};
+use objc2_app_kit::{
+    NSCursor, NSEvent, NSSplitView, NSSplitViewDelegate, NSTrackingArea, NSTrackingAreaOptions,
+    NSUserInterfaceLayoutDirection, NSView, NSWindow,
+};
+use objc2_foundation::{
+    MainThreadMarker, NSArray, NSNotification, NSObject, NSObjectProtocol, NSPoint, NSRect, NSSize,
+};
+
+use crate::deferred::DeferredCallback;
+use crate::splitview::{ResizeMode, SplitViewEvent};
+
+/// Seconds without a layout pass before an autosaved layout is written
+const AUTOSAVE_DELAY_SECS: f64 = 0.25;
+
+/// Identifies a pane by its view, so per-pane settings follow the pane when panes move
+#[derive(Clone, Debug)]
+pub(crate) struct PaneKey(Retained<NSView>);
+
+impl PaneKey {
+    pub(crate) fn new(view: &NSView) -> Self {
+        Self(view.retain())
+    }
+
+    /// Get the pane's view
+    pub(crate) fn view(&self) -> &NSView {
+        &self.0
+    }
+}
+
+impl PartialEq for PaneKey {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq::<NSView>(&*self.0, &*other.0)
+    }
+}
+
+impl Eq for PaneKey {}
+
+impl Hash for PaneKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::ptr::hash::<NSView, H>(&*self.0, state);
+    }
+}
+
+/// Layout state shared between a split view wrapper and its delegate
+#[derive(Default)]
+pub(crate) struct LayoutState {
+    pub resize_mode: Cell<ResizeMode>,
+    /// Minimum extent along the split axis, keyed by pane
+    pub min_sizes: RefCell<HashMap<PaneKey, f64>>,
+    /// Maximum extent along the split axis, keyed by pane
+    pub max_sizes: RefCell<HashMap<PaneKey, f64>>,
+    /// Writes the layout to disk once resizing settles
+    pub autosave: RefCell<Option<Retained<DeferredCallback>>>,
+    /// Minimum and maximum width to height ratios, keyed by pane
+    pub aspect_bounds: RefCell<HashMap<PaneKey, (Option<f64>, Option<f64>)>>,
+    /// Preset positions and snap threshold, keyed by divider index
+    pub snap_positions: RefCell<HashMap<usize, (Vec<f64>, f64)>>,
+    /// Cursor update areas over the dividers, rebuilt after each layout pass
+    pub divider_tracking_areas: RefCell<Vec<Retained<NSTrackingArea>>>,
+    /// Whether the dividers run top to bottom, picking the resize cursor shown over them
+    pub dividers_vertical: Cell<bool>,
+    /// Panes whose size is rounded to whole backing store pixels
+    pub pixel_aligned: RefCell<HashSet<PaneKey>>,
+    /// Panes the user can collapse by dragging a divider past their minimum
+    pub collapsible: RefCell<HashSet<PaneKey>>,
+    /// Fractions of the expanded panes' total extent to keep while the layout is locked
+    pub locked_fractions: RefCell<Option<Vec<f64>>>,
+    /// Whether the split view has resized its panes since the delegate was installed
+    pub did_layout: Cell<bool>,
+    /// Callbacks waiting for the split view's first layout pass
+    pub first_layout_callbacks: RefCell<Vec<Box<dyn FnOnce()>>>,
+    /// Senders for the subscribers to the split view's events
+    pub event_senders: RefCell<Vec<Sender<SplitViewEvent>>>,
+    /// Divider positions and pane collapse states as of the last layout pass
+    pub last_layout: RefCell<(Vec<f64>, Vec<bool>)>,
+    /// Whether the subviews run in reverse of the pane indices
+    pub panes_reversed: Cell<bool>,
+    /// Subviews in layout order once a pane's drawing order was changed, empty until then
+    pub pane_order: RefCell<Vec<Retained<NSView>>>,
+    /// Distance the resize cursor region extends beyond each side of a divider
+    pub cursor_region_padding: Cell<f64>,
+    /// Whether the expanded panes are made equal on every resize
+    pub auto_equalize: Cell<bool>,
+    /// Reports the pane frames to the frontend after each burst of layout passes
+    pub geometry_reporter: RefCell<Option<Retained<DeferredCallback>>>,
+}
+
+impl LayoutState {
+    /// Send an event to every subscriber, dropping subscribers that have gone away
+    pub(crate) fn emit(&self, event: SplitViewEvent) {
+        self.event_senders
+            .borrow_mut()
+            .retain(|sender| sender.send(event.clone()).is_ok());
+    }
+
+    /// Get the split view's subviews in layout order
+    ///
+    /// This is the subview order until a pane is brought to the front or sent to the back.
+    /// After that it's the recorded order, with subviews added since placed after the
+    /// last pane.
+    pub(crate) fn ordered_subviews(&self, split_view: &NSSplitView) -> Vec<Retained<NSView>> {
+        let subviews: Retained<NSArray<NSView>> = unsafe { msg_send![split_view, subviews] };
+        let subviews = subviews.to_vec();
+        let recorded = self.pane_order.borrow();
+        if recorded.is_empty() {
+            return subviews;
+        }
+
+        let mut order: Vec<_> = recorded
+            .iter()
+            .filter(|view| subviews.contains(view))
+            .cloned()
+            .collect();
+        let added = subviews.into_iter().filter(|view| !recorded.contains(view));
+        if self.panes_reversed.get() {
+            // The last pane is the first subview while panes are reversed
+            let mut added: Vec<_> = added.collect();
+            added.reverse();
+            added.append(&mut order);
+            added
+        } else {
+            order.extend(added);
+            order
+        }
+    }
+}
+
+pub(crate) struct SplitViewDelegateIvars {
+    state: Rc<LayoutState>,
+}
+
+define_class!(
+    /// NSSplitViewDelegate installed by the crate to customize pane layout
+    #[unsafe(super(NSObject))]
+    #[name = "TauriNSSplitViewDelegate"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = SplitViewDelegateIvars]
+    pub(crate) struct SplitViewDelegate;
+
+    impl SplitViewDelegate {
+        #[unsafe(method(cursorUpdate:))]
+        fn cursor_update(&self, _event: &NSEvent) {
+            let cursor: Retained<NSCursor> = if self.ivars().state.dividers_vertical.get() {
+                unsafe { msg_send![class!(NSCursor), resizeLeftRightCursor] }
+            } else {
+                unsafe { msg_send![class!(NSCursor), resizeUpDownCursor] }
+            };
+            cursor.set();
+        }
+    }
+
+    unsafe impl NSObjectProtocol for SplitViewDelegate {}
+
+    unsafe impl NSSplitViewDelegate for SplitViewDelegate {
+        #[unsafe(method(splitView:resizeSubviewsWithOldSize:))]
+        fn split_view_resize_subviews(&self, split_view: &NSSplitView, old_size: NSSize) {
+            let state = &self.ivars().state;
+
+            // A locked layout overrides the resize mode until it's unlocked
+            if let Some(fractions) = state.locked_fractions.borrow().as_deref() {
+                resize_proportionally(split_view, old_size, Some(fractions));
+                return;
+            }
+
+            if state.auto_equalize.get() {
+                resize_proportionally(split_view, old_size, Some(&equal_fractions(split_view)));
+                return;
+            }
+
+            match state.resize_mode.get() {
+                ResizeMode::HoldingPriority => unsafe {
+                    let _: () = msg_send![split_view, adjustSubviews];
+                },
+                ResizeMode::Proportional => resize_proportionally(split_view, old_size, None),
+            }
+        }
+
+        #[unsafe(method(splitView:constrainMinCoordinate:ofSubviewAt:))]
+        fn split_view_constrain_min_coordinate(
+            &self,
+            split_view: &NSSplitView,
+            proposed_minimum_position: f64,
+            divider_index: isize,
+        ) -> f64 {
+            let state = &self.ivars().state;
+            let index = divider_index as usize;
+            let mut position = proposed_minimum_position;
+
+            // The leading pane can't shrink below its minimum
+            if let (Some(min), Some((start, _))) = (
+                extent_limits(split_view, state, index).0,
+                pane_span(split_view, index),
+            ) {
+                position = position.max(start + min);
+            }
+
+            // The trailing pane can't grow beyond its maximum
+            if let (Some(max), Some((_, end))) = (
+                extent_limits(split_view, state, index + 1).1,
+                pane_span(split_view, index + 1),
+            ) {
+                position = position.max(end - max - divider_thickness(split_view));
+            }
+
+            position
+        }
+
+        #[unsafe(method(splitView:constrainMaxCoordinate:ofSubviewAt:))]
+        fn split_view_constrain_max_coordinate(
+            &self,
+            split_view: &NSSplitView,
+            proposed_maximum_position: f64,
+            divider_index: isize,
+        ) -> f64 {
+            let state = &self.ivars().state;
+            let index = divider_index as usize;
+            let mut position = proposed_maximum_position;
+
+            // The leading pane can't grow beyond its maximum
+            if let (Some(max), Some((start, _))) = (
+                extent_limits(split_view, state, index).1,
+                pane_span(split_view, index),
+            ) {
+                position = position.min(start + max);
+            }
+
+            // The trailing pane can't shrink below its minimum
+            if let (Some(min), Some((_, end))) = (
+                extent_limits(split_view, state, index + 1).0,
+                pane_span(split_view, index + 1),
+            ) {
+                position = position.min(end - min - divider_thickness(split_view));
+            }
+
+            position
+        }
+
+        #[unsafe(method(splitView:constrainSplitPosition:ofSubviewAt:))]
+        fn split_view_constrain_split_position(
+            &self,
+            split_view: &NSSplitView,
+            proposed_position: f64,
+            divider_index: isize,
+        ) -> f64 {
+            let state = &self.ivars().state;
+            let index = divider_index as usize;
+            let mut position = snap_position(state, index, proposed_position);
+
+            let pixel_aligned = state.pixel_aligned.borrow();
+            let is_aligned = |position| {
+                arranged_pane(split_view, position)
+                    .is_some_and(|pane| pixel_aligned.contains(&pane))
+            };
+            let leading_aligned = is_aligned(index);
+            let trailing_aligned = is_aligned(index + 1);
+            if leading_aligned || trailing_aligned {
+                let scale = backing_scale_factor(split_view);
+                let align = |extent: f64| (extent * scale).round() / scale;
+
+                // Round the leading pane's size, or the trailing pane's if only it is aligned
+                if leading_aligned {
+                    if let Some((start, _)) = pane_span(split_view, index) {
+                        position = start + align(position - start);
+                    }
+                } else if let Some((_, end)) = pane_span(split_view, index + 1) {
+                    let thickness = divider_thickness(split_view);
+                    position = end - thickness - align(end - thickness - position);
+                }
+            }
+
+            position
+        }
+
+        #[unsafe(method(splitView:effectiveRect:forDrawnRect:ofDividerAtIndex:))]
+        fn split_view_effective_rect(
+            &self,
+            split_view: &NSSplitView,
+            proposed_effective_rect: NSRect,
+            _drawn_rect: NSRect,
+            _divider_index: isize,
+        ) -> NSRect {
+            let padding = self.ivars().state.cursor_region_padding.get();
+            let mut rect = proposed_effective_rect;
+            let vertical: bool = unsafe { msg_send![split_view, isVertical] };
+            if vertical {
+                rect.origin.x -= padding;
+                rect.size.width += 2.0 * padding;
+            } else {
+                rect.origin.y -= padding;
+                rect.size.height += 2.0 * padding;
+            }
+            rect
+        }
+
+        #[unsafe(method(splitView:canCollapseSubview:))]
+        fn split_view_can_collapse_subview(
+            &self,
+            _split_view: &NSSplitView,
+            subview: &NSView,
+        ) -> bool {
+            self.ivars()
+                .state
+                .collapsible
+                .borrow()
+                .contains(&PaneKey::new(subview))
+        }
+
+        #[unsafe(method(splitViewDidResizeSubviews:))]
+        fn split_view_did_resize_subviews(&self, notification: &NSNotification) {
+            let state = &self.ivars().state;
+            state.did_layout.set(true);
+
+            let split_view: Option<Retained<NSSplitView>> =
+                unsafe { msg_send![notification, object] };
+            if let Some(split_view) = split_view {
+                // Tracking areas keep their rect, so move them to where the dividers are now
+                self.rebuild_divider_tracking_areas(&split_view);
+                if !state.event_senders.borrow().is_empty() {
+                    emit_layout_changes(&split_view, state);
+                }
+                if let Some(reporter) = state.geometry_reporter.borrow().as_ref() {
+                    reporter.schedule();
+                }
+
+                // Cursor rects are only rebuilt when invalidated, so they'd stay at the old
+                // divider positions after a window resize
+                let window: Option<Retained<NSWindow>> = unsafe { msg_send![&*split_view, window] };
+                if let Some(window) = window {
+                    unsafe {
+                        let _: () = msg_send![&*window, invalidateCursorRectsForView: &*split_view];
+                    }
+                }
+            }
+
+            if let Some(autosave) = state.autosave.borrow().as_ref() {
+                autosave.schedule_after(AUTOSAVE_DELAY_SECS);
+            }
+
+            // Take the callbacks first so they can use the split view without a double borrow
+            for callback in state.first_layout_callbacks.take() {
+                callback();
+            }
+        }
+    }
+);
+
+impl SplitViewDelegate {
+    /// Create a new delegate backed by the given layout state
+    pub(crate) fn new(mtm: MainThreadMarker, state: Rc<LayoutState>) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(SplitViewDelegateIvars { state });
+        unsafe { msg_send![super(this), init] }
+    }
+
+    /// Install a cursor update area over each divider, replacing the previous areas
+    pub(crate) fn rebuild_divider_tracking_areas(&self, split_view: &NSSplitView) {
+        let state = &self.ivars().state;
+        remove_divider_tracking_areas(split_view, state);
+
+        let vertical: bool = unsafe { msg_send![split_view, isVertical] };
+        state.dividers_vertical.set(vertical);
+
+        let options =
+            NSTrackingAreaOptions::CursorUpdate | NSTrackingAreaOptions::ActiveInKeyWindow;
+        let mut areas = state.divider_tracking_areas.borrow_mut();
+        for rect in divider_rects(split_view) {
+            unsafe {
+                let area: Retained<NSTrackingArea> = msg_send![
+                    NSTrackingArea::alloc(),
+                    initWithRect: rect,
+                    options: options,
+                    owner: self,
+                    userInfo: std::ptr::null::<NSObject>()
+                ];
+                let _: () = msg_send![split_view, addTrackingArea: &*area];
+                areas.push(area);
+            }
+        }
+    }
+}
+
+/// Remove the divider cursor areas installed on the split view
+pub(crate) fn remove_divider_tracking_areas(split_view: &NSSplitView, state: &LayoutState) {
+    for area in state.divider_tracking_areas.take() {
+        unsafe {
+            let _: () = msg_send![split_view, removeTrackingArea: &*area];
+        }
+    }
+}
+
+/// Get the rect of each divider, between the arranged panes on either side of it
+fn divider_rects(split_view: &NSSplitView) -> Vec<NSRect> {
+    unsafe {
+        let subviews: Retained<NSArray<NSView>> = msg_send![split_view, arrangedSubviews];
+        let count: usize = msg_send![&*subviews, count];
+        let vertical: bool = msg_send![split_view, isVertical];
+        let thickness = divider_thickness(split_view);
+        let bounds: NSRect = msg_send![split_view, bounds];
+
+        let frames: Vec<NSRect> = (0..count)
+            .map(|i| {
+                let view: Retained<NSView> = msg_send![&*subviews, objectAtIndex: i];
+                msg_send![&*view, frame]
+            })
+            .collect();
+
+        frames
+            .windows(2)
+            .map(|pair| {
+                // The divider starts where the earlier of the two panes ends, which also holds
+                // for right-to-left layouts
+                if vertical {
+                    let x = (pair[0].origin.x + pair[0].size.width)
+                        .min(pair[1].origin.x + pair[1].size.width);
+                    NSRect::new(
+                        NSPoint::new(x, 0.0),
+                        NSSize::new(thickness, bounds.size.height),
+                    )
+                } else {
+                    let y = (pair[0].origin.y + pair[0].size.height)
+                        .min(pair[1].origin.y + pair[1].size.height);
+                    NSRect::new(
+                        NSPoint::new(0.0, y),
+                        NSSize::new(bounds.size.width, thickness),
+                    )
+                }
+            })
+            .collect()
+    }
+}
+
+/// Get the key of the pane arranged at `position`
+fn arranged_pane(split_view: &NSSplitView, position: usize) -> Option<PaneKey> {
+    unsafe {
+        let arranged: Retained<NSArray<NSView>> = msg_send![split_view, arrangedSubviews];
+        let count: usize = msg_send![&*arranged, count];
+        (position < count).then(|| {
+            let view: Retained<NSView> = msg_send![&*arranged, objectAtIndex: position];
+            PaneKey(view)
+        })
+    }
+}
+
+/// Get the minimum and maximum extent along the split axis of the pane arranged at
+/// `position`, combining its size limits with its aspect ratio bounds
+fn extent_limits(
+    split_view: &NSSplitView,
+    state: &LayoutState,
+    position: usize,
+) -> (Option<f64>, Option<f64>) {
+    let Some(pane) = arranged_pane(split_view, position) else {
+        return (None, None);
+    };
+    let mut min = state.min_sizes.borrow().get(&pane).copied();
+    let mut max = state.max_sizes.borrow().get(&pane).copied();
+
+    if let Some(&(min_ratio, max_ratio)) = state.aspect_bounds.borrow().get(&pane) {
+        let (vertical, bounds): (bool, NSRect) = unsafe {
+            (
+                msg_send![split_view, isVertical],
+                msg_send![split_view, bounds],
+            )
+        };
+
+        // Ratios are width over height, and the pane's cross axis extent is the split view's
+        let (low, high) = if vertical {
+            (
+                min_ratio.map(|ratio| ratio * bounds.size.height),
+                max_ratio.map(|ratio| ratio * bounds.size.height),
+            )
+        } else {
+            (
+                max_ratio.map(|ratio| bounds.size.width / ratio),
+                min_ratio.map(|ratio| bounds.size.width / ratio),
+            )
+        };
+
+        min = match (min, low) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        max = match (max, high) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+    }
+
+    (min, max)
+}
+
+/// Send events for the dividers that moved and panes that collapsed or expanded
+/// since the last layout pass
+fn emit_layout_changes(split_view: &NSSplitView, state: &LayoutState) {
+    let (positions, collapsed) = layout_snapshot(split_view);
+    let (last_positions, last_collapsed) = state
+        .last_layout
+        .replace((positions.clone(), collapsed.clone()));
+
+    for (divider_index, &position) in positions.iter().enumerate() {
+        if last_positions.get(divider_index) != Some(&position) {
+            state.emit(SplitViewEvent::DividerMoved {
+                divider_index,
+                position,
+            });
+        }
+    }
+
+    for (index, &is_collapsed) in collapsed.iter().enumerate() {
+        let was_collapsed = last_collapsed.get(index).copied().unwrap_or(false);
+        match (was_collapsed, is_collapsed) {
+            (false, true) => state.emit(SplitViewEvent::PaneCollapsed(index)),
+            (true, false) => state.emit(SplitViewEvent::PaneExpanded(index)),
+            _ => {}
+        }
+    }
+}
+
+/// Get every divider's position and whether each arranged pane is collapsed
+pub(crate) fn layout_snapshot(split_view: &NSSplitView) -> (Vec<f64>, Vec<bool>) {
+    unsafe {
+        let subviews: Retained<NSArray<NSView>> = msg_send![split_view, arrangedSubviews];
+        let count: usize = msg_send![&*subviews, count];
+
+        let positions = (0..count.saturating_sub(1))
+            .filter_map(|index| pane_span(split_view, index).map(|(_, end)| end))
+            .collect();
+        let collapsed = (0..count)
+            .map(|i| {
+                let view: Retained<NSView> = msg_send![&*subviews, objectAtIndex: i];
+                let collapsed: bool = msg_send![split_view, isSubviewCollapsed: &*view];
+                collapsed
+            })
+            .collect();
+        (positions, collapsed)
+    }
+}
+
+/// Snap a proposed divider position to the nearest preset within the divider's threshold
+fn snap_position(state: &LayoutState, divider_index: usize, proposed_position: f64) -> f64 {
+    let snap_positions = state.snap_positions.borrow();
+    let Some((positions, threshold)) = snap_positions.get(&divider_index) else {
+        return proposed_position;
+    };
+
+    positions
+        .iter()
+        .copied()
+        .filter(|position| (position - proposed_position).abs() <= *threshold)
+        .min_by(|a, b| {
+            (a - proposed_position)
+                .abs()
+                .total_cmp(&(b - proposed_position).abs())
+        })
+        .unwrap_or(proposed_position)
+}
+
+/// Get the number of backing store pixels per point for the split view's window
+fn backing_scale_factor(split_view: &NSSplitView) -> f64 {
+    unsafe {
+        let window: Option<Retained<NSWindow>> = msg_send![split_view, window];
+        window.map_or(1.0, |window| msg_send![&*window, backingScaleFactor])
+    }
+}
+
+/// Get the thickness of the split view's dividers
+fn divider_thickness(split_view: &NSSplitView) -> f64 {
+    unsafe { msg_send![split_view, dividerThickness] }
+}
+
+/// Get the unconstrained range of positions of a divider, from the leading pane's start
+/// to the trailing pane's end
+pub(crate) fn divider_span(split_view: &NSSplitView, divider_index: usize) -> Option<(f64, f64)> {
+    let (start, _) = pane_span(split_view, divider_index)?;
+    let (_, end) = pane_span(split_view, divider_index + 1)?;
+    Some((start, end - divider_thickness(split_view)))
+}
+
+/// Get the start and end of the arranged pane at `index` along the split axis
+fn pane_span(split_view: &NSSplitView, index: usize) -> Option<(f64, f64)> {
+    unsafe {
+        let subviews: Retained<NSArray<NSView>> = msg_send![split_view, arrangedSubviews];
+        let count: usize = msg_send![&*subviews, count];
+        if index >= count {
+            return None;
+        }
+
+        let view: Retained<NSView> = msg_send![&*subviews, objectAtIndex: index];
+        let frame: NSRect = msg_send![&*view, frame];
+        let vertical: bool = msg_send![split_view, isVertical];
+
+        Some(if vertical {
+            (frame.origin.x, frame.origin.x + frame.size.width)
+        } else {
+            (frame.origin.y, frame.origin.y + frame.size.height)
+        })
+    }
+}
+
+/// Get each arranged pane's fraction of the expanded panes' total extent
+///
+/// Collapsed panes have a fraction of 0.0.
+pub(crate) fn pane_fractions(split_view: &NSSplitView) -> Vec<f64> {
+    unsafe {
+        let subviews: Retained<NSArray<NSView>> = msg_send![split_view, arrangedSubviews];
+        let count: usize = msg_send![&*subviews, count];
+        let vertical: bool = msg_send![split_view, isVertical];
+
+        let extents: Vec<f64> = (0..count)
+            .map(|i| {
+                let view: Retained<NSView> = msg_send![&*subviews, objectAtIndex: i];
+                let collapsed: bool = msg_send![split_view, isSubviewCollapsed: &*view];
+                let frame: NSRect = msg_send![&*view, frame];
+                match (collapsed, vertical) {
+                    (true, _) => 0.0,
+                    (false, true) => frame.size.width,
+                    (false, false) => frame.size.height,
+                }
+            })
+            .collect();
+
+        let total: f64 = extents.iter().sum();
+        extents
+            .iter()
+            .map(|extent| if total > 0.0 { extent / total } else { 0.0 })
+            .collect()
+    }
+}
+
+/// Get an equal fraction for each expanded arranged pane
+///
+/// Collapsed panes have a fraction of 0.0.
+pub(crate) fn equal_fractions(split_view: &NSSplitView) -> Vec<f64> {
+    unsafe {
+        let subviews: Retained<NSArray<NSView>> = msg_send![split_view, arrangedSubviews];
+        let count: usize = msg_send![&*subviews, count];
+        let expanded: Vec<bool> = (0..count)
+            .map(|i| {
+                let view: Retained<NSView> = msg_send![&*subviews, objectAtIndex: i];
+                let collapsed: bool = msg_send![split_view, isSubviewCollapsed: &*view];
+                !collapsed
+            })
+            .collect();
+
+        let share = 1.0 / expanded.iter().filter(|&&e| e).count().max(1) as f64;
+        expanded
+            .iter()
+            .map(|&e| if e { share } else { 0.0 })
+            .collect()
+    }
+}
+
+/// Resize the arranged subviews so each expanded pane keeps its fraction of the split axis
+///
+/// Fractions are taken from the current pane sizes unless `locked` fractions are given
+/// for every arranged pane.
+pub(crate) fn resize_proportionally(
+    split_view: &NSSplitView,
+    old_size: NSSize,
+    locked: Option<&[f64]>,
+) {
+    unsafe {
+        let subviews: Retained<NSArray<NSView>> = msg_send![split_view, arrangedSubviews];
+        let count: usize = msg_send![&*subviews, count];
+        let vertical: bool = msg_send![split_view, isVertical];
+        let thickness: f64 = msg_send![split_view, dividerThickness];
+        let bounds: NSRect = msg_send![split_view, bounds];
+        let direction: NSUserInterfaceLayoutDirection =
+            msg_send![split_view, userInterfaceLayoutDirection];
+        let rtl = direction == NSUserInterfaceLayoutDirection::RightToLeft;
+
+        let axis = |size: NSSize| if vertical { size.width } else { size.height };
+
+        let mut views = Vec::with_capacity(count);
+        let mut panes = Vec::with_capacity(count);
+        for i in 0..count {
+            let view: Retained<NSView> = msg_send![&*subviews, objectAtIndex: i];
+            let collapsed: bool = msg_send![split_view, isSubviewCollapsed: &*view];
+            let frame: NSRect = msg_send![&*view, frame];
+            panes.push((collapsed, axis(frame.size)));
+            views.push(view);
+        }
+
+        // Nothing to scale from, let AppKit lay out the panes
+        let extents = (axis(old_size) > 0.0)
+            .then(|| scaled_extents(&panes, axis(bounds.size), thickness, locked))
+            .flatten();
+        let Some(extents) = extents else {
+            let _: () = msg_send![split_view, adjustSubviews];
+            return;
+        };
+
+        let mut offset = 0.0;
+        for ((view, &(collapsed, _)), extent) in views.iter().zip(&panes).zip(extents) {
+            if collapsed {
+                offset += thickness;
+                continue;
+            }
+
+            let frame = if vertical {
+                // Right-to-left layouts place the first pane at the right edge
+                let x = if rtl {
+                    bounds.size.width - offset - extent
+                } else {
+                    offset
+                };
+                NSRect::new(
+                    NSPoint::new(x, 0.0),
+                    NSSize::new(extent, bounds.size.height),
+                )
+            } else {
+                NSRect::new(
+                    NSPoint::new(0.0, offset),
+                    NSSize::new(bounds.size.width, extent),
+                )
+            };
+            let _: () = msg_send![&**view, setFrame: frame];
+
+            offset += extent + thickness;
+        }
+    }
+}
+
+/// Compute each arranged pane's extent along a split axis that is now `axis_extent` long
+///
+/// `panes` holds each pane's collapsed state and current extent. Expanded panes keep
+/// their share of the expanded panes' total extent, or their `locked` fraction if one is
+/// given for every pane, and collapsed panes get 0.0. Returns `None` if the expanded
+/// panes have no extent to scale from.
+fn scaled_extents(
+    panes: &[(bool, f64)],
+    axis_extent: f64,
+    thickness: f64,
+    locked: Option<&[f64]>,
+) -> Option<Vec<f64>> {
+    let old_total: f64 = panes
+        .iter()
+        .filter(|(collapsed, _)| !collapsed)
+        .map(|(_, extent)| extent)
+        .sum();
+    if old_total <= 0.0 {
+        return None;
+    }
+
+    let dividers = thickness * panes.len().saturating_sub(1) as f64;
+    let new_total = (axis_extent - dividers).max(0.0);
+    let locked = locked.filter(|fractions| fractions.len() == panes.len());
+    let last_expanded = panes.iter().rposition(|(collapsed, _)| !collapsed);
+    let mut offset = 0.0;
+
+    let extents = panes
+        .iter()
+        .enumerate()
+        .map(|(i, &(collapsed, extent))| {
+            if collapsed {
+                offset += thickness;
+                return 0.0;
+            }
+
+            // The last expanded pane absorbs rounding so the panes fill the split view
+            let extent = if Some(i) == last_expanded {
+                (axis_extent - offset).max(0.0)
+            } else {
+                match locked {
+                    Some(fractions) => fractions[i] * new_total,
+                    None => extent / old_total * new_total,
+                }
+            };
+            offset += extent + thickness;
+            extent
+        })
+        .collect();
+    Some(extents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scaled_extents;
+
+    #[test]
+    fn scaled_extents_keep_each_pane_share() {
+        let panes = [(false, 100.0), (false, 300.0)];
+        assert_eq!(
+            scaled_extents(&panes, 801.0, 1.0, None),
+            Some(vec![200.0, 600.0])
+        );
+    }
+
+    #[test]
+    fn scaled_extents_skip_collapsed_panes() {
+        let panes = [(false, 100.0), (true, 0.0), (false, 100.0)];
+        assert_eq!(
+            scaled_extents(&panes, 402.0, 1.0, None),
+            Some(vec![200.0, 0.0, 200.0])
+        );
+    }
+
+    #[test]
+    fn scaled_extents_use_locked_fractions() {
+        let panes = [(false, 100.0), (false, 100.0)];
+        assert_eq!(
+            scaled_extents(&panes, 301.0, 1.0, Some(&[0.25, 0.75])),
+            Some(vec![75.0, 225.0])
+        );
+    }
+
+    #[test]
+    fn scaled_extents_ignore_locked_fractions_for_other_pane_counts() {
+        let panes = [(false, 100.0), (false, 300.0)];
+        assert_eq!(
+            scaled_extents(&panes, 801.0, 1.0, Some(&[0.5, 0.25, 0.25])),
+            Some(vec![200.0, 600.0])
+        );
+    }
+
+    #[test]
+    fn scaled_extents_need_an_expanded_extent() {
+        assert_eq!(scaled_extents(&[], 400.0, 1.0, None), None);
+        assert_eq!(
+            scaled_extents(&[(false, 0.0), (true, 0.0)], 400.0, 1.0, None),
+            None
+        );
+    }
+
+    #[test]
+    fn last_expanded_pane_absorbs_rounding() {
+        let panes = [(false, 1.0), (false, 1.0), (false, 1.0)];
+        let extents = scaled_extents(&panes, 102.0, 1.0, None).unwrap();
+        assert!((extents.iter().sum::<f64>() + 2.0 - 102.0).abs() < 1e-9);
+    }
+}
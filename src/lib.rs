@@ -1,46 +1,99 @@
 pub mod builder;
+#[cfg(target_os = "macos")]
+mod close_guard;
+#[cfg(target_os = "macos")]
 pub mod common;
+#[cfg(target_os = "macos")]
+pub mod controller;
+#[cfg(target_os = "macos")]
+mod deferred;
+#[cfg(target_os = "macos")]
+mod delegate;
+#[cfg(target_os = "macos")]
 pub mod event;
+#[cfg(target_os = "macos")]
+pub mod main_thread;
+#[cfg(target_os = "macos")]
+mod pane_click;
+#[cfg(target_os = "macos")]
+mod pane_frame_observer;
+#[cfg(target_os = "macos")]
+mod pane_hover;
+#[cfg(target_os = "macos")]
+mod pane_mirror;
+#[cfg(target_os = "macos")]
 pub mod splitview;
+#[cfg(target_os = "macos")]
+mod tauri_split_view;
+#[cfg(target_os = "macos")]
+mod trace;
+#[cfg(not(target_os = "macos"))]
+mod unsupported;
 
 // Re-export for macro usage
+#[cfg(target_os = "macos")]
 #[doc(hidden)]
 pub use objc2;
+#[cfg(target_os = "macos")]
 #[doc(hidden)]
 pub use objc2_app_kit;
+#[cfg(target_os = "macos")]
 #[doc(hidden)]
 pub use objc2_foundation;
 #[doc(hidden)]
 pub use pastey;
 
+#[cfg(target_os = "macos")]
+use std::any::Any;
+#[cfg(target_os = "macos")]
+use std::path::{Path, PathBuf};
 use std::{
-    any::Any,
     collections::HashMap,
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, Weak},
 };
 
+#[cfg(target_os = "macos")]
 use objc2::runtime::ProtocolObject;
+#[cfg(target_os = "macos")]
 use objc2_app_kit::NSWindowDelegate;
 use tauri::{
     plugin::{Builder, TauriPlugin},
-    Manager, Runtime, WebviewWindow,
+    AppHandle, Manager, Runtime, WebviewUrl, WebviewWindow,
 };
 
 pub use builder::{PaneConfig, SplitViewBuilder, SplitViewOrientation};
-pub use splitview::BasicSplitView;
+#[cfg(target_os = "macos")]
+pub use controller::{ControllerSplitView, SplitViewItemBehavior};
+#[cfg(target_os = "macos")]
+pub use main_thread::{set_main_thread_checks, MainThreadBound};
+#[cfg(target_os = "macos")]
+pub use splitview::{
+    AnimationSuppression, BasicSplitView, Density, DividerInfo, DividerPosition, DividerStyle,
+    Edge, PaneConstraints, PaneGeometry, PaneKind, PanePresentation, ResizeMode, SplitViewEvent,
+    SplitViewSnapshot,
+};
+#[cfg(not(target_os = "macos"))]
+pub use unsupported::{BasicSplitView, SplitView};
 
 // Re-export commonly used types for convenience
+#[cfg(target_os = "macos")]
 pub use objc2::runtime::AnyObject;
+#[cfg(target_os = "macos")]
+pub use objc2::MainThreadMarker;
+#[cfg(target_os = "macos")]
 pub use objc2_app_kit::{NSResponder, NSSplitView, NSView, NSWindow};
+#[cfg(target_os = "macos")]
 pub use objc2_foundation::{NSNotification, NSObject, NSPoint, NSRect, NSSize};
 
 /// Trait for event handlers that can be used with split views
+#[cfg(target_os = "macos")]
 pub trait EventHandler {
     /// Get the NSWindowDelegate protocol object
     fn as_delegate(&self) -> ProtocolObject<dyn NSWindowDelegate>;
 }
 
 /// Common trait for all split view types
+#[cfg(target_os = "macos")]
 pub trait SplitView<R: tauri::Runtime = tauri::Wry>: Send + Sync {
     /// Show the split view
     fn show(&self);
@@ -51,6 +104,9 @@ pub trait SplitView<R: tauri::Runtime = tauri::Wry>: Send + Sync {
     /// Convert split view back to a regular Tauri window
     fn to_window(&self) -> Option<tauri::WebviewWindow<R>>;
 
+    /// Get the app handle the split view belongs to
+    fn app_handle(&self) -> tauri::AppHandle<R>;
+
     /// Get a reference to the underlying NSSplitView
     fn as_split_view(&self) -> &objc2_app_kit::NSSplitView;
 
@@ -61,9 +117,31 @@ pub trait SplitView<R: tauri::Runtime = tauri::Wry>: Send + Sync {
     fn as_any(&self) -> &dyn Any;
 
     /// Set the event handler (window delegate)
-    /// Pass `None` to remove the current delegate
+    /// Pass `None` to remove the current delegate, restoring the window's original
+    /// delegate, or no delegate if the window had none when the handler was installed
     fn set_event_handler(&self, handler: Option<&ProtocolObject<dyn NSWindowDelegate>>);
 
+    /// Subscribe to a stream of changes to the split view
+    ///
+    /// Events are sent as they happen and the receiver can be polled from any thread.
+    /// Dropping the receiver unsubscribes.
+    fn events(&self) -> std::sync::mpsc::Receiver<SplitViewEvent>;
+
+    /// Emit a Tauri event named `event_name` with every pane's frame after the layout changes
+    ///
+    /// The payload is a list of [`PaneGeometry`], so a web UI can line up HTML overlays
+    /// with native panes. Bursts of layout passes, such as during a live resize, are
+    /// coalesced into one event. The current layout is reported straight away.
+    fn emit_pane_geometry_events(&self, event_name: &str);
+
+    /// Attach app data to the split view, replacing any data attached before
+    fn set_user_data(&self, data: Box<dyn Any + Send + Sync>);
+
+    /// Get the app data attached to the split view
+    ///
+    /// Downcast the data with [`Arc::downcast`] or `downcast_ref`.
+    fn user_data(&self) -> Option<Arc<dyn Any + Send + Sync>>;
+
     // Query methods
     /// Check if the split view is visible
     fn is_visible(&self) -> bool;
@@ -72,40 +150,689 @@ pub trait SplitView<R: tauri::Runtime = tauri::Wry>: Send + Sync {
     fn is_vertical(&self) -> bool;
 
     /// Get number of panes
+    ///
+    /// In debug builds a warning is logged if this disagrees with
+    /// [`tracked_pane_count`](Self::tracked_pane_count).
     fn pane_count(&self) -> usize;
 
+    /// Get the number of panes as of the crate's last pane operation
+    ///
+    /// Differs from [`pane_count`](Self::pane_count) when panes were added or removed
+    /// with raw messages to the split view, which leaves per-pane settings out of sync.
+    fn tracked_pane_count(&self) -> usize;
+
+    /// Check if the split view is its window's content view
+    ///
+    /// Split views nested in a pane, such as those added by
+    /// [`add_region_split_view`](Self::add_region_split_view), return false. A split view
+    /// inset by [`set_layout_margins`](Self::set_layout_margins) still counts as the
+    /// content view.
+    fn is_content_view(&self) -> bool;
+
+    /// Describe the split view's superviews and panes as an indented tree of classes and frames
+    ///
+    /// Intended for debugging window conversion.
+    fn describe_hierarchy(&self) -> String;
+
     // Divider methods
-    /// Set the position of a divider (0-indexed divider, 0.0-1.0 position)
-    fn set_divider_position(&self, divider_index: usize, position: f64);
+    /// Set the position of a divider (0-indexed divider, position in points)
+    ///
+    /// Equivalent to [`set_divider`](Self::set_divider) with [`DividerPosition::Points`].
+    /// Returns the position the divider ended up at, which differs from `position`
+    /// when pane size constraints clamp the move.
+    fn set_divider_position(&self, divider_index: usize, position: f64) -> f64;
+
+    /// Move a divider to a position given in points or as a fraction of the split axis
+    fn set_divider(&self, divider_index: usize, position: DividerPosition);
 
     /// Get the position of a divider (0-indexed divider)
     fn get_divider_position(&self, divider_index: usize) -> f64;
 
+    /// Extend the region showing the resize cursor `px` points beyond each side of the dividers
+    ///
+    /// The region follows the dividers as the panes are resized.
+    fn set_cursor_region_padding(&self, px: f64);
+
+    /// Snap a divider to the nearest preset position within `snap_threshold` while dragging
+    ///
+    /// Passing an empty `positions` vector disables snapping for the divider.
+    fn set_divider_snap_positions(
+        &self,
+        divider_index: usize,
+        positions: Vec<f64>,
+        snap_threshold: f64,
+    );
+
+    /// Get the lowest and highest positions the user can currently drag a divider to
+    ///
+    /// The range accounts for the adjacent panes' minimum and maximum sizes. Returns
+    /// `None` if there's no divider at `divider_index`.
+    fn divider_drag_range(&self, divider_index: usize) -> Option<(f64, f64)>;
+
+    /// Get the position, fraction, thickness and draggability of every divider in one pass
+    fn divider_info(&self) -> Vec<DividerInfo>;
+
+    /// Get the index of the divider the user is currently dragging
+    ///
+    /// Only split views created by the crate track their active divider.
+    fn active_divider(&self) -> Option<usize>;
+
+    /// Lay panes out right to left, so pane 0 appears on the right of a vertical split
+    ///
+    /// Divider fractions are measured from the leading edge in either direction.
+    fn set_user_interface_layout_direction(&self, rtl: bool);
+
     /// Set divider thickness
+    ///
+    /// Overrides the thickness of the divider style. Only split views created by the
+    /// crate can change their divider thickness.
     fn set_divider_thickness(&self, thickness: f64);
 
     /// Get divider thickness
     fn divider_thickness(&self) -> f64;
 
+    /// Space the panes out with a preset divider thickness
+    ///
+    /// Shorthand for [`set_divider_thickness`](Self::set_divider_thickness) with
+    /// [`Density::divider_thickness`].
+    fn set_density(&self, density: Density);
+
+    /// Show or hide divider drawing without changing the layout
+    ///
+    /// Only split views created by the crate can hide their dividers.
+    fn set_dividers_visible(&self, visible: bool);
+
+    /// Check if dividers are drawn
+    fn dividers_visible(&self) -> bool;
+
+    /// Remove every tracking area the crate installed on the split view
+    ///
+    /// Divider cursor areas are installed again at the dividers' positions after the next
+    /// layout pass. Hover callbacks are removed and have to be registered again.
+    fn clear_tracking_areas(&self);
+
+    /// Set the visual style of the dividers
+    fn set_divider_style(&self, style: DividerStyle);
+
+    /// Get the visual style of the dividers
+    fn divider_style(&self) -> DividerStyle;
+
+    /// Draw the dividers in the given sRGB color
+    ///
+    /// Only split views created by the crate can change their divider color.
+    fn set_divider_color(&self, red: f64, green: f64, blue: f64, alpha: f64);
+
+    /// Get the divider color set with `set_divider_color`, as sRGB components
+    fn divider_color(&self) -> Option<(f64, f64, f64, f64)>;
+
+    /// Draw an image, decoded from PNG or other image file bytes, centered over a divider
+    ///
+    /// Only split views created by the crate can draw divider images; others fail with
+    /// [`Error::Unsupported`].
+    fn set_divider_image(&self, divider_index: usize, image_bytes: &[u8]) -> Result<(), Error>;
+
+    /// Stop drawing an image over a divider
+    fn clear_divider_image(&self, divider_index: usize);
+
+    /// Let a divider be moved `step` points at a time with the arrow keys
+    ///
+    /// The divider takes keyboard focus when clicked, or when the split view is tabbed
+    /// into. A step of 0.0 turns keyboard resizing off. Only split views created by the
+    /// crate can be resized from the keyboard.
+    fn set_divider_keyboard_resizable(&self, divider_index: usize, step: f64);
+
     // Pane methods
+    /// Add a view as a new pane after the existing panes, returning its index
+    ///
+    /// Fails with [`Error::InvalidHierarchy`] if the view is or contains the split view,
+    /// or [`Error::MaxPanesReached`] if the split view already has its maximum pane count.
+    fn add_pane(&self, view: &objc2_app_kit::NSView) -> Result<usize, Error>;
+
+    /// Add several views as panes in order with a single layout pass, returning their indices
+    ///
+    /// No pane is added if any of the views is or contains the split view, or if
+    /// adding them all would exceed the maximum pane count.
+    fn add_panes(
+        &self,
+        views: Vec<objc2::rc::Retained<objc2_app_kit::NSView>>,
+    ) -> Result<Vec<usize>, Error>;
+
+    /// Add a view as a new pane without laying out the panes, returning its index
+    ///
+    /// Call [`commit_layout`](Self::commit_layout) once all deferred panes are added.
+    fn add_pane_deferred(&self, view: &objc2_app_kit::NSView) -> Result<usize, Error>;
+
+    /// Lay out the panes in a single pass after deferred changes
+    fn commit_layout(&self);
+
+    /// Add a pre-built hosting view, such as a SwiftUI `NSHostingView`, as a new pane
+    ///
+    /// The view is checked to really be an `NSView` and set to resize with its pane
+    /// before being added like [`add_pane`](Self::add_pane).
+    fn add_hosting_pane(
+        &self,
+        hosting_view: objc2::rc::Retained<objc2_app_kit::NSView>,
+    ) -> Result<usize, Error>;
+
     /// Get a pane view by index
     fn pane_at_index(&self, index: usize) -> Option<objc2::rc::Retained<objc2_app_kit::NSView>>;
 
-    /// Set whether a pane can collapse
+    /// Tag a pane so it can be found again after panes move
+    ///
+    /// A tag names one pane at a time, so tagging another pane with it moves the tag.
+    /// An empty tag removes the pane's tag.
+    fn set_pane_tag(&self, index: usize, tag: &str) -> Result<(), Error>;
+
+    /// Get the current index of the pane tagged `tag`
+    fn pane_index_for_tag(&self, tag: &str) -> Option<usize>;
+
+    /// Get the view of the pane tagged `tag`
+    fn pane_view_for_tag(&self, tag: &str) -> Option<objc2::rc::Retained<objc2_app_kit::NSView>>;
+
+    /// Add a pane showing a new webview that loads `url`
+    ///
+    /// The webview belongs to a hidden window, so it can be controlled like any other
+    /// [`WebviewWindow`]. The window is labelled `{label}-pane-{n}` after the split view,
+    /// with `n` counting up from the pane's index until the label is unused.
+    fn add_webview_pane(&self, url: WebviewUrl) -> tauri::Result<usize>;
+
+    /// Get the label of the webview shown in the pane at `index`, if it is a webview pane
+    fn webview_pane_label(&self, index: usize) -> Option<String>;
+
+    /// Get the webview shown in each webview pane, paired with the pane's index
+    ///
+    /// The window's original webview is included as the pane it was converted into.
+    fn webview_panes(&self) -> Vec<(usize, WebviewWindow<R>)>;
+
+    /// Reload the webview of every webview pane
+    fn reload_all_webview_panes(&self);
+
+    /// Replace the view shown in the pane at `index`
+    ///
+    /// The swap is committed on the next turn of the run loop. Changing the same pane
+    /// again before then supersedes the pending swap, so when panes are replaced in
+    /// quick succession only the most recent view is ever shown.
+    fn replace_pane_view(&self, index: usize, view: &objc2_app_kit::NSView) -> Result<(), Error>;
+
+    /// Load `url` in the webview shown in the pane at `index`
+    ///
+    /// Like [`SplitView::replace_pane_view`], the navigation is committed on the next
+    /// turn of the run loop and supersedes a pending change of the same pane.
+    fn navigate_pane(&self, index: usize, url: tauri::Url) -> Result<(), Error>;
+
+    /// Get what kind of view the pane at `index` holds
+    ///
+    /// Panes containing a webview are [`PaneKind::Webview`], other panes added through
+    /// the crate are [`PaneKind::Native`], and anything else is [`PaneKind::Unknown`].
+    fn pane_kind(&self, index: usize) -> Option<PaneKind>;
+
+    /// Cap the number of panes, or remove the cap with `None`
+    ///
+    /// Existing panes are kept if there are already more than `max`; only adding
+    /// new panes is refused.
+    fn set_max_panes(&self, max: Option<usize>);
+
+    /// Move a pane to a new index, shifting the panes in between
+    ///
+    /// Per-pane settings such as minimum and maximum sizes move with the pane.
+    fn move_pane(&self, from: usize, to: usize) -> Result<(), Error>;
+
+    /// Draw a pane above the other panes without changing its position in the layout
+    ///
+    /// The pane moves to the end of the split view's subviews and keeps its index.
+    fn bring_pane_to_front(&self, index: usize) -> Result<(), Error>;
+
+    /// Draw a pane below the other panes without changing its position in the layout
+    ///
+    /// The pane moves to the start of the split view's subviews and keeps its index.
+    fn send_pane_to_back(&self, index: usize) -> Result<(), Error>;
+
+    /// Present a pane inline or as an overlay floating over its neighbour
+    ///
+    /// Overlay panes keep their index but take no layout space, so divider indices
+    /// only count the inline panes while an overlay is shown.
+    fn set_pane_presentation(&self, index: usize, mode: PanePresentation) -> Result<(), Error>;
+
+    /// Get how a pane is presented
+    fn pane_presentation(&self, index: usize) -> Option<PanePresentation>;
+
+    /// Pin an accessory view to an edge of a pane
+    ///
+    /// A `thickness`-deep strip is carved off the pane's edge for `view`, and the pane's
+    /// existing content is moved into the remaining area. Replaces any accessory
+    /// already on that edge.
+    fn set_pane_edge_accessory(
+        &self,
+        index: usize,
+        edge: Edge,
+        view: objc2::rc::Retained<objc2_app_kit::NSView>,
+        thickness: f64,
+    ) -> Result<(), Error>;
+
+    /// Remove the accessory pinned to an edge of a pane, giving its space back to the content
+    fn clear_pane_edge_accessory(&self, index: usize, edge: Edge) -> Result<(), Error>;
+
+    /// Call `callback` with the click location, in pane coordinates, whenever a pane is clicked
+    ///
+    /// Replaces any click callback already installed on the pane.
+    fn on_pane_click(
+        &self,
+        index: usize,
+        callback: Box<dyn Fn(NSPoint) + Send + 'static>,
+    ) -> Result<(), Error>;
+
+    /// Remove the click callback installed on a pane
+    fn clear_pane_click(&self, index: usize) -> Result<(), Error>;
+
+    /// Call `callback` with `true` when the mouse enters a pane and `false` when it leaves
+    ///
+    /// The tracked region follows the pane as it resizes or moves. Installing a new
+    /// callback replaces the previous one, and dropping the split view removes it.
+    fn on_pane_hover(
+        &self,
+        index: usize,
+        callback: Box<dyn Fn(bool) + Send + 'static>,
+    ) -> Result<(), Error>;
+
+    /// Show `text` in a tooltip when hovering over a pane
+    ///
+    /// Passing an empty string removes the tooltip.
+    fn set_pane_tooltip(&self, index: usize, text: &str) -> Result<(), Error>;
+
+    /// Get the tooltip shown when hovering over a pane
+    fn pane_tooltip(&self, index: usize) -> Option<String>;
+
+    /// Call `callback` with the new size whenever a pane's content view changes size
+    ///
+    /// The content view is the pane's first subview, such as a webview, or the pane
+    /// itself if it has no subviews. Observing a pane again replaces its callback.
+    fn observe_pane_content_size(
+        &self,
+        index: usize,
+        callback: Box<dyn Fn(NSSize) + Send + 'static>,
+    ) -> Result<(), Error>;
+
+    /// Animate a layer-backed pane's content from one scale to another
+    ///
+    /// The pane's layer transform is back to identity once the animation completes.
+    /// Fails with [`Error::PaneNotLayerBacked`] if the pane has no layer.
+    fn animate_pane_zoom(
+        &self,
+        index: usize,
+        from_scale: f64,
+        to_scale: f64,
+        duration_secs: f64,
+    ) -> Result<(), Error>;
+
+    /// Cover a pane with a snapshot of its current content while the content rebuilds
+    ///
+    /// The snapshot stays until [`unfreeze_pane`](Self::unfreeze_pane) is called. Freezing
+    /// a frozen pane takes a new snapshot.
+    fn freeze_pane(&self, index: usize) -> Result<(), Error>;
+
+    /// Remove the snapshot covering a frozen pane
+    fn unfreeze_pane(&self, index: usize) -> Result<(), Error>;
+
+    /// Add a pane showing a live copy of the pane at `source_index`, returning its index
+    ///
+    /// The mirror is refreshed from a snapshot of the source about 30 times a second,
+    /// and stops updating once either pane is gone. Webview content is drawn out of
+    /// process and doesn't appear in snapshots, so only native content is mirrored.
+    fn add_mirror_pane(&self, source_index: usize) -> Result<usize, Error>;
+
+    /// Embed a new split view into a region of a pane and register it under `label`
+    ///
+    /// `region` is in the pane's coordinate space. The nested split view is independent
+    /// of this one and can be retrieved from the manager like any other split view.
+    fn add_region_split_view(
+        &self,
+        index: usize,
+        label: &str,
+        region: NSRect,
+        orientation: SplitViewOrientation,
+    ) -> Result<SplitViewHandle<R>, Error>;
+
+    /// Split a webview pane into a nested split view without reloading its webview
+    ///
+    /// The pane's view moves into the nested split view as its first pane and `new_pane` is
+    /// created next to it, a native pane as an empty view with its identifier. The nested
+    /// split view is registered in the manager and returned.
+    fn split_pane_preserving_webview(
+        &self,
+        index: usize,
+        orientation: SplitViewOrientation,
+        new_pane: PaneConfig,
+    ) -> Result<SplitViewHandle<R>, Error>;
+
+    /// Set whether the user can collapse a pane by dragging its divider
     fn set_pane_collapsible(&self, index: usize, collapsible: bool);
 
     /// Check if a pane is collapsed
     fn is_pane_collapsed(&self, index: usize) -> bool;
 
+    /// Collapse a pane, hiding it and giving its space to the other panes
+    ///
+    /// Does nothing if the pane is already collapsed.
+    fn collapse_pane(&self, index: usize) -> Result<(), Error>;
+
+    /// Expand a collapsed pane back to the extent it had when it was collapsed
+    ///
+    /// Does nothing if the pane isn't collapsed.
+    fn expand_pane(&self, index: usize) -> Result<(), Error>;
+
+    /// Expand a pane to at least `min_extent` along the split axis, respecting its maximum size
+    ///
+    /// Collapsed panes are uncollapsed. Does nothing if the pane is already large enough.
+    fn ensure_pane_visible(
+        &self,
+        index: usize,
+        min_extent: f64,
+        animated: bool,
+    ) -> Result<(), Error>;
+
+    /// Animate a sidebar pane collapsing if it's expanded, or expanding if it's collapsed
+    ///
+    /// Only the sidebar moves: the neighbouring content pane's holding priority is raised
+    /// for the duration of the animation. An expanding sidebar returns to the extent it had
+    /// when it was collapsed.
+    fn toggle_sidebar(&self, sidebar_index: usize) -> Result<(), Error>;
+
+    /// Keep a header visible along the top of a pane while it's collapsed
+    ///
+    /// The header is added as the pane's top edge accessory. When [`toggle_sidebar`]
+    /// collapses the pane, it shrinks to `height` along the split axis instead of
+    /// disappearing, which suits stacked panes in a horizontal split view.
+    ///
+    /// [`toggle_sidebar`]: Self::toggle_sidebar
+    fn set_pane_collapse_header(
+        &self,
+        index: usize,
+        header_view: objc2::rc::Retained<objc2_app_kit::NSView>,
+        height: f64,
+    ) -> Result<(), Error>;
+
+    /// Apply a pane's size, collapse, holding priority and tag settings in one call
+    ///
+    /// Only the fields that are set are applied; the rest are left as they are.
+    fn apply_pane_constraints(
+        &self,
+        index: usize,
+        constraints: PaneConstraints,
+    ) -> Result<(), Error>;
+
     /// Set minimum size for a pane
     fn set_pane_min_size(&self, index: usize, size: f64);
 
     /// Set maximum size for a pane
     fn set_pane_max_size(&self, index: usize, size: f64);
 
+    /// Keep a pane's width to height ratio at or above `min_ratio` while dragging dividers
+    fn set_pane_min_aspect(&self, index: usize, min_ratio: f64);
+
+    /// Keep a pane's width to height ratio at or below `max_ratio` while dragging dividers
+    ///
+    /// Setting the same minimum and maximum ratio fixes the pane's aspect ratio.
+    fn set_pane_max_aspect(&self, index: usize, max_ratio: f64);
+
+    /// Round a pane's size to whole backing store pixels while dragging its dividers
+    fn set_pane_pixel_aligned(&self, index: usize, aligned: bool);
+
+    /// Get the smallest extent along the split axis the panes and dividers can shrink to
+    fn minimum_content_extent(&self) -> f64;
+
+    /// Get the largest extent along the split axis the panes and dividers can grow to
+    ///
+    /// Returns `None` if any pane has no maximum size.
+    fn maximum_content_extent(&self) -> Option<f64>;
+
+    /// Suggest an extent along the split axis that fits a pane's content
+    ///
+    /// The suggestion is clamped to the pane's minimum and maximum size, ready to be
+    /// applied with `set_divider_position`. Returns `None` if the index is out of range
+    /// or the content doesn't report a size, as with webviews.
+    fn recommended_pane_width(&self, index: usize) -> Option<f64>;
+
+    /// Let a pane be resized below its content's intrinsic size
+    ///
+    /// When `ignore` is true the pane content's hugging and compression resistance
+    /// along the split axis are lowered so dragging the divider isn't blocked.
+    fn set_pane_ignores_intrinsic_size(&self, index: usize, ignore: bool) -> Result<(), Error>;
+
+    /// Give keyboard focus to a pane
+    fn focus_pane(&self, index: usize) -> Result<(), Error>;
+
+    /// Get the index of the pane containing the window's first responder
+    fn focused_pane_index(&self) -> Option<usize>;
+
+    /// Check whether the window's first responder is inside the pane at `index`
+    fn is_pane_focused(&self, index: usize) -> bool;
+
+    /// Set whether the split view itself can become first responder
+    ///
+    /// Refusing first responder lets key events pass through to the panes.
+    fn set_accepts_first_responder(&self, accepts: bool);
+
     // Window methods
     /// Get the parent window
     fn window(&self) -> Option<objc2::rc::Retained<objc2_app_kit::NSWindow>>;
+
+    /// Keep the window's content aspect ratio matching the current pane layout
+    ///
+    /// While enabled, the ratio is recomputed whenever panes are added or change presentation.
+    fn set_window_aspect_ratio_from_panes(&self, enabled: bool);
+
+    /// Ask `guard` before the window closes, vetoing the close when it returns false
+    ///
+    /// Other window delegate messages still reach the current delegate. Setting an
+    /// event handler afterwards replaces the window delegate and removes the guard.
+    fn set_close_guard(&self, guard: Box<dyn Fn(&dyn SplitView<R>) -> bool + Send + 'static>);
+
+    /// Get the vertical space taken up by the window's traffic light buttons
+    ///
+    /// Returns 0.0 for windows without window buttons, such as borderless windows.
+    fn traffic_light_inset(&self) -> f64;
+
+    /// Move a pane's content down so it starts below the traffic light buttons
+    ///
+    /// Useful for sidebars in windows with a hidden titlebar. Applying it again
+    /// updates the inset rather than adding to it.
+    fn apply_traffic_light_inset_to_pane(&self, index: usize) -> Result<(), Error>;
+
+    /// Extend the split view behind the title bar, for a full-height sidebar
+    ///
+    /// The first pane runs the full height of the window, and the other panes' content
+    /// starts below the title bar. Disabling restores the title bar and removes the insets.
+    fn set_full_height_content(&self, enabled: bool);
+
+    /// Toggle the window between its standard frame and its zoomed frame
+    ///
+    /// The panes are laid out again for the new size.
+    fn zoom(&self);
+
+    /// Check if the window is zoomed
+    fn is_zoomed(&self) -> bool;
+
+    /// Resize the window to fit the panes' preferred sizes, then center it on its screen
+    ///
+    /// The content size along the split axis is the sum of the visible panes' fitting
+    /// sizes (their current size for panes without one) plus the dividers between them.
+    /// `cross_axis_extent` sets the other dimension. Useful right after building.
+    fn size_window_to_fit_panes(&self, cross_axis_extent: f64);
+
+    /// Set how the window takes part in Spaces, Exposé and full-screen tiling
+    fn set_window_collection_behavior(&self, behavior: objc2_app_kit::NSWindowCollectionBehavior);
+
+    /// Get how the window takes part in Spaces, Exposé and full-screen tiling
+    fn window_collection_behavior(&self) -> objc2_app_kit::NSWindowCollectionBehavior;
+
+    /// Show the window on every Space rather than only the one it was opened on
+    fn set_can_join_all_spaces(&self, enabled: bool);
+
+    /// Let the window be tiled next to another full-screen window
+    fn set_full_screen_allows_tiling(&self, enabled: bool);
+
+    // Layout methods
+    /// Set how panes are resized when the split view changes size
+    fn set_resize_mode(&self, mode: ResizeMode);
+
+    /// Capture the orientation, divider positions and which panes are collapsed
+    fn snapshot(&self) -> SplitViewSnapshot;
+
+    /// Restore a layout captured by [`snapshot`](SplitView::snapshot)
+    ///
+    /// Positions and collapse states for dividers and panes the split view doesn't have
+    /// are ignored, and dividers and panes missing from the snapshot are left as they are.
+    fn apply_snapshot(&self, snapshot: &SplitViewSnapshot);
+
+    /// Write the layout to `path` as JSON whenever the panes are resized
+    ///
+    /// Writes are debounced, so a divider drag is saved once it settles rather than on
+    /// every step. Enabling autosave again replaces the previous path. Autosave relies
+    /// on the crate's split view delegate, so [`ControllerSplitView`] returns
+    /// [`Error::Unsupported`].
+    fn enable_autosave_to_path(&self, path: PathBuf) -> Result<(), Error>;
+
+    /// Restore a layout written by [`enable_autosave_to_path`](SplitView::enable_autosave_to_path)
+    fn restore_from_path(&self, path: &Path) -> Result<(), Error>;
+
+    /// Keep every pane's fraction of the split axis fixed while the split view resizes
+    ///
+    /// Unlike [`ResizeMode::Proportional`] this is a temporary lock: the fractions are
+    /// captured when locking, and the resize mode applies again once unlocked.
+    fn set_layout_locked(&self, locked: bool);
+
+    /// Give every expanded pane an equal share of the split axis
+    fn equalize_panes(&self);
+
+    /// Make the expanded panes equal again each time the split view resizes
+    ///
+    /// Unlike [`ResizeMode::Proportional`], which keeps whatever fractions the panes
+    /// have, this forces equal fractions. A locked layout takes precedence.
+    fn set_auto_equalize(&self, enabled: bool);
+
+    /// Arrange the panes in reverse, with the last pane first
+    ///
+    /// Pane indices keep their logical order, so `pane_at_index(0)` is still the first
+    /// pane added. Divider indices and positions follow the visual order.
+    fn set_panes_reversed(&self, reversed: bool);
+
+    /// Inset the whole pane arrangement from the edges of the split view's area
+    ///
+    /// The first time margins are set, the split view is moved into a container view
+    /// that takes its place, and the margins are the space between the two.
+    fn set_layout_margins(&self, top: f64, left: f64, bottom: f64, right: f64);
+
+    /// Get the `(top, left, bottom, right)` margins around the pane arrangement
+    fn layout_margins(&self) -> (f64, f64, f64, f64);
+
+    /// Apply animated changes instantly until the returned guard is dropped
+    ///
+    /// Guards can overlap; animations resume once every guard is gone.
+    fn suppress_animations(&self) -> AnimationSuppression;
+
+    /// Switch the split axis, moving each pane to its new frame over `duration_secs`
+    ///
+    /// Divider fractions are preserved across the change. A zero duration switches instantly.
+    fn set_orientation_animated(&self, orientation: SplitViewOrientation, duration_secs: f64);
+
+    /// Get how long the most recent layout pass triggered by the split view took
+    ///
+    /// Returns `None` until an operation has laid out the panes.
+    fn last_layout_duration(&self) -> Option<std::time::Duration>;
+
+    /// Run `callback` once, after the split view first lays out its panes
+    ///
+    /// Pane sizes are final when the callback runs. If the split view is in a window
+    /// and its panes have already been sized, the callback runs immediately.
+    fn on_first_layout(&self, callback: Box<dyn FnOnce(&dyn SplitView<R>) + Send + 'static>);
+
+    // Rendering methods
+    /// Mark the split view and its layer as opaque so AppKit can skip blending
+    ///
+    /// Only split views created by the crate report themselves as opaque to AppKit;
+    /// set a background color on an opaque split view so no undrawn areas show.
+    fn set_opaque(&self, opaque: bool);
+
+    /// Set the scale factor of the split view's layer contents, making it layer-backed
+    fn set_layer_contents_scale(&self, scale: f64);
+
+    /// Fill a pane's layer with an sRGB color, making the pane layer-backed
+    fn set_pane_background_color(
+        &self,
+        index: usize,
+        red: f64,
+        green: f64,
+        blue: f64,
+        alpha: f64,
+    ) -> Result<(), Error>;
+
+    /// Get the `(red, green, blue, alpha)` components of a pane's layer background color
+    ///
+    /// Components are in the device RGB color space. Returns `None` if the pane isn't
+    /// layer-backed or its layer has no background color.
+    fn pane_background_color(&self, index: usize) -> Option<(f64, f64, f64, f64)>;
+
+    /// Hide content that overflows a pane's bounds, making the pane layer-backed
+    ///
+    /// Useful for panes whose content animates or scales past its edges.
+    fn set_pane_clips_to_bounds(&self, index: usize, clips: bool) -> Result<(), Error>;
+
+    /// Get the scale from points to pixels that a pane's content renders at
+    ///
+    /// This is the window's backing scale factor, or the contents scale of a layer-backed
+    /// pane's layer. Returns `None` if the index is out of range or the pane isn't in a window.
+    fn pane_backing_scale_factor(&self, index: usize) -> Option<f64>;
+}
+
+#[cfg(target_os = "macos")]
+impl<R: Runtime> dyn SplitView<R> {
+    /// Run a closure with the view of the pane tagged `tag`
+    ///
+    /// Does nothing if no pane has the tag. This can't be part of [`SplitView`], which
+    /// is used as a trait object, because the closure is generic.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// split_view.set_pane_tag(0, "sidebar")?;
+    /// split_view.with_pane_for_tag("sidebar", |view| view.setHidden(false));
+    /// ```
+    pub fn with_pane_for_tag(&self, tag: &str, f: impl FnOnce(&objc2_app_kit::NSView)) {
+        if let Some(view) = self.pane_view_for_tag(tag) {
+            f(&view);
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+impl<R: Runtime> dyn SplitView<R> {
+    /// Run a closure with the underlying NSSplitView on the main thread
+    ///
+    /// The `MainThreadMarker` proves the caller is on the main thread, and the
+    /// reference can't outlive the closure.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tauri_nssplitview::{MainThreadMarker, ManagerExt};
+    ///
+    /// let split_view = app.get_split_view("main")?;
+    /// let mtm = MainThreadMarker::new().unwrap();
+    /// let thickness = split_view.with_raw_split_view(mtm, |raw| raw.dividerThickness());
+    /// ```
+    pub fn with_raw_split_view<T>(
+        &self,
+        _mtm: MainThreadMarker,
+        f: impl FnOnce(&objc2_app_kit::NSSplitView) -> T,
+    ) -> T {
+        f(self.as_split_view())
+    }
+
+    /// Run a closure with animated changes applied instantly
+    ///
+    /// Calls can be nested, and animations resume even if the closure panics.
+    pub fn without_animations<T>(&self, f: impl FnOnce(&Self) -> T) -> T {
+        let _suppression = self.suppress_animations();
+        f(self)
+    }
 }
 
 /// Trait for split views that can be created from a window
@@ -117,6 +844,17 @@ pub trait FromWindow<R: Runtime>: SplitView<R> + Sized {
 /// Type alias for shared split view references
 pub type SplitViewHandle<R> = Arc<dyn SplitView<R>>;
 
+/// A registered split view and the registered split views nested inside it
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitViewNode {
+    /// Label the split view is registered under
+    pub label: String,
+    /// Number of panes in the split view
+    pub pane_count: usize,
+    /// Split views whose closest registered ancestor is this one, sorted by label
+    pub children: Vec<SplitViewNode>,
+}
+
 pub struct Store<R: Runtime> {
     split_views: HashMap<String, SplitViewHandle<R>>,
 }
@@ -139,12 +877,129 @@ impl<R: Runtime> Default for SplitViewManager<R> {
 
 pub trait ManagerExt<R: Runtime> {
     fn get_split_view(&self, label: &str) -> Result<SplitViewHandle<R>, Error>;
+
+    /// Get a weak reference to a split view that doesn't keep it alive
+    ///
+    /// Once the split view is removed and every strong handle is dropped, upgrading
+    /// the reference returns `None`.
+    fn get_split_view_weak(&self, label: &str) -> Option<Weak<dyn SplitView<R>>>;
+
     fn remove_split_view(&self, label: &str) -> Option<SplitViewHandle<R>>;
+
+    /// Register the split view with the given label under `alias` as well
+    ///
+    /// Both keys share one handle, so removing either leaves the split view
+    /// reachable through the other.
+    fn alias_split_view(&self, label: &str, alias: &str) -> Result<(), Error>;
+
+    /// Get a split view handle that can only be used after claiming it on the main thread
+    #[cfg(target_os = "macos")]
+    fn get_bound_split_view(&self, label: &str) -> Result<MainThreadBound<SplitViewHandle<R>>, Error>;
+
+    /// Get the registered split views nested inside the one with the given label
+    ///
+    /// Returns `None` if no split view is registered under `root_label`.
+    #[cfg(target_os = "macos")]
+    fn split_view_tree(&self, root_label: &str) -> Option<SplitViewNode>;
+
+    /// Register the split view a window already shows, instead of converting it again
+    ///
+    /// Useful after a hot reload resets the manager while the window keeps its split
+    /// view. A window whose content view isn't a split view is converted as usual.
+    #[cfg(target_os = "macos")]
+    fn adopt_existing_split_view(&self, label: &str) -> Result<SplitViewHandle<R>, Error>;
+
+    /// Convert the webview window with the given label into a split view and register it
+    fn convert_window_to_split_view<S: FromWindow<R> + 'static>(
+        &self,
+        label: &str,
+    ) -> Result<SplitViewHandle<R>, Error>;
+
+    /// Open a new window with a split view configured like the one with the given label
+    ///
+    /// The orientation, divider thickness, window title and size are copied. Webview
+    /// panes are recreated as new webviews loading their current URLs, and native panes
+    /// with an identifier as empty views with the same identifier, in the same order.
+    /// Other panes can't be recreated and are left out with a warning.
+    fn duplicate_split_view(
+        &self,
+        label: &str,
+        new_label: &str,
+    ) -> Result<SplitViewHandle<R>, Error>;
 }
 
 #[derive(Debug)]
 pub enum Error {
     SplitViewNotFound,
+    /// No webview window exists with the given label
+    WindowNotFound,
+    /// A Tauri operation failed
+    Tauri(tauri::Error),
+    /// No pane exists at the given index
+    InvalidPaneIndex(usize),
+    /// The pane at the given index doesn't show a webview
+    NotAWebviewPane(usize),
+    /// Adding the view would make the split view a descendant of itself
+    InvalidHierarchy,
+    /// The pane at the given index isn't layer-backed
+    PaneNotLayerBacked(usize),
+    /// The split view type doesn't support the operation
+    Unsupported(&'static str),
+    /// Adding panes would exceed the split view's maximum pane count
+    MaxPanesReached(usize),
+    /// An AppKit call returned nil or otherwise failed unexpectedly
+    ObjCOperation(String),
+    /// Reading or writing a file failed
+    Io(std::io::Error),
+    /// A saved layout couldn't be parsed
+    InvalidSnapshot(String),
+    /// Split views are only available on macOS
+    UnsupportedPlatform,
+    /// A layout spec couldn't be parsed
+    InvalidSpec(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::SplitViewNotFound => write!(f, "split view not found"),
+            Error::WindowNotFound => write!(f, "window not found"),
+            Error::Tauri(error) => write!(f, "tauri error: {}", error),
+            Error::InvalidPaneIndex(index) => write!(f, "no pane at index {}", index),
+            Error::NotAWebviewPane(index) => write!(f, "pane {} does not show a webview", index),
+            Error::InvalidHierarchy => write!(f, "a split view cannot contain itself"),
+            Error::PaneNotLayerBacked(index) => write!(f, "pane {} is not layer-backed", index),
+            Error::Unsupported(operation) => {
+                write!(f, "`{}` is not supported by this split view", operation)
+            }
+            Error::MaxPanesReached(max) => write!(f, "split view is limited to {} panes", max),
+            Error::ObjCOperation(reason) => write!(f, "Objective-C operation failed: {}", reason),
+            Error::Io(error) => write!(f, "I/O error: {}", error),
+            Error::InvalidSnapshot(reason) => write!(f, "invalid layout snapshot: {}", reason),
+            Error::UnsupportedPlatform => write!(f, "split views are only supported on macOS"),
+            Error::InvalidSpec(reason) => write!(f, "invalid layout spec: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<tauri::Error> for Error {
+    fn from(error: tauri::Error) -> Self {
+        Error::Tauri(error)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<Error> for tauri::Error {
+    fn from(error: Error) -> Self {
+        tauri::Error::Io(std::io::Error::other(error))
+    }
 }
 
 impl<R: Runtime, T: Manager<R>> ManagerExt<R> for T {
@@ -158,6 +1013,12 @@ impl<R: Runtime, T: Manager<R>> ManagerExt<R> for T {
         }
     }
 
+    fn get_split_view_weak(&self, label: &str) -> Option<Weak<dyn SplitView<R>>> {
+        self.get_split_view(label)
+            .ok()
+            .map(|split_view| Arc::downgrade(&split_view))
+    }
+
     fn remove_split_view(&self, label: &str) -> Option<SplitViewHandle<R>> {
         self.state::<self::SplitViewManager<R>>()
             .0
@@ -166,6 +1027,221 @@ impl<R: Runtime, T: Manager<R>> ManagerExt<R> for T {
             .split_views
             .remove(label)
     }
+
+    fn alias_split_view(&self, label: &str, alias: &str) -> Result<(), Error> {
+        let manager = self.state::<self::SplitViewManager<R>>();
+        let mut manager = manager.0.lock().unwrap();
+
+        let split_view = manager
+            .split_views
+            .get(label)
+            .cloned()
+            .ok_or(Error::SplitViewNotFound)?;
+        manager.split_views.insert(alias.to_string(), split_view);
+        Ok(())
+    }
+
+    #[cfg(target_os = "macos")]
+    fn get_bound_split_view(&self, label: &str) -> Result<MainThreadBound<SplitViewHandle<R>>, Error> {
+        self.get_split_view(label).map(MainThreadBound::bind)
+    }
+
+    #[cfg(target_os = "macos")]
+    fn split_view_tree(&self, root_label: &str) -> Option<SplitViewNode> {
+        let root = self.get_split_view(root_label).ok()?;
+
+        // Release the lock before calling into AppKit, listing aliased split views once
+        let mut registered: Vec<SplitViewHandle<R>> = Vec::new();
+        for split_view in self
+            .state::<self::SplitViewManager<R>>()
+            .0
+            .lock()
+            .unwrap()
+            .split_views
+            .values()
+        {
+            if !registered.iter().any(|seen| Arc::ptr_eq(seen, split_view)) {
+                registered.push(split_view.clone());
+            }
+        }
+
+        Some(split_view_node(&*root, &registered))
+    }
+
+    #[cfg(target_os = "macos")]
+    fn adopt_existing_split_view(&self, label: &str) -> Result<SplitViewHandle<R>, Error> {
+        let window = self
+            .get_webview_window(label)
+            .ok_or(Error::WindowNotFound)?;
+        let Some(split_view) = BasicSplitView::from_existing(&window, label.to_string())? else {
+            return Ok(window.to_split_view::<BasicSplitView<R>>()?);
+        };
+
+        let split_view = Arc::new(split_view) as SplitViewHandle<R>;
+        self.state::<self::SplitViewManager<R>>()
+            .0
+            .lock()
+            .unwrap()
+            .split_views
+            .insert(label.to_string(), split_view.clone());
+        Ok(split_view)
+    }
+
+    fn convert_window_to_split_view<S: FromWindow<R> + 'static>(
+        &self,
+        label: &str,
+    ) -> Result<SplitViewHandle<R>, Error> {
+        let window = self.get_webview_window(label).ok_or(Error::WindowNotFound)?;
+        Ok(window.to_split_view::<S>()?)
+    }
+
+    fn duplicate_split_view(
+        &self,
+        label: &str,
+        new_label: &str,
+    ) -> Result<SplitViewHandle<R>, Error> {
+        let source = self.get_split_view(label)?;
+
+        #[cfg(target_os = "macos")]
+        if source.as_any().is::<ControllerSplitView<R>>() {
+            return build_like::<R, ControllerSplitView<R>>(self.app_handle(), &*source, new_label);
+        }
+        build_like::<R, BasicSplitView<R>>(self.app_handle(), &*source, new_label)
+    }
+}
+
+/// Build a split view of type `S` under `label`, configured like `source`
+fn build_like<R: Runtime, S: FromWindow<R> + 'static>(
+    app_handle: &AppHandle<R>,
+    source: &dyn SplitView<R>,
+    label: &str,
+) -> Result<SplitViewHandle<R>, Error> {
+    let window = source.to_window().ok_or(Error::WindowNotFound)?;
+    let size = window
+        .inner_size()?
+        .to_logical::<f64>(window.scale_factor()?);
+    let orientation = if source.is_vertical() {
+        SplitViewOrientation::Vertical
+    } else {
+        SplitViewOrientation::Horizontal
+    };
+
+    #[cfg(target_os = "macos")]
+    let (panes, content_index) = duplicate_pane_configs(source, &window)?;
+    #[cfg(not(target_os = "macos"))]
+    let (panes, content_index) = (
+        vec![PaneConfig::Webview {
+            url: WebviewUrl::External(window.url()?),
+        }],
+        Some(0),
+    );
+
+    let mut builder = SplitViewBuilder::<R, S>::new(app_handle, label)
+        .title(window.title()?)
+        .size(size.into())
+        .orientation(orientation)
+        .divider_thickness(source.divider_thickness());
+    for pane in panes {
+        builder = builder.add_pane(pane);
+    }
+    if let Some(index) = content_index.filter(|&index| index > 0) {
+        builder = builder.original_content_pane_index(index);
+    }
+    Ok(builder.build()?)
+}
+
+/// Describe the panes of `source` that can be recreated, with the webview of `window` first
+///
+/// The builder loads a leading webview pane as the new window's content, so the
+/// window's webview comes first and its index among the other panes is returned to
+/// move it back to.
+#[cfg(target_os = "macos")]
+fn duplicate_pane_configs<R: Runtime>(
+    source: &dyn SplitView<R>,
+    window: &WebviewWindow<R>,
+) -> Result<(Vec<PaneConfig>, Option<usize>), Error> {
+    let webviews = source.webview_panes();
+    let mut panes = Vec::new();
+    let mut content_index = None;
+
+    for index in 0..source.pane_count() {
+        if let Some((_, webview)) = webviews.iter().find(|(pane, _)| *pane == index) {
+            if webview.label() == window.label() {
+                content_index = Some(panes.len());
+            }
+            panes.push(PaneConfig::Webview {
+                url: WebviewUrl::External(webview.url()?),
+            });
+            continue;
+        }
+
+        let identifier: Option<objc2::rc::Retained<objc2_foundation::NSString>> = source
+            .pane_at_index(index)
+            .and_then(|pane| unsafe { objc2::msg_send![&*pane, identifier] });
+        match identifier {
+            Some(identifier) => panes.push(PaneConfig::Native {
+                identifier: identifier.to_string(),
+            }),
+            None => log::warn!(
+                "Leaving out pane {} of split view `{}`, which can't be recreated",
+                index,
+                source.label()
+            ),
+        }
+    }
+
+    if let Some(index) = content_index {
+        let content = panes.remove(index);
+        panes.insert(0, content);
+    }
+    Ok((panes, content_index))
+}
+
+/// Build the tree of registered split views nested inside `split_view`
+#[cfg(target_os = "macos")]
+fn split_view_node<R: Runtime>(
+    split_view: &dyn SplitView<R>,
+    registered: &[SplitViewHandle<R>],
+) -> SplitViewNode {
+    let mut children: Vec<SplitViewNode> = registered
+        .iter()
+        .filter(|candidate| {
+            enclosing_split_view(candidate.as_split_view(), registered).is_some_and(|parent| {
+                std::ptr::eq::<objc2_app_kit::NSView>(
+                    &**parent.as_split_view(),
+                    &**split_view.as_split_view(),
+                )
+            })
+        })
+        .map(|child| split_view_node(&**child, registered))
+        .collect();
+    children.sort_by(|a, b| a.label.cmp(&b.label));
+
+    SplitViewNode {
+        label: split_view.label().to_string(),
+        pane_count: split_view.pane_count(),
+        children,
+    }
+}
+
+/// Find the registered split view that most closely encloses `view`
+#[cfg(target_os = "macos")]
+fn enclosing_split_view<'a, R: Runtime>(
+    view: &objc2_app_kit::NSView,
+    registered: &'a [SplitViewHandle<R>],
+) -> Option<&'a SplitViewHandle<R>> {
+    let mut ancestor: Option<objc2::rc::Retained<objc2_app_kit::NSView>> =
+        unsafe { objc2::msg_send![view, superview] };
+    while let Some(view) = ancestor {
+        let found = registered.iter().find(|split_view| {
+            std::ptr::eq::<objc2_app_kit::NSView>(&**split_view.as_split_view(), &*view)
+        });
+        if found.is_some() {
+            return found;
+        }
+        ancestor = unsafe { objc2::msg_send![&*view, superview] };
+    }
+    None
 }
 
 pub trait WebviewWindowExt<R: Runtime> {
@@ -201,3 +1277,56 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
         })
         .build()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    #[test]
+    fn errors_describe_themselves() {
+        assert_eq!(Error::InvalidPaneIndex(3).to_string(), "no pane at index 3");
+        assert_eq!(
+            Error::Unsupported("set_pane_presentation").to_string(),
+            "`set_pane_presentation` is not supported by this split view"
+        );
+        assert_eq!(
+            Error::MaxPanesReached(2).to_string(),
+            "split view is limited to 2 panes"
+        );
+        assert_eq!(
+            Error::ObjCOperation("contentView returned nil".into()).to_string(),
+            "Objective-C operation failed: contentView returned nil"
+        );
+    }
+
+    #[test]
+    fn tauri_errors_are_wrapped() {
+        let error = Error::from(tauri::Error::WindowNotFound);
+        assert!(matches!(error, Error::Tauri(tauri::Error::WindowNotFound)));
+        assert_eq!(error.to_string(), "tauri error: window not found");
+    }
+
+    #[test]
+    fn errors_convert_into_tauri_errors() {
+        let tauri::Error::Io(io_error) = tauri::Error::from(Error::InvalidPaneIndex(1)) else {
+            panic!("expected an I/O error");
+        };
+        assert_eq!(io_error.to_string(), "no pane at index 1");
+        let inner = io_error.into_inner().unwrap();
+        assert!(matches!(
+            inner.downcast_ref::<Error>(),
+            Some(Error::InvalidPaneIndex(1))
+        ));
+    }
+
+    #[test]
+    fn converting_a_missing_window_fails() {
+        use super::{BasicSplitView, ManagerExt};
+
+        let app = tauri::test::mock_app();
+        let result = app
+            .handle()
+            .convert_window_to_split_view::<BasicSplitView<_>>("missing");
+        assert!(matches!(result, Err(Error::WindowNotFound)));
+    }
+}
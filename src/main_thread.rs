@@ -0,0 +1,126 @@
+use std::mem::ManuallyDrop;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use objc2::MainThreadMarker;
+
+static MAIN_THREAD_CHECKS: AtomicBool = AtomicBool::new(cfg!(debug_assertions));
+
+/// Enable or disable panicking when a split view is used off the main thread
+///
+/// Checks are on by default in debug builds and off in release builds. With checks
+/// enabled, an operation or query that reaches AppKit panics with its name when
+/// called from another thread, rather than silently corrupting AppKit state.
+pub fn set_main_thread_checks(enabled: bool) {
+    MAIN_THREAD_CHECKS.store(enabled, Ordering::Relaxed);
+}
+
+/// Panic if main thread checks are enabled and this isn't the main thread
+pub(crate) fn check_main_thread(operation: &str) {
+    if MAIN_THREAD_CHECKS.load(Ordering::Relaxed) {
+        assert!(
+            MainThreadMarker::new().is_some(),
+            "`{}` must be called on the main thread",
+            operation
+        );
+    }
+}
+
+/// A value that may be sent between threads but only used on the main thread
+///
+/// AppKit objects must only be touched from the main thread. Wrapping a value in
+/// `MainThreadBound` makes that requirement part of the type: the wrapper is
+/// `Send + Sync`, but the only way to reach the inner value is [`claim`], which
+/// takes a [`MainThreadMarker`] as proof of running on the main thread.
+///
+/// Dropping the wrapper drops the value, so a value bound with [`new`] must be
+/// dropped on the main thread too. Dropping it on another thread panics, leaking
+/// the value rather than dropping it there.
+///
+/// # Example
+/// ```rust
+/// use tauri_nssplitview::{MainThreadMarker, ManagerExt};
+///
+/// let bound = app.get_bound_split_view("main")?;
+///
+/// app.run_on_main_thread(move || {
+///     let mtm = MainThreadMarker::new().unwrap();
+///     bound.claim(mtm).show();
+/// })?;
+/// ```
+///
+/// [`claim`]: MainThreadBound::claim
+/// [`new`]: MainThreadBound::new
+pub struct MainThreadBound<T> {
+    value: ManuallyDrop<T>,
+    /// Whether the value is `Send + Sync`, and so can be dropped on any thread
+    drops_anywhere: bool,
+}
+
+// SAFETY: The inner value is only reachable through `claim` and `into_inner`, which
+// require a MainThreadMarker, and is only dropped on the main thread unless it's
+// `Send + Sync`, so it is never accessed from another thread.
+unsafe impl<T> Send for MainThreadBound<T> {}
+unsafe impl<T> Sync for MainThreadBound<T> {}
+
+impl<T> MainThreadBound<T> {
+    /// Bind a value to the main thread
+    pub fn new(value: T, _mtm: MainThreadMarker) -> Self {
+        Self {
+            value: ManuallyDrop::new(value),
+            drops_anywhere: false,
+        }
+    }
+
+    /// Bind a value that is already `Send + Sync` from any thread
+    pub(crate) fn bind(value: T) -> Self
+    where
+        T: Send + Sync,
+    {
+        Self {
+            value: ManuallyDrop::new(value),
+            drops_anywhere: true,
+        }
+    }
+
+    /// Get a reference to the value on the main thread
+    pub fn claim(&self, _mtm: MainThreadMarker) -> &T {
+        &self.value
+    }
+
+    /// Unwrap the value on the main thread
+    pub fn into_inner(self, _mtm: MainThreadMarker) -> T {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped, so the value is taken exactly once
+        unsafe { ManuallyDrop::take(&mut this.value) }
+    }
+}
+
+impl<T> Drop for MainThreadBound<T> {
+    fn drop(&mut self) {
+        if self.drops_anywhere || MainThreadMarker::new().is_some() {
+            // SAFETY: The value is dropped once, here, and on a thread it may be used on
+            unsafe { ManuallyDrop::drop(&mut self.value) }
+        } else if !std::thread::panicking() {
+            panic!("`MainThreadBound` values must be dropped on the main thread");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::MainThreadBound;
+
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    #[test]
+    fn send_sync_values_drop_on_any_thread() {
+        let value = Arc::new(String::from("main"));
+        let bound = MainThreadBound::bind(value.clone());
+        assert_send_sync(&bound);
+
+        std::thread::spawn(move || drop(bound)).join().unwrap();
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+}
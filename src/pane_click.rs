@@ -0,0 +1,38 @@
+use objc2::rc::Retained;
+use objc2::{define_class, msg_send, DefinedClass, MainThreadOnly};
+use objc2_app_kit::{NSClickGestureRecognizer, NSView};
+use objc2_foundation::{MainThreadMarker, NSObject, NSObjectProtocol, NSPoint};
+
+pub(crate) struct PaneClickTargetIvars {
+    callback: Box<dyn Fn(NSPoint)>,
+}
+
+define_class!(
+    /// Action target for the click gesture recognizer installed on a pane
+    #[unsafe(super(NSObject))]
+    #[name = "TauriPaneClickTarget"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = PaneClickTargetIvars]
+    pub(crate) struct PaneClickTarget;
+
+    unsafe impl NSObjectProtocol for PaneClickTarget {}
+
+    impl PaneClickTarget {
+        #[unsafe(method(handleClick:))]
+        fn handle_click(&self, recognizer: &NSClickGestureRecognizer) {
+            unsafe {
+                let view: Option<Retained<NSView>> = msg_send![recognizer, view];
+                let location: NSPoint = msg_send![recognizer, locationInView: view.as_deref()];
+                (self.ivars().callback)(location);
+            }
+        }
+    }
+);
+
+impl PaneClickTarget {
+    /// Create a target that calls `callback` with click locations in pane coordinates
+    pub(crate) fn new(mtm: MainThreadMarker, callback: Box<dyn Fn(NSPoint)>) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(PaneClickTargetIvars { callback });
+        unsafe { msg_send![super(this), init] }
+    }
+}
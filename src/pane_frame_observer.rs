@@ -0,0 +1,42 @@
+use objc2::rc::Retained;
+use objc2::{define_class, msg_send, DefinedClass, MainThreadOnly};
+use objc2_app_kit::NSView;
+use objc2_foundation::{
+    MainThreadMarker, NSNotification, NSObject, NSObjectProtocol, NSRect, NSSize,
+};
+
+pub(crate) struct PaneFrameObserverIvars {
+    callback: Box<dyn Fn(NSSize)>,
+}
+
+define_class!(
+    /// Observer for the frame change notifications of a pane's content view
+    #[unsafe(super(NSObject))]
+    #[name = "TauriPaneFrameObserver"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = PaneFrameObserverIvars]
+    pub(crate) struct PaneFrameObserver;
+
+    unsafe impl NSObjectProtocol for PaneFrameObserver {}
+
+    impl PaneFrameObserver {
+        #[unsafe(method(frameDidChange:))]
+        fn frame_did_change(&self, notification: &NSNotification) {
+            unsafe {
+                let view: Option<Retained<NSView>> = msg_send![notification, object];
+                if let Some(view) = view {
+                    let frame: NSRect = msg_send![&*view, frame];
+                    (self.ivars().callback)(frame.size);
+                }
+            }
+        }
+    }
+);
+
+impl PaneFrameObserver {
+    /// Create an observer that calls `callback` with the observed view's new size
+    pub(crate) fn new(mtm: MainThreadMarker, callback: Box<dyn Fn(NSSize)>) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(PaneFrameObserverIvars { callback });
+        unsafe { msg_send![super(this), init] }
+    }
+}
@@ -0,0 +1,39 @@
+use objc2::rc::Retained;
+use objc2::{define_class, msg_send, DefinedClass, MainThreadOnly};
+use objc2_app_kit::NSEvent;
+use objc2_foundation::{MainThreadMarker, NSObject, NSObjectProtocol};
+
+pub(crate) struct PaneHoverTargetIvars {
+    callback: Box<dyn Fn(bool)>,
+}
+
+define_class!(
+    /// Owner of the tracking area installed on a pane to report hover changes
+    #[unsafe(super(NSObject))]
+    #[name = "TauriPaneHoverTarget"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = PaneHoverTargetIvars]
+    pub(crate) struct PaneHoverTarget;
+
+    unsafe impl NSObjectProtocol for PaneHoverTarget {}
+
+    impl PaneHoverTarget {
+        #[unsafe(method(mouseEntered:))]
+        fn mouse_entered(&self, _event: &NSEvent) {
+            (self.ivars().callback)(true);
+        }
+
+        #[unsafe(method(mouseExited:))]
+        fn mouse_exited(&self, _event: &NSEvent) {
+            (self.ivars().callback)(false);
+        }
+    }
+);
+
+impl PaneHoverTarget {
+    /// Create a target that calls `callback` with whether the mouse is over the pane
+    pub(crate) fn new(mtm: MainThreadMarker, callback: Box<dyn Fn(bool)>) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(PaneHoverTargetIvars { callback });
+        unsafe { msg_send![super(this), init] }
+    }
+}
@@ -0,0 +1,56 @@
+use objc2::rc::{Retained, Weak};
+use objc2::{define_class, msg_send, DefinedClass, MainThreadOnly};
+use objc2_app_kit::{NSImageView, NSView};
+use objc2_foundation::{MainThreadMarker, NSObject, NSObjectProtocol, NSTimer};
+
+use crate::splitview::snapshot_view;
+
+pub(crate) struct PaneMirrorIvars {
+    source: Weak<NSView>,
+    mirror: Weak<NSImageView>,
+}
+
+define_class!(
+    /// Timer target copying snapshots of a pane into its mirror
+    #[unsafe(super(NSObject))]
+    #[name = "TauriPaneMirror"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = PaneMirrorIvars]
+    pub(crate) struct PaneMirror;
+
+    unsafe impl NSObjectProtocol for PaneMirror {}
+
+    impl PaneMirror {
+        #[unsafe(method(refresh:))]
+        fn refresh(&self, timer: &NSTimer) {
+            let (Some(source), Some(mirror)) =
+                (self.ivars().source.load(), self.ivars().mirror.load())
+            else {
+                // The timer retains its target, so stop it once either view is gone
+                timer.invalidate();
+                return;
+            };
+
+            if let Ok(image) = snapshot_view(&source) {
+                unsafe {
+                    let _: () = msg_send![&*mirror, setImage: &*image];
+                }
+            }
+        }
+    }
+);
+
+impl PaneMirror {
+    /// Create a target that copies `source` into `mirror` each time it's refreshed
+    pub(crate) fn new(
+        mtm: MainThreadMarker,
+        source: &NSView,
+        mirror: &NSImageView,
+    ) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(PaneMirrorIvars {
+            source: Weak::new(source),
+            mirror: Weak::new(mirror),
+        });
+        unsafe { msg_send![super(this), init] }
+    }
+}
@@ -1,14 +1,308 @@
 use std::any::Any;
-use std::cell::{OnceCell, RefCell};
+use std::cell::{Cell, OnceCell, RefCell};
+use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use objc2::rc::Retained;
 use objc2::runtime::{AnyObject, ProtocolObject};
-use objc2::ClassType;
-use objc2_app_kit::{NSSplitView, NSView, NSWindow, NSWindowDelegate};
-use objc2_foundation::NSRect;
-use tauri::{AppHandle, Manager, Runtime, WebviewWindow};
+use objc2::{AnyThread, ClassType, DefinedClass, MainThreadOnly, Message};
+use objc2_app_kit::{
+    NSBitmapImageRep, NSClickGestureRecognizer, NSColor, NSColorSpace, NSImage, NSImageScaling,
+    NSImageView, NSLayoutConstraintOrientation, NSLayoutPriorityDefaultHigh,
+    NSLayoutPriorityDefaultLow, NSResponder, NSSplitView, NSSplitViewDividerStyle, NSTrackingArea,
+    NSTrackingAreaOptions, NSUserInterfaceLayoutDirection, NSView,
+    NSViewFrameDidChangeNotification, NSWindow, NSWindowButton, NSWindowCollectionBehavior,
+    NSWindowDelegate, NSWindowOrderingMode, NSWindowStyleMask,
+};
+use objc2_foundation::{
+    MainThreadMarker, NSArray, NSData, NSNotificationCenter, NSNumber, NSPoint, NSRect, NSSize,
+    NSString, NSTimer,
+};
+use serde::{Deserialize, Serialize};
+use tauri::{
+    AppHandle, Emitter, Manager, Runtime, WebviewUrl, WebviewWindow, WebviewWindowBuilder,
+};
 
-use crate::{FromWindow, SplitView};
+use crate::close_guard::CloseGuardDelegate;
+use crate::deferred::DeferredCallback;
+use crate::delegate::{
+    divider_span, equal_fractions, layout_snapshot, pane_fractions, remove_divider_tracking_areas,
+    resize_proportionally, LayoutState, PaneKey, SplitViewDelegate,
+};
+use crate::main_thread::check_main_thread;
+use crate::pane_click::PaneClickTarget;
+use crate::pane_frame_observer::PaneFrameObserver;
+use crate::pane_hover::PaneHoverTarget;
+use crate::pane_mirror::PaneMirror;
+use crate::tauri_split_view::TauriSplitView;
+use crate::trace::trace_operation;
+use crate::{
+    Error, FromWindow, ManagerExt, PaneConfig, SplitView, SplitViewHandle, SplitViewManager,
+    SplitViewOrientation,
+};
+
+/// Seconds between refreshes of a mirror pane
+const MIRROR_REFRESH_INTERVAL: f64 = 1.0 / 30.0;
+
+/// Extent a sidebar expands to when it wasn't collapsed by `toggle_sidebar`
+const DEFAULT_SIDEBAR_EXTENT: f64 = 240.0;
+
+/// How panes are resized when the split view itself changes size
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ResizeMode {
+    /// Let AppKit distribute the change according to holding priorities
+    #[default]
+    HoldingPriority,
+    /// Distribute the change across panes by their current fractions
+    Proportional,
+}
+
+/// How a pane is presented in the split view
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PanePresentation {
+    /// The pane takes up space in the layout next to the other panes
+    #[default]
+    Inline,
+    /// The pane floats over its neighbour without taking up layout space
+    Overlay,
+}
+
+/// What a pane's view holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaneKind {
+    /// The pane is or contains a webview
+    Webview,
+    /// A native view added through the crate
+    Native,
+    /// A view added some other way, such as with raw AppKit messages
+    Unknown,
+}
+
+/// Preset spacing between panes, from tight to roomy
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Density {
+    /// One point dividers
+    Compact,
+    /// Four point dividers
+    #[default]
+    Regular,
+    /// Eight point dividers
+    Spacious,
+}
+
+impl Density {
+    /// Get the divider thickness, in points, the density maps to
+    pub fn divider_thickness(self) -> f64 {
+        match self {
+            Density::Compact => 1.0,
+            Density::Regular => 4.0,
+            Density::Spacious => 8.0,
+        }
+    }
+}
+
+/// Visual style of the dividers
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DividerStyle {
+    /// A thick divider with a dimple
+    Thick,
+    /// A thin, one point divider
+    #[default]
+    Thin,
+    /// A thick divider without a dimple
+    PaneSplitter,
+}
+
+impl DividerStyle {
+    fn to_ns(self) -> NSSplitViewDividerStyle {
+        match self {
+            DividerStyle::Thick => NSSplitViewDividerStyle::Thick,
+            DividerStyle::Thin => NSSplitViewDividerStyle::Thin,
+            DividerStyle::PaneSplitter => NSSplitViewDividerStyle::PaneSplitter,
+        }
+    }
+
+    fn from_ns(style: NSSplitViewDividerStyle) -> Self {
+        match style {
+            NSSplitViewDividerStyle::Thick => DividerStyle::Thick,
+            NSSplitViewDividerStyle::PaneSplitter => DividerStyle::PaneSplitter,
+            _ => DividerStyle::Thin,
+        }
+    }
+}
+
+/// An edge of a pane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Edge {
+    /// Returns true if the edge runs along the pane's height
+    fn is_horizontal(&self) -> bool {
+        matches!(self, Edge::Left | Edge::Right)
+    }
+
+    /// Returns true if the edge is at the minimum coordinate of a view's bounds
+    fn is_at_min(&self, flipped: bool) -> bool {
+        match self {
+            Edge::Left => true,
+            Edge::Right => false,
+            Edge::Top => flipped,
+            Edge::Bottom => !flipped,
+        }
+    }
+}
+
+/// A divider position along the split axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DividerPosition {
+    /// Offset from the split view's origin in points
+    Points(f64),
+    /// Fraction (0.0-1.0) of the split view's extent, measured from the leading edge
+    Fraction(f64),
+}
+
+impl DividerPosition {
+    /// Get the position in points for a split view of the given size
+    fn to_points(self, size: NSSize, vertical: bool, right_to_left: bool, thickness: f64) -> f64 {
+        match self {
+            DividerPosition::Points(points) => points,
+            DividerPosition::Fraction(fraction) => {
+                if !vertical {
+                    fraction * size.height
+                } else if right_to_left {
+                    // Match divider_info, which measures RTL fractions from the right edge
+                    size.width * (1.0 - fraction) - thickness
+                } else {
+                    fraction * size.width
+                }
+            }
+        }
+    }
+}
+
+/// A snapshot of a divider's position and state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DividerInfo {
+    /// Index of the divider (divider `i` sits between panes `i` and `i + 1`)
+    pub index: usize,
+    /// Position of the divider along the split axis in points
+    pub position_points: f64,
+    /// Position of the divider as a fraction (0.0-1.0) of the split view's extent,
+    /// measured from the leading edge
+    pub fraction: f64,
+    /// Thickness of the divider in points
+    pub thickness: f64,
+    /// Whether the divider currently has room to be dragged
+    pub is_draggable: bool,
+}
+
+/// A split view's layout, captured by [`SplitView::snapshot`]
+///
+/// Serializes to JSON for [`SplitView::enable_autosave_to_path`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitViewSnapshot {
+    /// Whether the panes are side by side
+    pub vertical: bool,
+    /// Position of each divider along the split axis in points, as reported by
+    /// [`SplitView::get_divider_position`]
+    pub divider_positions: Vec<f64>,
+    /// Whether each pane is collapsed, by pane index
+    #[serde(default)]
+    pub collapsed: Vec<bool>,
+}
+
+/// Layout constraints for a pane, applied together by [`SplitView::apply_pane_constraints`]
+///
+/// Fields left as `None` keep the pane's current setting.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PaneConstraints {
+    /// Minimum extent along the split axis in points
+    pub min: Option<f64>,
+    /// Maximum extent along the split axis in points
+    pub max: Option<f64>,
+    /// Whether the pane can collapse
+    pub collapsible: Option<bool>,
+    /// How strongly the pane keeps its size when the split view resizes
+    pub holding_priority: Option<f32>,
+    /// Tag to look the pane up by, see [`SplitView::set_pane_tag`]
+    pub tag: Option<String>,
+}
+
+/// A pane's frame, sent to the frontend by [`SplitView::emit_pane_geometry_events`]
+///
+/// Frames are `(x, y, width, height)` in points, with AppKit's origin at the bottom left.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaneGeometry {
+    /// Index of the pane
+    pub index: usize,
+    /// Whether the pane is collapsed
+    pub collapsed: bool,
+    /// Frame in the window's coordinates
+    pub window_frame: (f64, f64, f64, f64),
+    /// Frame in screen coordinates
+    pub screen_frame: (f64, f64, f64, f64),
+}
+
+/// Keeps animated changes applying instantly until dropped, see
+/// [`SplitView::suppress_animations`]
+///
+/// Animations resume when the last outstanding guard is dropped, including while
+/// unwinding from a panic.
+#[must_use = "animations resume as soon as the guard is dropped"]
+pub struct AnimationSuppression {
+    depth: Rc<Cell<usize>>,
+}
+
+impl AnimationSuppression {
+    fn new(depth: Rc<Cell<usize>>) -> Self {
+        depth.set(depth.get() + 1);
+        Self { depth }
+    }
+}
+
+impl Drop for AnimationSuppression {
+    fn drop(&mut self) {
+        self.depth.set(self.depth.get() - 1);
+    }
+}
+
+/// A change to a split view, delivered through [`SplitView::events`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SplitViewEvent {
+    /// A divider moved to a new position in points
+    DividerMoved { divider_index: usize, position: f64 },
+    /// The pane at the given index collapsed
+    PaneCollapsed(usize),
+    /// The collapsed pane at the given index expanded
+    PaneExpanded(usize),
+    /// A pane was added at the given index
+    PaneAdded(usize),
+    /// A pane moved from one index to another
+    PaneMoved { from: usize, to: usize },
+    /// The split axis changed
+    OrientationChanged { vertical: bool },
+}
+
+/// Type alias for a pane's click recognizer and the target it calls
+type PaneClickHandler = (
+    Retained<NSClickGestureRecognizer>,
+    Retained<PaneClickTarget>,
+);
+
+/// Type alias for a pane's hover tracking area and the owner it messages
+type PaneHoverHandler = (Retained<NSTrackingArea>, Retained<PaneHoverTarget>);
 
 /// A basic split view implementation
 ///
@@ -18,8 +312,45 @@ pub struct BasicSplitView<R: Runtime = tauri::Wry> {
     split_view: Retained<NSSplitView>,
     label: String,
     app_handle: AppHandle<R>,
+    /// Panes showing a window's webview, with the label of that window
+    webview_panes: RefCell<Vec<(Retained<NSView>, String)>>,
+    /// Pane changes waiting for the next turn of the run loop, keyed by the pane's view
+    pending_pane_changes: Rc<RefCell<Vec<(Retained<NSView>, Box<dyn FnOnce()>)>>>,
+    pane_change_commit: OnceCell<Retained<DeferredCallback>>,
+    /// Tags naming panes independently of their index
+    pane_tags: RefCell<Vec<(Retained<NSView>, String)>>,
+    /// Extents of collapsed panes before they were collapsed, keyed by the pane's view
+    collapsed_extents: RefCell<Vec<(Retained<NSView>, f64)>>,
     original_delegate: OnceCell<Retained<ProtocolObject<dyn NSWindowDelegate>>>,
     event_handler: RefCell<Option<Retained<ProtocolObject<dyn NSWindowDelegate>>>>,
+    layout: Rc<LayoutState>,
+    split_view_delegate: OnceCell<Retained<SplitViewDelegate>>,
+    aspect_ratio_from_panes: Cell<bool>,
+    last_layout_duration: Cell<Option<Duration>>,
+    edge_accessories: RefCell<HashMap<(PaneKey, Edge), (Retained<NSView>, f64)>>,
+    traffic_light_insets: RefCell<HashMap<PaneKey, f64>>,
+    /// Insets keeping panes below the title bar in a full-height layout, keyed by pane
+    title_bar_insets: RefCell<HashMap<PaneKey, f64>>,
+    /// Extents of sidebars collapsed by `toggle_sidebar`, keyed by pane
+    sidebar_extents: RefCell<HashMap<PaneKey, f64>>,
+    /// Extents panes collapse to so their header stays visible, keyed by pane
+    collapse_headers: RefCell<HashMap<PaneKey, f64>>,
+    max_panes: Cell<Option<usize>>,
+    pane_click_handlers: RefCell<HashMap<PaneKey, PaneClickHandler>>,
+    pane_hover_handlers: RefCell<HashMap<PaneKey, PaneHoverHandler>>,
+    close_guard: RefCell<Option<Retained<CloseGuardDelegate>>>,
+    tracked_pane_count: Cell<usize>,
+    user_data: Mutex<Option<Arc<dyn Any + Send + Sync>>>,
+    content_size_observers: RefCell<HashMap<PaneKey, Retained<PaneFrameObserver>>>,
+    frozen_panes: RefCell<HashMap<PaneKey, Retained<NSImageView>>>,
+    /// Views added as panes through the crate
+    native_panes: RefCell<Vec<objc2::rc::Weak<NSView>>>,
+    /// Margins around the pane arrangement, as top, left, bottom and right
+    layout_margins: Cell<(f64, f64, f64, f64)>,
+    /// View holding the split view once layout margins are set
+    margin_container: RefCell<Option<Retained<NSView>>>,
+    /// Number of outstanding animation suppression guards
+    animations_suppressed: Rc<Cell<usize>>,
 }
 
 // SAFETY: While NSSplitView must only be used on the main thread, we implement Send + Sync
@@ -28,6 +359,339 @@ pub struct BasicSplitView<R: Runtime = tauri::Wry> {
 unsafe impl<R: Runtime> Send for BasicSplitView<R> {}
 unsafe impl<R: Runtime> Sync for BasicSplitView<R> {}
 
+/// Create a hidden window showing a webview that loads `url`, and take its content view
+///
+/// The window keeps owning the webview, so the webview stays registered under `label`
+/// while its view is shown elsewhere.
+pub(crate) fn take_webview_view<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    label: &str,
+    url: WebviewUrl,
+) -> tauri::Result<Retained<NSView>> {
+    let window = WebviewWindowBuilder::new(app_handle, label, url)
+        .visible(false)
+        .build()?;
+    let ns_window = window.ns_window()? as *mut AnyObject;
+
+    unsafe {
+        let content_view: Option<Retained<NSView>> = objc2::msg_send![ns_window, contentView];
+        let content_view = non_nil(content_view, "contentView")?;
+
+        // Give the window an empty content view, so the webview's only superview is the pane
+        let frame: NSRect = objc2::msg_send![&*content_view, frame];
+        let placeholder: Retained<NSView> =
+            objc2::msg_send![NSView::alloc(content_view.mtm()), initWithFrame: frame];
+        let _: () = objc2::msg_send![ns_window, setContentView: &*placeholder];
+
+        Ok(content_view)
+    }
+}
+
+/// Create the view for a new pane described by `config`
+///
+/// Webview panes show a new webview registered under `label`. Native panes are an empty view
+/// with the configured identifier, for the app to fill in.
+pub(crate) fn pane_view_from_config<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    label: &str,
+    config: &PaneConfig,
+    mtm: MainThreadMarker,
+) -> tauri::Result<Retained<NSView>> {
+    match config {
+        PaneConfig::Webview { url } => take_webview_view(app_handle, label, url.clone()),
+        PaneConfig::Native { identifier } => {
+            let view = NSView::new(mtm);
+            unsafe {
+                let _: () =
+                    objc2::msg_send![&*view, setIdentifier: &*NSString::from_str(identifier)];
+            }
+            Ok(view)
+        }
+    }
+}
+
+/// Convert an unexpected nil result of `selector` into an error
+pub(crate) fn non_nil<T>(value: Option<T>, selector: &str) -> Result<T, Error> {
+    value.ok_or_else(|| Error::ObjCOperation(format!("`{}` unexpectedly returned nil", selector)))
+}
+
+/// Run `f`, converting a raised Objective-C exception into an error instead of aborting
+pub(crate) fn catch_objc_exception<T>(f: impl FnOnce() -> T) -> Result<T, Error> {
+    objc2::exception::catch(AssertUnwindSafe(f)).map_err(|exception| {
+        Error::ObjCOperation(match exception {
+            Some(exception) => exception.to_string(),
+            None => "a nil exception was raised".to_string(),
+        })
+    })
+}
+
+/// Check that a hosting view is an NSView and make it fill its pane
+pub(crate) fn prepare_hosting_view(view: &NSView) -> Result<(), Error> {
+    unsafe {
+        // Hosting views often come from Swift through an untyped pointer
+        let is_view: bool = objc2::msg_send![view, isKindOfClass: NSView::class()];
+        if !is_view {
+            return Err(Error::ObjCOperation(
+                "hosting view is not an NSView subclass".to_string(),
+            ));
+        }
+
+        let resize_mask = objc2_app_kit::NSAutoresizingMaskOptions::ViewWidthSizable
+            | objc2_app_kit::NSAutoresizingMaskOptions::ViewHeightSizable;
+        let _: () = objc2::msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: true];
+        let _: () = objc2::msg_send![view, setAutoresizingMask: resize_mask];
+    }
+    Ok(())
+}
+
+/// Ensure adding `view` as a pane won't make `split_view` a descendant of itself
+pub(crate) fn check_hierarchy(split_view: &NSSplitView, view: &NSView) -> Result<(), Error> {
+    // isDescendantOf: also returns true when both are the same view
+    let cycle: bool = unsafe { objc2::msg_send![split_view, isDescendantOf: view] };
+    if cycle {
+        Err(Error::InvalidHierarchy)
+    } else {
+        Ok(())
+    }
+}
+
+/// Collapse or expand panes to match `collapsed`, ignoring entries past the last pane
+pub(crate) fn restore_collapsed<R: Runtime>(split_view: &dyn SplitView<R>, collapsed: &[bool]) {
+    for (index, &collapsed) in collapsed.iter().take(split_view.pane_count()).enumerate() {
+        if collapsed == split_view.is_pane_collapsed(index) {
+            continue;
+        }
+        let result = if collapsed {
+            split_view.collapse_pane(index)
+        } else {
+            split_view.expand_pane(index)
+        };
+        if let Err(error) = result {
+            log::warn!(
+                "Failed to restore the collapse state of pane {} of split view `{}`: {}",
+                index,
+                split_view.label(),
+                error
+            );
+        }
+    }
+}
+
+/// Write a snapshot to `path` as JSON
+fn write_snapshot(path: &Path, snapshot: &SplitViewSnapshot) -> Result<(), Error> {
+    let json = serde_json::to_vec_pretty(snapshot)
+        .map_err(|error| Error::InvalidSnapshot(error.to_string()))?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Shrink `frame` by `amount` from the given edge, growing it for a negative amount
+fn shrink_from_edge(mut frame: NSRect, edge: Edge, amount: f64, flipped: bool) -> NSRect {
+    if edge.is_horizontal() {
+        if edge.is_at_min(flipped) {
+            frame.origin.x += amount;
+        }
+        frame.size.width -= amount;
+    } else {
+        if edge.is_at_min(flipped) {
+            frame.origin.y += amount;
+        }
+        frame.size.height -= amount;
+    }
+    frame
+}
+
+/// Get the strip of `bounds` that is `thickness` deep along the given edge
+fn edge_strip(bounds: NSRect, edge: Edge, thickness: f64, flipped: bool) -> NSRect {
+    let mut strip = bounds;
+    if edge.is_horizontal() {
+        if !edge.is_at_min(flipped) {
+            strip.origin.x += bounds.size.width - thickness;
+        }
+        strip.size.width = thickness;
+    } else {
+        if !edge.is_at_min(flipped) {
+            strip.origin.y += bounds.size.height - thickness;
+        }
+        strip.size.height = thickness;
+    }
+    strip
+}
+
+/// Describe a view as its class name and frame
+fn describe_view(view: &NSView) -> String {
+    let frame: NSRect = unsafe { objc2::msg_send![view, frame] };
+    format!(
+        "{} ({}, {}, {}, {})",
+        view.class().name().to_string_lossy(),
+        frame.origin.x,
+        frame.origin.y,
+        frame.size.width,
+        frame.size.height
+    )
+}
+
+/// Make a view layer-backed and fill its layer with an sRGB `(red, green, blue, alpha)` color
+fn set_layer_background_color(view: &NSView, rgba: (f64, f64, f64, f64)) {
+    let (red, green, blue, alpha) = rgba;
+
+    unsafe {
+        let _: () = objc2::msg_send![view, setWantsLayer: true];
+        let layer: Option<Retained<AnyObject>> = objc2::msg_send![view, layer];
+        if let Some(layer) = layer {
+            let color: Retained<NSColor> = objc2::msg_send![
+                NSColor::class(),
+                colorWithSRGBRed: red,
+                green: green,
+                blue: blue,
+                alpha: alpha
+            ];
+            let cg_color: *mut AnyObject = objc2::msg_send![&*color, CGColor];
+            let _: () = objc2::msg_send![&*layer, setBackgroundColor: cg_color];
+        }
+    }
+}
+
+/// Count a split view's subviews as AppKit sees them
+fn subview_count(split_view: &NSSplitView) -> usize {
+    unsafe {
+        let subviews: Retained<NSArray<NSView>> = objc2::msg_send![split_view, subviews];
+        objc2::msg_send![&*subviews, count]
+    }
+}
+
+/// Check whether AppKit has sized every expanded arranged pane
+pub(crate) fn panes_sized(split_view: &NSSplitView) -> bool {
+    unsafe {
+        let arranged: Retained<NSArray<NSView>> = objc2::msg_send![split_view, arrangedSubviews];
+        let count: usize = objc2::msg_send![&*arranged, count];
+        count > 0
+            && (0..count).all(|i| {
+                let view: Retained<NSView> = objc2::msg_send![&*arranged, objectAtIndex: i];
+                let collapsed: bool = objc2::msg_send![split_view, isSubviewCollapsed: &*view];
+                let frame: NSRect = objc2::msg_send![&*view, frame];
+                collapsed || (frame.size.width > 0.0 && frame.size.height > 0.0)
+            })
+    }
+}
+
+/// Measure every pane's frame in window and screen coordinates, in pane index order
+///
+/// Returns `None` if the split view isn't in a window.
+fn pane_geometry(split_view: &NSSplitView, layout: &LayoutState) -> Option<Vec<PaneGeometry>> {
+    let rect = |r: NSRect| (r.origin.x, r.origin.y, r.size.width, r.size.height);
+    let reversed = layout.panes_reversed.get();
+    unsafe {
+        let window: Option<Retained<NSWindow>> = objc2::msg_send![split_view, window];
+        let window = window?;
+        let subviews = layout.ordered_subviews(split_view);
+        let count = subviews.len();
+
+        let mut panes: Vec<PaneGeometry> = subviews
+            .iter()
+            .enumerate()
+            .map(|(position, view)| {
+                let collapsed: bool = objc2::msg_send![split_view, isSubviewCollapsed: &**view];
+                let bounds: NSRect = objc2::msg_send![&**view, bounds];
+                let in_window: NSRect = objc2::msg_send![
+                    &**view,
+                    convertRect: bounds,
+                    toView: Option::<&NSView>::None
+                ];
+                let on_screen: NSRect = objc2::msg_send![&*window, convertRectToScreen: in_window];
+                PaneGeometry {
+                    index: if reversed {
+                        count - 1 - position
+                    } else {
+                        position
+                    },
+                    collapsed,
+                    window_frame: rect(in_window),
+                    screen_frame: rect(on_screen),
+                }
+            })
+            .collect();
+        panes.sort_by_key(|pane| pane.index);
+        Some(panes)
+    }
+}
+
+/// Find the first view in `view`'s hierarchy that accepts first responder status
+fn first_key_view(view: &NSView) -> Option<Retained<NSView>> {
+    unsafe {
+        let accepts: bool = objc2::msg_send![view, acceptsFirstResponder];
+        if accepts {
+            return Some(view.retain());
+        }
+
+        let subviews: Retained<NSArray<NSView>> = objc2::msg_send![view, subviews];
+        let count: usize = objc2::msg_send![&*subviews, count];
+        (0..count).find_map(|i| {
+            let subview: Retained<NSView> = objc2::msg_send![&*subviews, objectAtIndex: i];
+            first_key_view(&subview)
+        })
+    }
+}
+
+/// Render `view` into an image of its current contents
+pub(crate) fn snapshot_view(view: &NSView) -> Result<Retained<NSImage>, Error> {
+    unsafe {
+        let bounds: NSRect = objc2::msg_send![view, bounds];
+        let bitmap: Option<Retained<NSBitmapImageRep>> =
+            objc2::msg_send![view, bitmapImageRepForCachingDisplayInRect: bounds];
+        let bitmap = non_nil(bitmap, "bitmapImageRepForCachingDisplayInRect:")?;
+        let _: () = objc2::msg_send![view, cacheDisplayInRect: bounds, toBitmapImageRep: &*bitmap];
+
+        let image: Retained<NSImage> =
+            objc2::msg_send![NSImage::alloc(), initWithSize: bounds.size];
+        let _: () = objc2::msg_send![&*image, addRepresentation: &*bitmap];
+        Ok(image)
+    }
+}
+
+/// Measure the extent `view`'s content needs along the split axis
+///
+/// Uses the view's fitting size, falling back to the widest intrinsic size of its
+/// subviews. Returns `None` if neither reports a size, as with webviews.
+pub(crate) fn fitting_extent(view: &NSView, vertical: bool) -> Option<f64> {
+    let extent = |size: NSSize| if vertical { size.width } else { size.height };
+    unsafe {
+        let fitting: NSSize = objc2::msg_send![view, fittingSize];
+        if extent(fitting) > 0.0 {
+            return Some(extent(fitting));
+        }
+
+        // NSViewNoIntrinsicMetric is negative, so unsized subviews are skipped
+        let subviews: Retained<NSArray<NSView>> = objc2::msg_send![view, subviews];
+        let count: usize = objc2::msg_send![&*subviews, count];
+        (0..count)
+            .map(|i| {
+                let subview: Retained<NSView> = objc2::msg_send![&*subviews, objectAtIndex: i];
+                let intrinsic: NSSize = objc2::msg_send![&*subview, intrinsicContentSize];
+                extent(intrinsic)
+            })
+            .filter(|&extent| extent > 0.0)
+            .max_by(f64::total_cmp)
+    }
+}
+
+/// Check whether `view` or one of its descendants is a webview
+fn contains_webview(view: &NSView) -> bool {
+    unsafe {
+        let is_webview: bool = objc2::msg_send![view, isKindOfClass: objc2::class!(WKWebView)];
+        if is_webview {
+            return true;
+        }
+
+        let subviews: Retained<NSArray<NSView>> = objc2::msg_send![view, subviews];
+        let count: usize = objc2::msg_send![&*subviews, count];
+        (0..count).any(|i| {
+            let subview: Retained<NSView> = objc2::msg_send![&*subviews, objectAtIndex: i];
+            contains_webview(&subview)
+        })
+    }
+}
+
 impl<R: Runtime> BasicSplitView<R> {
     /// Create a new BasicSplitView from a window
     pub fn new(
@@ -35,18 +699,504 @@ impl<R: Runtime> BasicSplitView<R> {
         label: String,
         app_handle: AppHandle<R>,
     ) -> Self {
+        check_main_thread("new");
+        let tracked_pane_count = subview_count(&split_view);
         Self {
             split_view,
             label,
             app_handle,
+            webview_panes: RefCell::new(Vec::new()),
+            pending_pane_changes: Rc::new(RefCell::new(Vec::new())),
+            pane_change_commit: OnceCell::new(),
+            pane_tags: RefCell::new(Vec::new()),
+            collapsed_extents: RefCell::new(Vec::new()),
             original_delegate: OnceCell::new(),
             event_handler: RefCell::new(None),
+            layout: Rc::new(LayoutState::default()),
+            split_view_delegate: OnceCell::new(),
+            aspect_ratio_from_panes: Cell::new(false),
+            last_layout_duration: Cell::new(None),
+            edge_accessories: RefCell::new(HashMap::new()),
+            traffic_light_insets: RefCell::new(HashMap::new()),
+            title_bar_insets: RefCell::new(HashMap::new()),
+            sidebar_extents: RefCell::new(HashMap::new()),
+            collapse_headers: RefCell::new(HashMap::new()),
+            max_panes: Cell::new(None),
+            pane_click_handlers: RefCell::new(HashMap::new()),
+            pane_hover_handlers: RefCell::new(HashMap::new()),
+            close_guard: RefCell::new(None),
+            tracked_pane_count: Cell::new(tracked_pane_count),
+            user_data: Mutex::new(None),
+            content_size_observers: RefCell::new(HashMap::new()),
+            frozen_panes: RefCell::new(HashMap::new()),
+            native_panes: RefCell::new(Vec::new()),
+            layout_margins: Cell::new((0.0, 0.0, 0.0, 0.0)),
+            margin_container: RefCell::new(None),
+            animations_suppressed: Rc::default(),
+        }
+    }
+
+    /// Wrap the split view that is already the window's content view
+    ///
+    /// Returns `Ok(None)` if the window's content view isn't a split view, such as a
+    /// window that was never converted.
+    pub fn from_existing(window: &WebviewWindow<R>, label: String) -> tauri::Result<Option<Self>> {
+        check_main_thread("from_existing");
+        let ns_window = window.ns_window()? as *mut AnyObject;
+        let content_view: Option<Retained<NSView>> =
+            unsafe { objc2::msg_send![ns_window, contentView] };
+
+        Ok(content_view
+            .as_deref()
+            .and_then(|view| view.downcast_ref::<NSSplitView>())
+            .map(|split_view| Self::new(split_view.retain(), label, window.app_handle().clone())))
+    }
+
+    /// Run a closure with animated changes applied instantly
+    ///
+    /// Useful when restoring a saved layout, where animating each change would flicker.
+    /// Calls can be nested, and animations resume even if the closure panics.
+    pub fn without_animations<T>(&self, f: impl FnOnce(&Self) -> T) -> T {
+        let _suppression = self.suppress_animations();
+        f(self)
+    }
+
+    /// Check whether animated changes should animate rather than apply instantly
+    pub(crate) fn animations_enabled(&self) -> bool {
+        self.animations_suppressed.get() == 0
+    }
+
+    /// Install the crate's NSSplitViewDelegate if it isn't installed yet
+    fn ensure_delegate(&self) {
+        self.split_view_delegate.get_or_init(|| {
+            trace_operation!("install_split_view_delegate", self.label);
+            let delegate = SplitViewDelegate::new(self.split_view.mtm(), self.layout.clone());
+            let installed = catch_objc_exception(|| unsafe {
+                let _: () = objc2::msg_send![&*self.split_view, setDelegate: &*delegate];
+            });
+            if let Err(error) = installed {
+                log::error!("Failed to install split view delegate: {}", error);
+            }
+            delegate.rebuild_divider_tracking_areas(&self.split_view);
+            delegate
+        });
+    }
+
+    /// Subscribe to the split view's events without installing the crate's delegate
+    ///
+    /// Divider moves and collapses are only reported while the crate's delegate is installed.
+    pub(crate) fn subscribe(&self) -> Receiver<SplitViewEvent> {
+        let (sender, receiver) = mpsc::channel();
+        *self.layout.last_layout.borrow_mut() = layout_snapshot(&self.split_view);
+        self.layout.event_senders.borrow_mut().push(sender);
+        receiver
+    }
+
+    /// Send an event to the split view's subscribers
+    pub(crate) fn emit(&self, event: SplitViewEvent) {
+        self.layout.emit(event);
+    }
+
+    /// Ensure `adding` more panes stays within the maximum pane count
+    pub(crate) fn check_pane_capacity(&self, adding: usize) -> Result<(), Error> {
+        match self.max_panes.get() {
+            Some(max) if self.pane_count() + adding > max => Err(Error::MaxPanesReached(max)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Run a layout-triggering operation, recording how long it took
+    fn timed_layout<T>(&self, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        self.last_layout_duration.set(Some(start.elapsed()));
+        result
+    }
+
+    /// Lay out the arranged panes
+    fn adjust_subviews(&self) {
+        self.timed_layout(|| unsafe {
+            let _: () = objc2::msg_send![&*self.split_view, adjustSubviews];
+        });
+    }
+
+    /// Move a pane's content, other than its edge accessories, in from `edge` by `amount`
+    fn inset_pane_content(&self, pane: &NSView, edge: Edge, amount: f64) {
+        let key = PaneKey::new(pane);
+        let accessories = self.edge_accessories.borrow();
+        let is_accessory = |view: &NSView| {
+            accessories
+                .iter()
+                .any(|((k, _), (accessory, _))| *k == key && std::ptr::eq(&**accessory, view))
+        };
+
+        unsafe {
+            let flipped: bool = objc2::msg_send![pane, isFlipped];
+            let subviews: Retained<NSArray<NSView>> = objc2::msg_send![pane, subviews];
+            let count: usize = objc2::msg_send![&*subviews, count];
+
+            for i in 0..count {
+                let subview: Retained<NSView> = objc2::msg_send![&*subviews, objectAtIndex: i];
+                if is_accessory(&subview) {
+                    continue;
+                }
+                let frame: NSRect = objc2::msg_send![&*subview, frame];
+                let frame = shrink_from_edge(frame, edge, amount, flipped);
+                let _: () = objc2::msg_send![&*subview, setFrame: frame];
+            }
+        }
+    }
+
+    /// Check whether the split view lays out its panes right to left
+    fn is_right_to_left(&self) -> bool {
+        let direction: NSUserInterfaceLayoutDirection =
+            unsafe { objc2::msg_send![&*self.split_view, userInterfaceLayoutDirection] };
+        direction == NSUserInterfaceLayoutDirection::RightToLeft
+    }
+
+    /// Get the split view as the crate's subclass, if it was created by the crate
+    fn tauri_split_view(&self) -> Option<&TauriSplitView> {
+        self.split_view.downcast_ref::<TauriSplitView>()
+    }
+
+    /// Get the key the pane at `index` keeps its per-pane state under
+    fn pane_key(&self, index: usize) -> Option<PaneKey> {
+        self.pane_at_index(index).map(|pane| PaneKey::new(&pane))
+    }
+
+    /// Get the key for a per-pane setting, logging that the setting is ignored if there's
+    /// no pane at `index`
+    fn setting_key(&self, index: usize, setting: &str) -> Option<PaneKey> {
+        let key = self.pane_key(index);
+        if key.is_none() {
+            log::warn!(
+                "Ignoring {} for missing pane {} of split view `{}`",
+                setting,
+                index,
+                self.label
+            );
+        }
+        key
+    }
+
+    /// Move the pane at `index` to the front or back of the drawing order
+    ///
+    /// Pane indices and layout slots follow the recorded layout order from then on, so
+    /// the pane keeps both.
+    fn reorder_pane_drawing(
+        &self,
+        index: usize,
+        ordering: NSWindowOrderingMode,
+    ) -> Result<(), Error> {
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        let order = self.layout.ordered_subviews(&self.split_view);
+        *self.layout.pane_order.borrow_mut() = order;
+
+        catch_objc_exception(|| unsafe {
+            let arranged: Retained<NSArray<NSView>> =
+                objc2::msg_send![&*self.split_view, arrangedSubviews];
+            let is_arranged: bool = objc2::msg_send![&*arranged, containsObject: &*pane];
+            let arranged_index: usize = objc2::msg_send![&*arranged, indexOfObject: &*pane];
+
+            // Stop the arranged subviews following the subview order before changing it
+            let _: () = objc2::msg_send![&*self.split_view, setArrangesAllSubviews: false];
+            let _: () = objc2::msg_send![
+                &*self.split_view,
+                addSubview: &*pane,
+                positioned: ordering,
+                relativeTo: Option::<&NSView>::None
+            ];
+            if is_arranged {
+                let _: () = objc2::msg_send![
+                    &*self.split_view,
+                    insertArrangedSubview: &*pane,
+                    atIndex: arranged_index as isize
+                ];
+            }
+            self.adjust_subviews();
+        })
+    }
+
+    /// Get the position in layout order of the pane at `index`
+    ///
+    /// The positions run in reverse of the pane indices while panes are reversed.
+    fn subview_position(&self, index: usize) -> Option<usize> {
+        let count = subview_count(&self.split_view);
+        (index < count).then(|| {
+            if self.layout.panes_reversed.get() {
+                count - 1 - index
+            } else {
+                index
+            }
+        })
+    }
+
+    /// Get the subview at `position` in layout order, regardless of whether panes are reversed
+    fn subview_at(&self, position: usize) -> Option<Retained<NSView>> {
+        self.layout
+            .ordered_subviews(&self.split_view)
+            .get(position)
+            .cloned()
+    }
+
+    /// Count the arranged subviews among the subviews before `position`
+    fn arranged_before(&self, position: usize) -> usize {
+        unsafe {
+            let arranged: Retained<NSArray<NSView>> =
+                objc2::msg_send![&*self.split_view, arrangedSubviews];
+            (0..position)
+                .filter_map(|position| self.subview_at(position))
+                .filter(|view| -> bool { objc2::msg_send![&*arranged, containsObject: &**view] })
+                .count()
+        }
+    }
+
+    /// Add `view` after the last pane, which is the first subview while panes are reversed
+    fn arrange_new_pane(&self, view: &NSView) {
+        unsafe {
+            if self.layout.panes_reversed.get() {
+                let _: () = objc2::msg_send![
+                    &*self.split_view,
+                    insertArrangedSubview: view,
+                    atIndex: 0isize
+                ];
+            } else {
+                let _: () = objc2::msg_send![&*self.split_view, addArrangedSubview: view];
+            }
+        }
+    }
+
+    /// Get the container holding the split view, moving the split view into one if needed
+    ///
+    /// The container takes the split view's place, as the window's content view or
+    /// as a subview of the split view's superview.
+    fn ensure_margin_container(&self) -> Option<Retained<NSView>> {
+        if let Some(container) = self.margin_container.borrow().as_ref() {
+            return Some(container.clone());
+        }
+
+        unsafe {
+            let superview: Option<Retained<NSView>> =
+                objc2::msg_send![&*self.split_view, superview];
+            let superview = superview?;
+            let frame: NSRect = objc2::msg_send![&*self.split_view, frame];
+            let mask: objc2_app_kit::NSAutoresizingMaskOptions =
+                objc2::msg_send![&*self.split_view, autoresizingMask];
+
+            let container: Retained<NSView> =
+                objc2::msg_send![NSView::alloc(self.split_view.mtm()), initWithFrame: frame];
+            let _: () = objc2::msg_send![&*container, setAutoresizingMask: mask];
+
+            if self.is_content_view() {
+                let window = self.window()?;
+                let _: () = objc2::msg_send![&*window, setContentView: &*container];
+            } else {
+                let _: () = objc2::msg_send![
+                    &*superview,
+                    replaceSubview: &*self.split_view,
+                    with: &*container
+                ];
+            }
+
+            let resize_mask = objc2_app_kit::NSAutoresizingMaskOptions::ViewWidthSizable
+                | objc2_app_kit::NSAutoresizingMaskOptions::ViewHeightSizable;
+            let _: () = objc2::msg_send![&*self.split_view, setAutoresizingMask: resize_mask];
+            let _: () = objc2::msg_send![&*container, addSubview: &*self.split_view];
+
+            *self.margin_container.borrow_mut() = Some(container.clone());
+            Some(container)
+        }
+    }
+
+    /// Remember that `view` was added as a pane through the crate
+    pub(crate) fn track_native_pane(&self, view: &NSView) {
+        let mut native_panes = self.native_panes.borrow_mut();
+        native_panes.retain(|pane| pane.load().is_some());
+        native_panes.push(objc2::rc::Weak::new(view));
+    }
+
+    /// Create a view showing a live copy of `source`, ready to be added as a pane
+    pub(crate) fn mirror_view(&self, source: &NSView) -> Result<Retained<NSView>, Error> {
+        let mtm = self.split_view.mtm();
+        let image = snapshot_view(source)?;
+
+        unsafe {
+            let bounds: NSRect = objc2::msg_send![source, bounds];
+            let mirror: Retained<NSImageView> =
+                objc2::msg_send![NSImageView::alloc(mtm), initWithFrame: bounds];
+            let _: () = objc2::msg_send![&*mirror, setImage: &*image];
+            let _: () = objc2::msg_send![
+                &*mirror,
+                setImageScaling: NSImageScaling::ScaleProportionallyUpOrDown
+            ];
+
+            // The timer retains the mirror target until it invalidates itself
+            let target = PaneMirror::new(mtm, source, &mirror);
+            let _: Retained<NSTimer> = objc2::msg_send![
+                NSTimer::class(),
+                scheduledTimerWithTimeInterval: MIRROR_REFRESH_INTERVAL,
+                target: &*target,
+                selector: objc2::sel!(refresh:),
+                userInfo: std::ptr::null::<AnyObject>(),
+                repeats: true
+            ];
+            Ok(mirror.into_super().into_super())
+        }
+    }
+
+    /// Update state that depends on the set of panes after panes were added or moved
+    pub(crate) fn panes_did_change(&self) {
+        self.tracked_pane_count.set(subview_count(&self.split_view));
+        if self.aspect_ratio_from_panes.get() {
+            self.apply_window_aspect_ratio();
+        }
+    }
+
+    /// Lock the window's content aspect ratio to the split view's current size
+    fn apply_window_aspect_ratio(&self) {
+        if let Some(window) = self.window() {
+            unsafe {
+                let bounds: NSRect = objc2::msg_send![&*self.split_view, bounds];
+                let _: () = objc2::msg_send![&*window, setContentAspectRatio: bounds.size];
+            }
+        }
+    }
+
+    /// Get the parent window, failing if the split view is no longer in one
+    pub(crate) fn require_window(&self) -> Result<Retained<NSWindow>, Error> {
+        non_nil(self.window(), "window")
+    }
+
+    /// Record that `view` shows the webview of the window registered under `label`
+    pub(crate) fn track_webview_pane(&self, view: Retained<NSView>, label: String) {
+        self.webview_panes.borrow_mut().push((view, label));
+    }
+
+    /// Check whether a window or split view already uses `label`
+    fn label_in_use(&self, label: &str) -> bool {
+        self.app_handle.get_webview_window(label).is_some()
+            || self
+                .app_handle
+                .state::<SplitViewManager<R>>()
+                .0
+                .lock()
+                .unwrap()
+                .split_views
+                .contains_key(label)
+    }
+
+    /// Make a label from `base` that no window or split view uses yet
+    fn unused_label(&self, base: &str) -> String {
+        (0..)
+            .map(|n| match n {
+                0 => base.to_string(),
+                n => format!("{}-{}", base, n),
+            })
+            .find(|label| !self.label_in_use(label))
+            .unwrap()
+    }
+
+    /// Generate the label of a new webview pane, `{label}-pane-{n}` numbered from its index
+    pub(crate) fn next_webview_pane_label(&self) -> String {
+        (self.pane_count()..)
+            .map(|n| format!("{}-pane-{}", self.label, n))
+            .find(|label| !self.label_in_use(label))
+            .unwrap()
+    }
+
+    /// Restore the orientation and divider positions of a snapshot, leaving collapse states
+    pub(crate) fn apply_snapshot_positions(&self, snapshot: &SplitViewSnapshot) {
+        if snapshot.vertical != self.is_vertical() {
+            unsafe {
+                let _: () = objc2::msg_send![&*self.split_view, setVertical: snapshot.vertical];
+                let _: () = objc2::msg_send![&*self.split_view, adjustSubviews];
+            }
+        }
+
+        let positions = snapshot
+            .divider_positions
+            .iter()
+            .take(self.pane_count().saturating_sub(1));
+        for (divider_index, position) in positions.enumerate() {
+            self.set_divider_position(divider_index, *position);
+        }
+    }
+
+    /// Register a split view nested in one of the panes under `label`
+    fn register_nested_split_view(
+        &self,
+        label: &str,
+        split_view: BasicSplitView<R>,
+    ) -> SplitViewHandle<R> {
+        let split_view = Arc::new(split_view) as SplitViewHandle<R>;
+        self.app_handle
+            .state::<SplitViewManager<R>>()
+            .0
+            .lock()
+            .unwrap()
+            .split_views
+            .insert(label.to_string(), split_view.clone());
+        split_view
+    }
+
+    /// Run `commit` on the next turn of the run loop, superseding a pending change of `pane`
+    pub(crate) fn queue_pane_change(&self, pane: Retained<NSView>, commit: Box<dyn FnOnce()>) {
+        {
+            let mut pending = self.pending_pane_changes.borrow_mut();
+            pending.retain(|(view, _)| *view != pane);
+            pending.push((pane, commit));
+        }
+
+        self.pane_change_commit
+            .get_or_init(|| {
+                let pending = self.pending_pane_changes.clone();
+                DeferredCallback::new(
+                    self.split_view.mtm(),
+                    Box::new(move || {
+                        // Take the changes first, so committing one can queue another
+                        let changes = std::mem::take(&mut *pending.borrow_mut());
+                        for (_, commit) in changes {
+                            commit();
+                        }
+                    }),
+                )
+            })
+            .schedule();
+    }
+}
+
+impl BasicSplitView {
+    /// Create a plain view with the given frame, ready to be added as a pane
+    pub fn make_view(mtm: MainThreadMarker, frame: NSRect) -> Retained<NSView> {
+        unsafe { objc2::msg_send![NSView::alloc(mtm), initWithFrame: frame] }
+    }
+
+    /// Create a layer-backed view filled with an sRGB `(red, green, blue, alpha)` color
+    pub fn make_colored_view(
+        mtm: MainThreadMarker,
+        frame: NSRect,
+        rgba: (f64, f64, f64, f64),
+    ) -> Retained<NSView> {
+        let view = Self::make_view(mtm, frame);
+        set_layer_background_color(&view, rgba);
+        view
+    }
+
+    /// Get the divider thickness AppKit uses for `style`, without creating a split view window
+    pub fn system_divider_thickness(style: DividerStyle, mtm: MainThreadMarker) -> f64 {
+        unsafe {
+            let split_view: Retained<NSSplitView> = objc2::msg_send![NSSplitView::alloc(mtm), init];
+            let _: () = objc2::msg_send![&*split_view, setDividerStyle: style.to_ns()];
+            objc2::msg_send![&*split_view, dividerThickness]
         }
     }
 }
 
 impl<R: Runtime> SplitView<R> for BasicSplitView<R> {
     fn show(&self) {
+        trace_operation!("show", self.label);
         if let Some(window) = self.window() {
             unsafe {
                 let _: () = objc2::msg_send![&*window, orderFrontRegardless];
@@ -55,6 +1205,7 @@ impl<R: Runtime> SplitView<R> for BasicSplitView<R> {
     }
 
     fn hide(&self) {
+        trace_operation!("hide", self.label);
         if let Some(window) = self.window() {
             unsafe {
                 let _: () = objc2::msg_send![&*window, orderOut: objc2::ffi::nil];
@@ -67,7 +1218,12 @@ impl<R: Runtime> SplitView<R> for BasicSplitView<R> {
         self.app_handle.get_webview_window(&self.label)
     }
 
+    fn app_handle(&self) -> AppHandle<R> {
+        self.app_handle.clone()
+    }
+
     fn as_split_view(&self) -> &NSSplitView {
+        check_main_thread("as_split_view");
         &self.split_view
     }
 
@@ -83,6 +1239,8 @@ impl<R: Runtime> SplitView<R> for BasicSplitView<R> {
         &self,
         handler: Option<&ProtocolObject<dyn NSWindowDelegate>>,
     ) {
+        trace_operation!("set_event_handler", self.label, handler);
+
         if let Some(window) = self.window() {
             unsafe {
                 match handler {
@@ -105,24 +1263,68 @@ impl<R: Runtime> SplitView<R> for BasicSplitView<R> {
                         let _: () = objc2::msg_send![&*window, setDelegate: h];
                     }
                     None => {
-                        if self.original_delegate.get().is_none() {
+                        // Clear stored handler, leaving the delegate alone if none was installed
+                        if self.event_handler.borrow_mut().take().is_none() {
                             return;
                         }
 
-                        // Clear stored handler
-                        *self.event_handler.borrow_mut() = None;
-
-                        // Restore original delegate
-                        if let Some(orig_delegate) = self.original_delegate.get() {
-                            let _: () = objc2::msg_send![&*window, setDelegate: &**orig_delegate];
-                        }
+                        // Restore original delegate, which was nil if none was captured
+                        let orig_delegate =
+                            self.original_delegate.get().map(|delegate| &**delegate);
+                        let _: () = objc2::msg_send![&*window, setDelegate: orig_delegate];
                     }
                 }
             }
         }
     }
 
+    fn events(&self) -> Receiver<SplitViewEvent> {
+        trace_operation!("events", self.label);
+        // Divider moves and collapses are reported by the delegate after each layout pass
+        self.ensure_delegate();
+        self.subscribe()
+    }
+
+    fn emit_pane_geometry_events(&self, event_name: &str) {
+        trace_operation!("emit_pane_geometry_events", self.label, event_name);
+        // Scheduled by the delegate after each layout pass
+        self.ensure_delegate();
+
+        let split_view = objc2::rc::Weak::new(&*self.split_view);
+        let layout = Rc::downgrade(&self.layout);
+        let app_handle = self.app_handle.clone();
+        let event_name = event_name.to_string();
+        let reporter = DeferredCallback::new(
+            self.split_view.mtm(),
+            Box::new(move || {
+                let (Some(split_view), Some(layout)) = (split_view.load(), layout.upgrade()) else {
+                    return;
+                };
+                let Some(panes) = pane_geometry(&split_view, &layout) else {
+                    return;
+                };
+                if let Err(error) = app_handle.emit(&event_name, panes) {
+                    log::warn!("Failed to emit `{}`: {}", event_name, error);
+                }
+            }),
+        );
+
+        // Report the current layout without waiting for the next resize
+        reporter.schedule();
+        *self.layout.geometry_reporter.borrow_mut() = Some(reporter);
+    }
+
+    fn set_user_data(&self, data: Box<dyn Any + Send + Sync>) {
+        trace_operation!("set_user_data", self.label);
+        *self.user_data.lock().unwrap() = Some(Arc::from(data));
+    }
+
+    fn user_data(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.user_data.lock().unwrap().clone()
+    }
+
     fn is_visible(&self) -> bool {
+        check_main_thread("is_visible");
         if let Some(window) = self.window() {
             unsafe { objc2::msg_send![&*window, isVisible] }
         } else {
@@ -131,43 +1333,130 @@ impl<R: Runtime> SplitView<R> for BasicSplitView<R> {
     }
 
     fn is_vertical(&self) -> bool {
+        check_main_thread("is_vertical");
         unsafe { objc2::msg_send![&*self.split_view, isVertical] }
     }
 
     fn pane_count(&self) -> usize {
-        unsafe {
-            let subviews: Retained<objc2_foundation::NSArray<NSView>> =
-                objc2::msg_send![&*self.split_view, subviews];
-            objc2::msg_send![&*subviews, count]
+        check_main_thread("pane_count");
+        let count = subview_count(&self.split_view);
+
+        // Panes added or removed with raw messages skip the crate's bookkeeping and events
+        if cfg!(debug_assertions) && count != self.tracked_pane_count.get() {
+            log::warn!(
+                "Split view `{}` has {} panes but tauri-nssplitview tracked {}, were panes added or removed outside the crate?",
+                self.label,
+                count,
+                self.tracked_pane_count.get()
+            );
         }
+        count
     }
 
-    fn set_divider_position(&self, divider_index: usize, position: f64) {
-        unsafe {
+    fn tracked_pane_count(&self) -> usize {
+        check_main_thread("tracked_pane_count");
+        self.tracked_pane_count.get()
+    }
+
+    fn is_content_view(&self) -> bool {
+        check_main_thread("is_content_view");
+        self.window().is_some_and(|window| unsafe {
+            let content_view: Option<Retained<NSView>> = objc2::msg_send![&*window, contentView];
+            content_view.is_some_and(|view| {
+                std::ptr::eq::<NSView>(&*view, &**self.split_view)
+                    || self
+                        .margin_container
+                        .borrow()
+                        .as_ref()
+                        .is_some_and(|container| std::ptr::eq::<NSView>(&*view, &**container))
+            })
+        })
+    }
+
+    fn describe_hierarchy(&self) -> String {
+        check_main_thread("describe_hierarchy");
+        let mut ancestors = Vec::new();
+        let mut current: Option<Retained<NSView>> =
+            unsafe { objc2::msg_send![&*self.split_view, superview] };
+        while let Some(view) = current {
+            current = unsafe { objc2::msg_send![&*view, superview] };
+            ancestors.push(view);
+        }
+
+        // Outermost view first, each level indented below its superview
+        let mut lines: Vec<String> = ancestors
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(depth, view)| format!("{}{}", "  ".repeat(depth), describe_view(view)))
+            .collect();
+
+        let depth = ancestors.len();
+        lines.push(format!(
+            "{}{}",
+            "  ".repeat(depth),
+            describe_view(&self.split_view)
+        ));
+        for index in 0..self.pane_count() {
+            if let Some(pane) = self.pane_at_index(index) {
+                lines.push(format!(
+                    "{}pane {}: {}",
+                    "  ".repeat(depth + 1),
+                    index,
+                    describe_view(&pane)
+                ));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    fn set_divider_position(&self, divider_index: usize, position: f64) -> f64 {
+        self.set_divider(divider_index, DividerPosition::Points(position));
+        self.get_divider_position(divider_index)
+    }
+
+    fn set_divider(&self, divider_index: usize, position: DividerPosition) {
+        trace_operation!("set_divider_position", self.label, divider_index, position);
+
+        let bounds: NSRect = unsafe { objc2::msg_send![&*self.split_view, bounds] };
+        let position = position.to_points(
+            bounds.size,
+            self.is_vertical(),
+            self.is_right_to_left(),
+            self.divider_thickness(),
+        );
+
+        self.timed_layout(|| unsafe {
             let _: () = objc2::msg_send![
                 &*self.split_view,
                 setPosition: position,
                 ofDividerAtIndex: divider_index as isize
             ];
-        }
+        });
     }
 
     fn get_divider_position(&self, divider_index: usize) -> f64 {
+        check_main_thread("get_divider_position");
         // NSSplitView doesn't have a direct method to get divider position
         // We need to calculate it from subview frames
         unsafe {
+            // Overlay panes float above the layout, so only arranged panes have dividers
             let subviews: Retained<objc2_foundation::NSArray<NSView>> =
-                objc2::msg_send![&*self.split_view, subviews];
+                objc2::msg_send![&*self.split_view, arrangedSubviews];
             let count: usize = objc2::msg_send![&*subviews, count];
 
-            if divider_index >= count - 1 {
+            if divider_index + 1 >= count {
                 return 0.0;
             }
 
             let view: Retained<NSView> = objc2::msg_send![&*subviews, objectAtIndex: divider_index];
             let frame: objc2_foundation::NSRect = objc2::msg_send![&*view, frame];
 
-            if self.is_vertical() {
+            if self.is_vertical() && self.is_right_to_left() {
+                // Panes run right to left, so the divider sits before the pane's origin
+                frame.origin.x - self.divider_thickness()
+            } else if self.is_vertical() {
                 frame.origin.x + frame.size.width
             } else {
                 frame.origin.y + frame.size.height
@@ -175,88 +1464,2372 @@ impl<R: Runtime> SplitView<R> for BasicSplitView<R> {
         }
     }
 
+    fn set_cursor_region_padding(&self, px: f64) {
+        trace_operation!("set_cursor_region_padding", self.label, px);
+        // Applied by the delegate's effectiveRect method whenever cursor rects are rebuilt
+        self.ensure_delegate();
+        self.layout.cursor_region_padding.set(px);
+
+        if let Some(window) = self.window() {
+            unsafe {
+                let _: () =
+                    objc2::msg_send![&*window, invalidateCursorRectsForView: &*self.split_view];
+            }
+        }
+    }
+
+    fn set_divider_snap_positions(
+        &self,
+        divider_index: usize,
+        positions: Vec<f64>,
+        snap_threshold: f64,
+    ) {
+        trace_operation!(
+            "set_divider_snap_positions",
+            self.label,
+            divider_index,
+            positions,
+            snap_threshold
+        );
+        self.ensure_delegate();
+
+        let mut snap_positions = self.layout.snap_positions.borrow_mut();
+        if positions.is_empty() {
+            snap_positions.remove(&divider_index);
+        } else {
+            snap_positions.insert(divider_index, (positions, snap_threshold));
+        }
+    }
+
+    fn set_user_interface_layout_direction(&self, rtl: bool) {
+        trace_operation!("set_user_interface_layout_direction", self.label, rtl);
+        let direction = if rtl {
+            NSUserInterfaceLayoutDirection::RightToLeft
+        } else {
+            NSUserInterfaceLayoutDirection::LeftToRight
+        };
+
+        unsafe {
+            let _: () =
+                objc2::msg_send![&*self.split_view, setUserInterfaceLayoutDirection: direction];
+        }
+        self.adjust_subviews();
+    }
+
+    fn active_divider(&self) -> Option<usize> {
+        check_main_thread("active_divider");
+        self.tauri_split_view()
+            .and_then(|split_view| split_view.ivars().active_divider.get())
+    }
+
+    fn divider_drag_range(&self, divider_index: usize) -> Option<(f64, f64)> {
+        check_main_thread("divider_drag_range");
+        let (min, max) = divider_span(&self.split_view, divider_index)?;
+
+        // Ask the delegate, whichever it is, so the range matches what dragging allows
+        unsafe {
+            let delegate: Option<Retained<AnyObject>> =
+                objc2::msg_send![&*self.split_view, delegate];
+            let Some(delegate) = delegate else {
+                return Some((min, max));
+            };
+
+            let constrains_min: bool = objc2::msg_send![
+                &*delegate,
+                respondsToSelector: objc2::sel!(splitView:constrainMinCoordinate:ofSubviewAt:)
+            ];
+            let min = if constrains_min {
+                objc2::msg_send![
+                    &*delegate,
+                    splitView: &*self.split_view,
+                    constrainMinCoordinate: min,
+                    ofSubviewAt: divider_index as isize
+                ]
+            } else {
+                min
+            };
+
+            let constrains_max: bool = objc2::msg_send![
+                &*delegate,
+                respondsToSelector: objc2::sel!(splitView:constrainMaxCoordinate:ofSubviewAt:)
+            ];
+            let max = if constrains_max {
+                objc2::msg_send![
+                    &*delegate,
+                    splitView: &*self.split_view,
+                    constrainMaxCoordinate: max,
+                    ofSubviewAt: divider_index as isize
+                ]
+            } else {
+                max
+            };
+
+            Some((min, max))
+        }
+    }
+
+    fn divider_info(&self) -> Vec<DividerInfo> {
+        check_main_thread("divider_info");
+        unsafe {
+            let subviews: Retained<NSArray<NSView>> =
+                objc2::msg_send![&*self.split_view, arrangedSubviews];
+            let count: usize = objc2::msg_send![&*subviews, count];
+            let vertical = self.is_vertical();
+            let rtl = vertical && self.is_right_to_left();
+            let thickness = self.divider_thickness();
+            let bounds: NSRect = objc2::msg_send![&*self.split_view, bounds];
+            let extent = if vertical {
+                bounds.size.width
+            } else {
+                bounds.size.height
+            };
+
+            (0..count.saturating_sub(1))
+                .map(|index| {
+                    let view: Retained<NSView> = objc2::msg_send![&*subviews, objectAtIndex: index];
+                    let frame: NSRect = objc2::msg_send![&*view, frame];
+                    let position_points = if rtl {
+                        frame.origin.x - thickness
+                    } else if vertical {
+                        frame.origin.x + frame.size.width
+                    } else {
+                        frame.origin.y + frame.size.height
+                    };
+
+                    // Measure from the leading edge, which is on the right for RTL layouts
+                    let leading_points = if rtl {
+                        extent - frame.origin.x
+                    } else {
+                        position_points
+                    };
+
+                    let min: f64 = objc2::msg_send![
+                        &*self.split_view,
+                        minPossiblePositionOfDividerAtIndex: index as isize
+                    ];
+                    let max: f64 = objc2::msg_send![
+                        &*self.split_view,
+                        maxPossiblePositionOfDividerAtIndex: index as isize
+                    ];
+
+                    DividerInfo {
+                        index,
+                        position_points,
+                        fraction: if extent > 0.0 {
+                            leading_points / extent
+                        } else {
+                            0.0
+                        },
+                        thickness,
+                        is_draggable: max > min,
+                    }
+                })
+                .collect()
+        }
+    }
+
     fn set_divider_thickness(&self, thickness: f64) {
-        // NSSplitView divider thickness is typically controlled by the dividerThickness property
-        // but it's read-only. We'd need to subclass to customize this.
-        // For now, this is a no-op placeholder
-        let _ = thickness;
+        trace_operation!("set_divider_thickness", self.label, thickness);
+        // NSSplitView's dividerThickness is read-only, so only the crate's subclass can change it
+        match self.tauri_split_view() {
+            Some(split_view) => {
+                split_view.ivars().divider_thickness.set(Some(thickness));
+                self.adjust_subviews();
+            }
+            None => log::warn!(
+                "Split view `{}` was not created by tauri-nssplitview, divider thickness can't be set",
+                self.label
+            ),
+        }
+    }
+
+    fn set_density(&self, density: Density) {
+        trace_operation!("set_density", self.label, density);
+        self.set_divider_thickness(density.divider_thickness());
     }
 
     fn divider_thickness(&self) -> f64 {
+        check_main_thread("divider_thickness");
         unsafe { objc2::msg_send![&*self.split_view, dividerThickness] }
     }
 
-    fn pane_at_index(&self, index: usize) -> Option<Retained<NSView>> {
-        unsafe {
-            let subviews: Retained<objc2_foundation::NSArray<NSView>> =
-                objc2::msg_send![&*self.split_view, subviews];
-            let count: usize = objc2::msg_send![&*subviews, count];
+    fn add_pane(&self, view: &NSView) -> Result<usize, Error> {
+        trace_operation!("add_pane", self.label, view);
+        check_hierarchy(&self.split_view, view)?;
+        self.check_pane_capacity(1)?;
 
-            if index < count {
-                Some(objc2::msg_send![&*subviews, objectAtIndex: index])
-            } else {
-                None
+        catch_objc_exception(|| {
+            self.arrange_new_pane(view);
+            self.adjust_subviews();
+        })?;
+        self.track_native_pane(view);
+        self.panes_did_change();
+
+        let index = self.pane_count() - 1;
+        self.emit(SplitViewEvent::PaneAdded(index));
+        Ok(index)
+    }
+
+    fn add_panes(&self, views: Vec<Retained<NSView>>) -> Result<Vec<usize>, Error> {
+        trace_operation!("add_panes", self.label, views);
+        for view in &views {
+            check_hierarchy(&self.split_view, view)?;
+        }
+        self.check_pane_capacity(views.len())?;
+
+        let first_index = self.pane_count();
+        catch_objc_exception(|| {
+            for view in &views {
+                self.arrange_new_pane(view);
+            }
+            self.adjust_subviews();
+        })?;
+        for view in &views {
+            self.track_native_pane(view);
+        }
+        self.panes_did_change();
+
+        let indices: Vec<usize> = (first_index..first_index + views.len()).collect();
+        for &index in &indices {
+            self.emit(SplitViewEvent::PaneAdded(index));
+        }
+        Ok(indices)
+    }
+
+    fn set_dividers_visible(&self, visible: bool) {
+        trace_operation!("set_dividers_visible", self.label, visible);
+        match self.tauri_split_view() {
+            Some(split_view) => {
+                split_view.ivars().dividers_visible.set(visible);
+                unsafe {
+                    let _: () = objc2::msg_send![split_view, setNeedsDisplay: true];
+                }
+            }
+            None => log::warn!(
+                "Split view `{}` was not created by tauri-nssplitview, dividers can't be hidden",
+                self.label
+            ),
+        }
+    }
+
+    fn dividers_visible(&self) -> bool {
+        check_main_thread("dividers_visible");
+        self.tauri_split_view()
+            .map_or(true, |split_view| split_view.ivars().dividers_visible.get())
+    }
+
+    fn clear_tracking_areas(&self) {
+        trace_operation!("clear_tracking_areas", self.label);
+        remove_divider_tracking_areas(&self.split_view, &self.layout);
+        for (pane, (tracking_area, _target)) in self.pane_hover_handlers.take() {
+            unsafe {
+                let _: () = objc2::msg_send![pane.view(), removeTrackingArea: &*tracking_area];
             }
         }
     }
 
-    fn set_pane_collapsible(&self, index: usize, _collapsible: bool) {
-        // This would typically be handled by NSSplitViewDelegate
-        // For now, this is a placeholder
-        let _ = index;
+    fn set_divider_style(&self, style: DividerStyle) {
+        trace_operation!("set_divider_style", self.label, style);
+        unsafe {
+            let _: () = objc2::msg_send![&*self.split_view, setDividerStyle: style.to_ns()];
+            self.adjust_subviews();
+        }
+    }
+
+    fn divider_style(&self) -> DividerStyle {
+        check_main_thread("divider_style");
+        let style: NSSplitViewDividerStyle =
+            unsafe { objc2::msg_send![&*self.split_view, dividerStyle] };
+        DividerStyle::from_ns(style)
+    }
+
+    fn set_divider_color(&self, red: f64, green: f64, blue: f64, alpha: f64) {
+        trace_operation!("set_divider_color", self.label, red, green, blue, alpha);
+        match self.tauri_split_view() {
+            Some(split_view) => {
+                split_view
+                    .ivars()
+                    .divider_color
+                    .set(Some((red, green, blue, alpha)));
+                unsafe {
+                    let _: () = objc2::msg_send![split_view, setNeedsDisplay: true];
+                }
+            }
+            None => log::warn!(
+                "Split view `{}` was not created by tauri-nssplitview, divider color can't be set",
+                self.label
+            ),
+        }
+    }
+
+    fn divider_color(&self) -> Option<(f64, f64, f64, f64)> {
+        check_main_thread("divider_color");
+        self.tauri_split_view()
+            .and_then(|split_view| split_view.ivars().divider_color.get())
+    }
+
+    fn set_divider_image(&self, divider_index: usize, image_bytes: &[u8]) -> Result<(), Error> {
+        trace_operation!("set_divider_image", self.label, divider_index);
+        let split_view = self
+            .tauri_split_view()
+            .ok_or(Error::Unsupported("set_divider_image"))?;
+
+        let data = NSData::with_bytes(image_bytes);
+        let image: Option<Retained<NSImage>> =
+            unsafe { objc2::msg_send![NSImage::alloc(), initWithData: &*data] };
+        let image = non_nil(image, "initWithData:")?;
+
+        split_view
+            .ivars()
+            .divider_images
+            .borrow_mut()
+            .insert(divider_index, image);
+        unsafe {
+            let _: () = objc2::msg_send![split_view, setNeedsDisplay: true];
+        }
+        Ok(())
+    }
+
+    fn clear_divider_image(&self, divider_index: usize) {
+        trace_operation!("clear_divider_image", self.label, divider_index);
+        if let Some(split_view) = self.tauri_split_view() {
+            split_view
+                .ivars()
+                .divider_images
+                .borrow_mut()
+                .remove(&divider_index);
+            unsafe {
+                let _: () = objc2::msg_send![split_view, setNeedsDisplay: true];
+            }
+        }
+    }
+
+    fn set_divider_keyboard_resizable(&self, divider_index: usize, step: f64) {
+        trace_operation!(
+            "set_divider_keyboard_resizable",
+            self.label,
+            divider_index,
+            step
+        );
+        let Some(split_view) = self.tauri_split_view() else {
+            log::warn!(
+                "Split view `{}` was not created by tauri-nssplitview, dividers can't be resized from the keyboard",
+                self.label
+            );
+            return;
+        };
+
+        let ivars = split_view.ivars();
+        if step > 0.0 {
+            ivars
+                .keyboard_dividers
+                .borrow_mut()
+                .insert(divider_index, step);
+        } else {
+            ivars.keyboard_dividers.borrow_mut().remove(&divider_index);
+            if ivars.focused_divider.get() == Some(divider_index) {
+                ivars.focused_divider.set(None);
+            }
+        }
+    }
+
+    fn pane_at_index(&self, index: usize) -> Option<Retained<NSView>> {
+        check_main_thread("pane_at_index");
+        self.subview_at(self.subview_position(index)?)
+    }
+
+    fn set_pane_tag(&self, index: usize, tag: &str) -> Result<(), Error> {
+        trace_operation!("set_pane_tag", self.label, index, tag);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        let mut tags = self.pane_tags.borrow_mut();
+        tags.retain(|(view, pane_tag)| *view != pane && pane_tag != tag);
+        if !tag.is_empty() {
+            tags.push((pane, tag.to_string()));
+        }
+        Ok(())
+    }
+
+    fn pane_index_for_tag(&self, tag: &str) -> Option<usize> {
+        check_main_thread("pane_index_for_tag");
+        let view = self.pane_view_for_tag(tag)?;
+        (0..self.pane_count()).find(|&index| self.pane_at_index(index).as_ref() == Some(&view))
+    }
+
+    fn pane_view_for_tag(&self, tag: &str) -> Option<Retained<NSView>> {
+        check_main_thread("pane_view_for_tag");
+        let view = self
+            .pane_tags
+            .borrow()
+            .iter()
+            .find(|(_, pane_tag)| pane_tag == tag)
+            .map(|(view, _)| view.clone())?;
+
+        // The tagged view may have been removed from the split view
+        let superview: Option<Retained<NSView>> = unsafe { objc2::msg_send![&*view, superview] };
+        superview
+            .is_some_and(|superview| *superview == **self.split_view)
+            .then_some(view)
+    }
+
+    fn add_webview_pane(&self, url: WebviewUrl) -> tauri::Result<usize> {
+        trace_operation!("add_webview_pane", self.label, url);
+        let label = self.next_webview_pane_label();
+        let view = take_webview_view(&self.app_handle, &label, url)?;
+        let index = self.add_pane(&view)?;
+        self.track_webview_pane(view, label);
+
+        Ok(index)
+    }
+
+    fn webview_pane_label(&self, index: usize) -> Option<String> {
+        check_main_thread("webview_pane_label");
+        let pane = self.pane_at_index(index)?;
+        self.webview_panes
+            .borrow()
+            .iter()
+            .find(|(view, _)| *view == pane)
+            .map(|(_, label)| label.clone())
+    }
+
+    fn webview_panes(&self) -> Vec<(usize, WebviewWindow<R>)> {
+        check_main_thread("webview_panes");
+        let webview_panes = self.webview_panes.borrow();
+        (0..self.pane_count())
+            .filter_map(|index| {
+                let pane = self.pane_at_index(index)?;
+                let (_, label) = webview_panes.iter().find(|(view, _)| *view == pane)?;
+                Some((index, self.app_handle.get_webview_window(label)?))
+            })
+            .collect()
+    }
+
+    fn reload_all_webview_panes(&self) {
+        trace_operation!("reload_all_webview_panes", self.label);
+        for (index, webview) in self.webview_panes() {
+            if let Err(error) = webview.reload() {
+                log::warn!("Failed to reload webview pane {}: {}", index, error);
+            }
+        }
+    }
+
+    fn replace_pane_view(&self, index: usize, view: &NSView) -> Result<(), Error> {
+        trace_operation!("replace_pane_view", self.label, index, view);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        check_hierarchy(&self.split_view, view)?;
+
+        let split_view = self.split_view.clone();
+        let view = view.retain();
+        self.queue_pane_change(
+            pane.clone(),
+            Box::new(move || unsafe {
+                // The pane may have been removed while the swap was pending
+                let superview: Option<Retained<NSView>> = objc2::msg_send![&*pane, superview];
+                if superview.is_some_and(|superview| *superview == **split_view) {
+                    let frame: NSRect = objc2::msg_send![&*pane, frame];
+                    let _: () = objc2::msg_send![&*view, setFrame: frame];
+                    let _: () =
+                        objc2::msg_send![&*split_view, replaceSubview: &*pane, with: &*view];
+                    let _: () = objc2::msg_send![&*split_view, adjustSubviews];
+                }
+            }),
+        );
+        Ok(())
+    }
+
+    fn navigate_pane(&self, index: usize, url: tauri::Url) -> Result<(), Error> {
+        trace_operation!("navigate_pane", self.label, index, url);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        let (_, webview) = self
+            .webview_panes()
+            .into_iter()
+            .find(|(pane_index, _)| *pane_index == index)
+            .ok_or(Error::NotAWebviewPane(index))?;
+
+        self.queue_pane_change(
+            pane,
+            Box::new(move || {
+                if let Err(error) = webview.navigate(url) {
+                    log::warn!(
+                        "Failed to navigate webview `{}`: {}",
+                        webview.label(),
+                        error
+                    );
+                }
+            }),
+        );
+        Ok(())
+    }
+
+    fn pane_kind(&self, index: usize) -> Option<PaneKind> {
+        check_main_thread("pane_kind");
+        let pane = self.pane_at_index(index)?;
+        if contains_webview(&pane) {
+            return Some(PaneKind::Webview);
+        }
+
+        let native = self
+            .native_panes
+            .borrow()
+            .iter()
+            .any(|tracked| tracked.load().is_some_and(|view| view == pane));
+        Some(if native {
+            PaneKind::Native
+        } else {
+            PaneKind::Unknown
+        })
+    }
+
+    fn add_pane_deferred(&self, view: &NSView) -> Result<usize, Error> {
+        trace_operation!("add_pane_deferred", self.label, view);
+        check_hierarchy(&self.split_view, view)?;
+        self.check_pane_capacity(1)?;
+
+        catch_objc_exception(|| self.arrange_new_pane(view))?;
+        self.track_native_pane(view);
+        self.tracked_pane_count.set(subview_count(&self.split_view));
+
+        let index = self.pane_count() - 1;
+        self.emit(SplitViewEvent::PaneAdded(index));
+        Ok(index)
+    }
+
+    fn commit_layout(&self) {
+        trace_operation!("commit_layout", self.label);
+        self.adjust_subviews();
+        self.panes_did_change();
+    }
+
+    fn add_hosting_pane(&self, hosting_view: Retained<NSView>) -> Result<usize, Error> {
+        trace_operation!("add_hosting_pane", self.label, hosting_view);
+        prepare_hosting_view(&hosting_view)?;
+        self.add_pane(&hosting_view)
+    }
+
+    fn set_max_panes(&self, max: Option<usize>) {
+        trace_operation!("set_max_panes", self.label, max);
+        self.max_panes.set(max);
+    }
+
+    fn move_pane(&self, from: usize, to: usize) -> Result<(), Error> {
+        trace_operation!("move_pane", self.label, from, to);
+        let pane = self
+            .pane_at_index(from)
+            .ok_or(Error::InvalidPaneIndex(from))?;
+        if to >= self.pane_count() {
+            return Err(Error::InvalidPaneIndex(to));
+        }
+        if from == to {
+            return Ok(());
+        }
+        let inline = self.pane_presentation(from) == Some(PanePresentation::Inline);
+        let target = self
+            .subview_position(to)
+            .ok_or(Error::InvalidPaneIndex(to))?;
+
+        catch_objc_exception(|| unsafe {
+            let _: () = objc2::msg_send![&*pane, removeFromSuperview];
+
+            // Pane indices follow the layout order, so position it among the other subviews
+            let anchor = self.subview_at(target);
+            let mut order = self.layout.pane_order.borrow_mut();
+            if !order.is_empty() {
+                order.retain(|view| view != &pane);
+                order.insert(target, pane.clone());
+            }
+            drop(order);
+
+            match anchor {
+                Some(anchor) => {
+                    let _: () = objc2::msg_send![
+                        &*self.split_view,
+                        addSubview: &*pane,
+                        positioned: NSWindowOrderingMode::Below,
+                        relativeTo: &*anchor
+                    ];
+                }
+                None => {
+                    let _: () = objc2::msg_send![&*self.split_view, addSubview: &*pane];
+                }
+            }
+
+            // Once overlay panes exist only some subviews are arranged
+            let arranges_all: bool = objc2::msg_send![&*self.split_view, arrangesAllSubviews];
+            if inline && !arranges_all {
+                let arranged_index = self.arranged_before(target);
+                let _: () = objc2::msg_send![
+                    &*self.split_view,
+                    insertArrangedSubview: &*pane,
+                    atIndex: arranged_index as isize
+                ];
+            }
+
+            self.adjust_subviews();
+        })?;
+
+        self.panes_did_change();
+        self.emit(SplitViewEvent::PaneMoved { from, to });
+        Ok(())
+    }
+
+    fn bring_pane_to_front(&self, index: usize) -> Result<(), Error> {
+        trace_operation!("bring_pane_to_front", self.label, index);
+        self.reorder_pane_drawing(index, NSWindowOrderingMode::Above)
+    }
+
+    fn send_pane_to_back(&self, index: usize) -> Result<(), Error> {
+        trace_operation!("send_pane_to_back", self.label, index);
+        self.reorder_pane_drawing(index, NSWindowOrderingMode::Below)
+    }
+
+    fn set_pane_presentation(&self, index: usize, mode: PanePresentation) -> Result<(), Error> {
+        trace_operation!("set_pane_presentation", self.label, index, mode);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        if self.pane_presentation(index) == Some(mode) {
+            return Ok(());
+        }
+
+        unsafe {
+            match mode {
+                PanePresentation::Overlay => {
+                    let frame: NSRect = objc2::msg_send![&*pane, frame];
+
+                    // Stop arranging the pane so its neighbours take over its space,
+                    // while keeping it as a subview so pane indices stay the same
+                    let _: () =
+                        objc2::msg_send![&*self.split_view, setArrangesAllSubviews: false];
+                    let _: () = objc2::msg_send![&*self.split_view, removeArrangedSubview: &*pane];
+                    self.adjust_subviews();
+
+                    // Follow the split view's cross axis when the window resizes
+                    let resize_mask = if self.is_vertical() {
+                        objc2_app_kit::NSAutoresizingMaskOptions::ViewHeightSizable
+                    } else {
+                        objc2_app_kit::NSAutoresizingMaskOptions::ViewWidthSizable
+                    };
+                    let _: () = objc2::msg_send![&*pane, setAutoresizingMask: resize_mask];
+
+                    // Slide the pane in from its leading edge on top of the other panes
+                    let mut start = frame;
+                    if self.is_vertical() {
+                        start.size.width = 0.0;
+                    } else {
+                        start.size.height = 0.0;
+                    }
+                    let _: () = objc2::msg_send![&*pane, setFrame: start];
+                    self.bring_pane_to_front(index)?;
+
+                    if self.animations_enabled() {
+                        let animator: Retained<AnyObject> = objc2::msg_send![&*pane, animator];
+                        let _: () = objc2::msg_send![&*animator, setFrame: frame];
+                    } else {
+                        let _: () = objc2::msg_send![&*pane, setFrame: frame];
+                    }
+                }
+                PanePresentation::Inline => {
+                    // Return the pane to its slot among the other inline panes
+                    let position = self.subview_position(index).unwrap_or(index);
+                    let arranged_index = self.arranged_before(position);
+                    let _: () = objc2::msg_send![
+                        &*self.split_view,
+                        insertArrangedSubview: &*pane,
+                        atIndex: arranged_index as isize
+                    ];
+                    self.adjust_subviews();
+                }
+            }
+        }
+
+        self.panes_did_change();
+        Ok(())
+    }
+
+    fn pane_presentation(&self, index: usize) -> Option<PanePresentation> {
+        check_main_thread("pane_presentation");
+        let pane = self.pane_at_index(index)?;
+        let arranged: bool = unsafe {
+            let arranged_subviews: Retained<NSArray<NSView>> =
+                objc2::msg_send![&*self.split_view, arrangedSubviews];
+            objc2::msg_send![&*arranged_subviews, containsObject: &*pane]
+        };
+
+        Some(if arranged {
+            PanePresentation::Inline
+        } else {
+            PanePresentation::Overlay
+        })
+    }
+
+    fn set_pane_edge_accessory(
+        &self,
+        index: usize,
+        edge: Edge,
+        view: Retained<NSView>,
+        thickness: f64,
+    ) -> Result<(), Error> {
+        trace_operation!(
+            "set_pane_edge_accessory",
+            self.label,
+            index,
+            edge,
+            thickness
+        );
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        check_hierarchy(&self.split_view, &view)?;
+        self.clear_pane_edge_accessory(index, edge)?;
+
+        // Make room for the accessory before it's added so it isn't inset itself
+        self.inset_pane_content(&pane, edge, thickness);
+
+        unsafe {
+            let flipped: bool = objc2::msg_send![&*pane, isFlipped];
+            let bounds: NSRect = objc2::msg_send![&*pane, bounds];
+
+            // A strip along the edge, pinned to it as the pane resizes
+            let strip = edge_strip(bounds, edge, thickness, flipped);
+            let resize_mask = match (edge.is_horizontal(), edge.is_at_min(flipped)) {
+                (true, true) => {
+                    objc2_app_kit::NSAutoresizingMaskOptions::ViewHeightSizable
+                        | objc2_app_kit::NSAutoresizingMaskOptions::ViewMaxXMargin
+                }
+                (true, false) => {
+                    objc2_app_kit::NSAutoresizingMaskOptions::ViewHeightSizable
+                        | objc2_app_kit::NSAutoresizingMaskOptions::ViewMinXMargin
+                }
+                (false, true) => {
+                    objc2_app_kit::NSAutoresizingMaskOptions::ViewWidthSizable
+                        | objc2_app_kit::NSAutoresizingMaskOptions::ViewMaxYMargin
+                }
+                (false, false) => {
+                    objc2_app_kit::NSAutoresizingMaskOptions::ViewWidthSizable
+                        | objc2_app_kit::NSAutoresizingMaskOptions::ViewMinYMargin
+                }
+            };
+
+            let _: () = objc2::msg_send![&*view, setFrame: strip];
+            let _: () = objc2::msg_send![&*view, setAutoresizingMask: resize_mask];
+            let _: () = objc2::msg_send![&*pane, addSubview: &*view];
+        }
+
+        self.edge_accessories
+            .borrow_mut()
+            .insert((PaneKey::new(&pane), edge), (view, thickness));
+        Ok(())
+    }
+
+    fn clear_pane_edge_accessory(&self, index: usize, edge: Edge) -> Result<(), Error> {
+        check_main_thread("clear_pane_edge_accessory");
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        let key = PaneKey::new(&pane);
+        let removed = self
+            .edge_accessories
+            .borrow_mut()
+            .remove(&(key.clone(), edge));
+        if let Some((view, thickness)) = removed {
+            unsafe {
+                let _: () = objc2::msg_send![&*view, removeFromSuperview];
+            }
+            // Give the strip back to the pane's content
+            self.inset_pane_content(&pane, edge, -thickness);
+        }
+        // The top accessory doubles as the pane's collapse header
+        if edge == Edge::Top {
+            self.collapse_headers.borrow_mut().remove(&key);
+        }
+        Ok(())
+    }
+
+    fn on_pane_click(
+        &self,
+        index: usize,
+        callback: Box<dyn Fn(NSPoint) + Send + 'static>,
+    ) -> Result<(), Error> {
+        trace_operation!("on_pane_click", self.label, index);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        self.clear_pane_click(index)?;
+
+        let mtm = self.split_view.mtm();
+        let target = PaneClickTarget::new(mtm, callback);
+        unsafe {
+            // Gesture recognizers don't retain their target, so it's kept alongside
+            let recognizer: Retained<NSClickGestureRecognizer> = objc2::msg_send![
+                NSClickGestureRecognizer::alloc(mtm),
+                initWithTarget: &*target,
+                action: objc2::sel!(handleClick:)
+            ];
+            let _: () = objc2::msg_send![&*pane, addGestureRecognizer: &*recognizer];
+
+            self.pane_click_handlers
+                .borrow_mut()
+                .insert(PaneKey::new(&pane), (recognizer, target));
+        }
+        Ok(())
+    }
+
+    fn observe_pane_content_size(
+        &self,
+        index: usize,
+        callback: Box<dyn Fn(NSSize) + Send + 'static>,
+    ) -> Result<(), Error> {
+        trace_operation!("observe_pane_content_size", self.label, index);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        unsafe {
+            let subviews: Retained<NSArray<NSView>> = objc2::msg_send![&*pane, subviews];
+            let content: Option<Retained<NSView>> = objc2::msg_send![&*subviews, firstObject];
+            let key = PaneKey::new(&pane);
+            let content = content.unwrap_or(pane);
+
+            let center = NSNotificationCenter::defaultCenter();
+            let observer = PaneFrameObserver::new(self.split_view.mtm(), callback);
+            let previous = self
+                .content_size_observers
+                .borrow_mut()
+                .insert(key, observer.clone());
+            if let Some(previous) = previous {
+                let _: () = objc2::msg_send![&*center, removeObserver: &*previous];
+            }
+
+            let _: () = objc2::msg_send![&*content, setPostsFrameChangedNotifications: true];
+            let _: () = objc2::msg_send![
+                &*center,
+                addObserver: &*observer,
+                selector: objc2::sel!(frameDidChange:),
+                name: NSViewFrameDidChangeNotification,
+                object: &*content
+            ];
+        }
+        Ok(())
+    }
+
+    fn clear_pane_click(&self, index: usize) -> Result<(), Error> {
+        check_main_thread("clear_pane_click");
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        let removed = self
+            .pane_click_handlers
+            .borrow_mut()
+            .remove(&PaneKey::new(&pane));
+        if let Some((recognizer, _target)) = removed {
+            unsafe {
+                let _: () = objc2::msg_send![&*pane, removeGestureRecognizer: &*recognizer];
+            }
+        }
+        Ok(())
+    }
+
+    fn on_pane_hover(
+        &self,
+        index: usize,
+        callback: Box<dyn Fn(bool) + Send + 'static>,
+    ) -> Result<(), Error> {
+        trace_operation!("on_pane_hover", self.label, index);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        let key = PaneKey::new(&pane);
+        let previous = self.pane_hover_handlers.borrow_mut().remove(&key);
+        if let Some((tracking_area, _target)) = previous {
+            unsafe {
+                let _: () = objc2::msg_send![&*pane, removeTrackingArea: &*tracking_area];
+            }
+        }
+
+        let mtm = self.split_view.mtm();
+        let target = PaneHoverTarget::new(mtm, callback);
+        // Tracking the visible rect keeps the area matching the pane as it resizes
+        let options = NSTrackingAreaOptions::MouseEnteredAndExited
+            | NSTrackingAreaOptions::ActiveInActiveApp
+            | NSTrackingAreaOptions::InVisibleRect;
+        unsafe {
+            // Tracking areas don't retain their owner, so it's kept alongside
+            let tracking_area: Retained<NSTrackingArea> = objc2::msg_send![
+                NSTrackingArea::alloc(),
+                initWithRect: NSRect::ZERO,
+                options: options,
+                owner: &*target,
+                userInfo: std::ptr::null::<AnyObject>()
+            ];
+            let _: () = objc2::msg_send![&*pane, addTrackingArea: &*tracking_area];
+
+            self.pane_hover_handlers
+                .borrow_mut()
+                .insert(key, (tracking_area, target));
+        }
+        Ok(())
+    }
+
+    fn set_pane_tooltip(&self, index: usize, text: &str) -> Result<(), Error> {
+        trace_operation!("set_pane_tooltip", self.label, index, text);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        // A nil tooltip removes it
+        let tooltip = (!text.is_empty()).then(|| NSString::from_str(text));
+        unsafe {
+            let _: () = objc2::msg_send![&*pane, setToolTip: tooltip.as_deref()];
+        }
+        Ok(())
+    }
+
+    fn pane_tooltip(&self, index: usize) -> Option<String> {
+        check_main_thread("pane_tooltip");
+        let pane = self.pane_at_index(index)?;
+        let tooltip: Option<Retained<NSString>> = unsafe { objc2::msg_send![&*pane, toolTip] };
+        tooltip.map(|tooltip| tooltip.to_string())
+    }
+
+    fn animate_pane_zoom(
+        &self,
+        index: usize,
+        from_scale: f64,
+        to_scale: f64,
+        duration_secs: f64,
+    ) -> Result<(), Error> {
+        trace_operation!("animate_pane_zoom", self.label, index, from_scale, to_scale);
+
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        unsafe {
+            let layer: Option<Retained<AnyObject>> = objc2::msg_send![&*pane, layer];
+            let layer = layer.ok_or(Error::PaneNotLayerBacked(index))?;
+
+            // The pane ends at its identity transform, so there's nothing to apply
+            if !self.animations_enabled() {
+                return Ok(());
+            }
+
+            let key_path = NSString::from_str("transform.scale");
+            let animation: Option<Retained<AnyObject>> = objc2::msg_send![
+                objc2::class!(CABasicAnimation),
+                animationWithKeyPath: &*key_path
+            ];
+            let animation = non_nil(animation, "animationWithKeyPath:")?;
+
+            let from = NSNumber::new_f64(from_scale);
+            let to = NSNumber::new_f64(to_scale);
+            let _: () = objc2::msg_send![&*animation, setFromValue: &*from];
+            let _: () = objc2::msg_send![&*animation, setToValue: &*to];
+            let _: () = objc2::msg_send![&*animation, setDuration: duration_secs];
+
+            // The animation is removed on completion and the layer's own transform is never
+            // changed, so the pane returns to its identity transform afterwards
+            let key = NSString::from_str("tauriPaneZoom");
+            let _: () = objc2::msg_send![&*layer, addAnimation: &*animation, forKey: &*key];
+        }
+
+        Ok(())
+    }
+
+    fn freeze_pane(&self, index: usize) -> Result<(), Error> {
+        trace_operation!("freeze_pane", self.label, index);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        self.unfreeze_pane(index)?;
+
+        let image = snapshot_view(&pane)?;
+        unsafe {
+            let bounds: NSRect = objc2::msg_send![&*pane, bounds];
+            let overlay: Retained<NSImageView> = objc2::msg_send![
+                NSImageView::alloc(self.split_view.mtm()),
+                initWithFrame: bounds
+            ];
+            let _: () = objc2::msg_send![&*overlay, setImage: &*image];
+            let _: () = objc2::msg_send![
+                &*overlay,
+                setImageScaling: NSImageScaling::ScaleAxesIndependently
+            ];
+            let resize_mask = objc2_app_kit::NSAutoresizingMaskOptions::ViewWidthSizable
+                | objc2_app_kit::NSAutoresizingMaskOptions::ViewHeightSizable;
+            let _: () = objc2::msg_send![&*overlay, setAutoresizingMask: resize_mask];
+            let _: () = objc2::msg_send![&*pane, addSubview: &*overlay];
+
+            self.frozen_panes
+                .borrow_mut()
+                .insert(PaneKey::new(&pane), overlay);
+        }
+        Ok(())
+    }
+
+    fn unfreeze_pane(&self, index: usize) -> Result<(), Error> {
+        trace_operation!("unfreeze_pane", self.label, index);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        if let Some(overlay) = self.frozen_panes.borrow_mut().remove(&PaneKey::new(&pane)) {
+            unsafe {
+                let _: () = objc2::msg_send![&*overlay, removeFromSuperview];
+            }
+        }
+        Ok(())
+    }
+
+    fn add_mirror_pane(&self, source_index: usize) -> Result<usize, Error> {
+        trace_operation!("add_mirror_pane", self.label, source_index);
+        let source = self
+            .pane_at_index(source_index)
+            .ok_or(Error::InvalidPaneIndex(source_index))?;
+        let mirror = self.mirror_view(&source)?;
+        self.add_pane(&mirror)
+    }
+
+    fn add_region_split_view(
+        &self,
+        index: usize,
+        label: &str,
+        region: NSRect,
+        orientation: SplitViewOrientation,
+    ) -> Result<SplitViewHandle<R>, Error> {
+        trace_operation!("add_region_split_view", self.label, index, region);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        let region_view = TauriSplitView::new(self.split_view.mtm(), region).into_super();
+        catch_objc_exception(|| unsafe {
+            let _: () = objc2::msg_send![&*region_view, setVertical: orientation.is_vertical()];
+            let _: () = objc2::msg_send![&*pane, addSubview: &*region_view];
+        })?;
+
+        let split_view =
+            BasicSplitView::new(region_view, label.to_string(), self.app_handle.clone());
+        Ok(self.register_nested_split_view(label, split_view))
+    }
+
+    fn split_pane_preserving_webview(
+        &self,
+        index: usize,
+        orientation: SplitViewOrientation,
+        new_pane: PaneConfig,
+    ) -> Result<SplitViewHandle<R>, Error> {
+        trace_operation!("split_pane_preserving_webview", self.label, index);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        let webview_label = self
+            .webview_pane_label(index)
+            .ok_or(Error::NotAWebviewPane(index))?;
+
+        let label = self.unused_label(&format!("{}-split", webview_label));
+        let new_pane_label = self.unused_label(&format!("{}-pane", label));
+        let mtm = self.split_view.mtm();
+        let new_view = pane_view_from_config(&self.app_handle, &new_pane_label, &new_pane, mtm)?;
+
+        let frame: NSRect = unsafe { objc2::msg_send![&*pane, frame] };
+        let nested_view = TauriSplitView::new(mtm, frame).into_super();
+        catch_objc_exception(|| unsafe {
+            let _: () = objc2::msg_send![&*nested_view, setVertical: orientation.is_vertical()];
+            // The nested split view takes the pane's place, the pane keeps its webview loaded
+            let _: () = objc2::msg_send![
+                &*self.split_view,
+                replaceSubview: &*pane,
+                with: &*nested_view
+            ];
+        })?;
+        self.webview_panes
+            .borrow_mut()
+            .retain(|(view, _)| *view != pane);
+        self.adjust_subviews();
+
+        let split_view = BasicSplitView::new(nested_view, label.clone(), self.app_handle.clone());
+        split_view.add_pane(&pane)?;
+        split_view.track_webview_pane(pane, webview_label);
+        split_view.add_pane(&new_view)?;
+        if let PaneConfig::Webview { .. } = new_pane {
+            split_view.track_webview_pane(new_view, new_pane_label);
+        }
+
+        Ok(self.register_nested_split_view(&label, split_view))
+    }
+
+    fn set_pane_collapsible(&self, index: usize, collapsible: bool) {
+        trace_operation!("set_pane_collapsible", self.label, index, collapsible);
+        // Enforced by the delegate's canCollapseSubview method
+        self.ensure_delegate();
+        if let Some(key) = self.setting_key(index, "collapsibility") {
+            let mut panes = self.layout.collapsible.borrow_mut();
+            if collapsible {
+                panes.insert(key);
+            } else {
+                panes.remove(&key);
+            }
+        }
     }
 
     fn is_pane_collapsed(&self, index: usize) -> bool {
-        if let Some(view) = self.pane_at_index(index) {
-            unsafe {
-                let result: bool = objc2::msg_send![
-                    &*self.split_view,
-                    isSubviewCollapsed: &*view
-                ];
-                result
-            }
+        check_main_thread("is_pane_collapsed");
+        let Some(view) = self.pane_at_index(index) else {
+            return false;
+        };
+
+        // Panes with a collapse header are collapsed while only the header shows
+        let key = PaneKey::new(&view);
+        if self.collapse_headers.borrow().contains_key(&key)
+            && self.sidebar_extents.borrow().contains_key(&key)
+        {
+            return true;
+        }
+
+        unsafe { objc2::msg_send![&*self.split_view, isSubviewCollapsed: &*view] }
+    }
+
+    fn collapse_pane(&self, index: usize) -> Result<(), Error> {
+        trace_operation!("collapse_pane", self.label, index);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        if self.is_pane_collapsed(index) {
+            return Ok(());
+        }
+
+        let frame: NSRect = unsafe { objc2::msg_send![&*pane, frame] };
+        let extent = if self.is_vertical() {
+            frame.size.width
         } else {
-            false
+            frame.size.height
+        };
+        let mut extents = self.collapsed_extents.borrow_mut();
+        extents.retain(|(view, _)| *view != pane);
+        extents.push((pane.clone(), extent));
+        drop(extents);
+
+        unsafe {
+            // The split view treats hidden panes as collapsed
+            let _: () = objc2::msg_send![&*pane, setHidden: true];
+            let _: () = objc2::msg_send![&*self.split_view, adjustSubviews];
+        }
+        Ok(())
+    }
+
+    fn expand_pane(&self, index: usize) -> Result<(), Error> {
+        trace_operation!("expand_pane", self.label, index);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        if !self.is_pane_collapsed(index) {
+            return Ok(());
+        }
+
+        // Panes collapsed by dragging a divider have no recorded extent
+        let recorded = {
+            let mut extents = self.collapsed_extents.borrow_mut();
+            let position = extents.iter().position(|(view, _)| *view == pane);
+            position.map(|position| extents.remove(position).1)
+        };
+        let extent = recorded
+            .or_else(|| self.layout.min_sizes.borrow().get(&PaneKey::new(&pane)).copied())
+            .unwrap_or(0.0);
+
+        unsafe {
+            let _: () = objc2::msg_send![&*pane, setHidden: false];
+            let _: () = objc2::msg_send![&*self.split_view, adjustSubviews];
+        }
+        self.ensure_pane_visible(index, extent, false)
+    }
+
+    fn ensure_pane_visible(
+        &self,
+        index: usize,
+        min_extent: f64,
+        animated: bool,
+    ) -> Result<(), Error> {
+        trace_operation!(
+            "ensure_pane_visible",
+            self.label,
+            index,
+            min_extent,
+            animated
+        );
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        // Overlay panes aren't sized by the dividers
+        if self.pane_presentation(index) != Some(PanePresentation::Inline) {
+            return Ok(());
+        }
+
+        let vertical = self.is_vertical();
+        let extent_of = |frame: NSRect| {
+            if vertical {
+                frame.size.width
+            } else {
+                frame.size.height
+            }
+        };
+        let frame: NSRect = unsafe { objc2::msg_send![&*pane, frame] };
+        let current = if self.is_pane_collapsed(index) {
+            0.0
+        } else {
+            extent_of(frame)
+        };
+
+        let target = match self.layout.max_sizes.borrow().get(&PaneKey::new(&pane)) {
+            Some(max) => min_extent.min(*max),
+            None => min_extent,
+        };
+        if current >= target {
+            return Ok(());
+        }
+        let delta = target - current;
+
+        unsafe {
+            let arranged: Retained<NSArray<NSView>> =
+                objc2::msg_send![&*self.split_view, arrangedSubviews];
+            let count: usize = objc2::msg_send![&*arranged, count];
+            if count < 2 {
+                return Ok(());
+            }
+
+            // Grow towards the next pane, or the previous one for the last pane. The pane's
+            // arranged position differs from its index while panes are reversed.
+            let arranged_index: usize = objc2::msg_send![&*arranged, indexOfObject: &*pane];
+            let (divider_index, neighbour_index) = if arranged_index + 1 < count {
+                (arranged_index, arranged_index + 1)
+            } else {
+                (arranged_index - 1, arranged_index - 1)
+            };
+            let towards_next = neighbour_index > arranged_index;
+
+            if !animated || !self.animations_enabled() {
+                let position = self.get_divider_position(divider_index);
+                // Dividers move the other way along the x axis in right-to-left layouts
+                let step = if vertical && self.is_right_to_left() {
+                    -delta
+                } else {
+                    delta
+                };
+                let position = if towards_next {
+                    position + step
+                } else {
+                    position - step
+                };
+                self.set_divider_position(divider_index, position);
+                return Ok(());
+            }
+
+            // Animate both frames, since dividers can't be moved through the animator
+            let neighbour: Retained<NSView> =
+                objc2::msg_send![&*arranged, objectAtIndex: neighbour_index];
+            let neighbour_frame: NSRect = objc2::msg_send![&*neighbour, frame];
+            let grows_at_max = towards_next != (vertical && self.is_right_to_left());
+            let mut pane_frame = frame;
+            let mut neighbour_frame = neighbour_frame;
+            if vertical {
+                pane_frame.size.width = target;
+                neighbour_frame.size.width -= delta;
+                if grows_at_max {
+                    neighbour_frame.origin.x += delta;
+                } else {
+                    pane_frame.origin.x -= delta;
+                }
+            } else {
+                pane_frame.size.height = target;
+                neighbour_frame.size.height -= delta;
+                if grows_at_max {
+                    neighbour_frame.origin.y += delta;
+                } else {
+                    pane_frame.origin.y -= delta;
+                }
+            }
+
+            // Collapsed panes are hidden by the split view
+            let _: () = objc2::msg_send![&*pane, setHidden: false];
+            let pane_animator: Retained<AnyObject> = objc2::msg_send![&*pane, animator];
+            let _: () = objc2::msg_send![&*pane_animator, setFrame: pane_frame];
+            let neighbour_animator: Retained<AnyObject> = objc2::msg_send![&*neighbour, animator];
+            let _: () = objc2::msg_send![&*neighbour_animator, setFrame: neighbour_frame];
+        }
+
+        Ok(())
+    }
+
+    fn toggle_sidebar(&self, sidebar_index: usize) -> Result<(), Error> {
+        trace_operation!("toggle_sidebar", self.label, sidebar_index);
+        let sidebar = self
+            .pane_at_index(sidebar_index)
+            .ok_or(Error::InvalidPaneIndex(sidebar_index))?;
+
+        // Overlay panes aren't sized by the dividers
+        if self.pane_presentation(sidebar_index) != Some(PanePresentation::Inline) {
+            return Ok(());
+        }
+
+        let vertical = self.is_vertical();
+        let extent_of = |frame: NSRect| {
+            if vertical {
+                frame.size.width
+            } else {
+                frame.size.height
+            }
+        };
+
+        unsafe {
+            let arranged: Retained<NSArray<NSView>> =
+                objc2::msg_send![&*self.split_view, arrangedSubviews];
+            let count: usize = objc2::msg_send![&*arranged, count];
+            let arranged_index: usize = objc2::msg_send![&*arranged, indexOfObject: &*sidebar];
+            if count < 2 || arranged_index >= count {
+                return Ok(());
+            }
+
+            // The content pane is the next pane, or the previous one for the last pane
+            let neighbour_index = if arranged_index + 1 < count {
+                arranged_index + 1
+            } else {
+                arranged_index - 1
+            };
+            let neighbour: Retained<NSView> =
+                objc2::msg_send![&*arranged, objectAtIndex: neighbour_index];
+
+            let mut sidebar_frame: NSRect = objc2::msg_send![&*sidebar, frame];
+            let mut neighbour_frame: NSRect = objc2::msg_send![&*neighbour, frame];
+            // A sidebar with a collapse header stays visible while collapsed
+            let hidden: bool = objc2::msg_send![&*sidebar, isHidden];
+            let key = PaneKey::new(&sidebar);
+            let header = self.collapse_headers.borrow().get(&key).copied();
+            let collapsing = !hidden && !self.sidebar_extents.borrow().contains_key(&key);
+            let target = if collapsing {
+                self.sidebar_extents
+                    .borrow_mut()
+                    .insert(key, extent_of(sidebar_frame));
+                header.unwrap_or(0.0)
+            } else {
+                self.sidebar_extents
+                    .borrow_mut()
+                    .remove(&key)
+                    .or_else(|| fitting_extent(&sidebar, vertical))
+                    .unwrap_or(DEFAULT_SIDEBAR_EXTENT)
+                    .min(extent_of(neighbour_frame) + extent_of(sidebar_frame))
+            };
+            let change = target - extent_of(sidebar_frame);
+
+            // The split view is flipped, so the content pane sits at the larger coordinate
+            // when it follows the sidebar, except along the x axis in right-to-left layouts
+            let content_follows =
+                (neighbour_index > arranged_index) != (vertical && self.is_right_to_left());
+            if vertical {
+                sidebar_frame.size.width = target;
+                neighbour_frame.size.width -= change;
+                if content_follows {
+                    neighbour_frame.origin.x += change;
+                } else {
+                    sidebar_frame.origin.x -= change;
+                }
+            } else {
+                sidebar_frame.size.height = target;
+                neighbour_frame.size.height -= change;
+                if content_follows {
+                    neighbour_frame.origin.y += change;
+                } else {
+                    sidebar_frame.origin.y -= change;
+                }
+            }
+
+            // Hold the content pane in place so only the sidebar gives way while animating
+            let sidebar_priority: f32 = objc2::msg_send![
+                &*self.split_view,
+                holdingPriorityForSubviewAtIndex: arranged_index as isize
+            ];
+            let content_priority: f32 = objc2::msg_send![
+                &*self.split_view,
+                holdingPriorityForSubviewAtIndex: neighbour_index as isize
+            ];
+            let _: () = objc2::msg_send![
+                &*self.split_view,
+                setHoldingPriority: content_priority.max(sidebar_priority + 1.0),
+                forSubviewAtIndex: neighbour_index as isize
+            ];
+
+            let split_view = objc2::rc::Weak::new(&*self.split_view);
+            let weak_sidebar = objc2::rc::Weak::new(&*sidebar);
+            let finish = move || {
+                let (Some(split_view), Some(sidebar)) = (split_view.load(), weak_sidebar.load())
+                else {
+                    return;
+                };
+                // The split view treats a hidden pane as collapsed
+                if collapsing && header.is_none() {
+                    let _: () = objc2::msg_send![&*sidebar, setHidden: true];
+                }
+                let _: () = objc2::msg_send![
+                    &*split_view,
+                    setHoldingPriority: content_priority,
+                    forSubviewAtIndex: neighbour_index as isize
+                ];
+                let _: () = objc2::msg_send![&*split_view, adjustSubviews];
+            };
+
+            if !collapsing {
+                let _: () = objc2::msg_send![&*sidebar, setHidden: false];
+            }
+
+            if !self.animations_enabled() {
+                let _: () = objc2::msg_send![&*sidebar, setFrame: sidebar_frame];
+                let _: () = objc2::msg_send![&*neighbour, setFrame: neighbour_frame];
+                finish();
+                return Ok(());
+            }
+
+            // Animate both frames, since dividers can't be moved through the animator
+            let sidebar_animator: Retained<AnyObject> = objc2::msg_send![&*sidebar, animator];
+            let _: () = objc2::msg_send![&*sidebar_animator, setFrame: sidebar_frame];
+            let neighbour_animator: Retained<AnyObject> = objc2::msg_send![&*neighbour, animator];
+            let _: () = objc2::msg_send![&*neighbour_animator, setFrame: neighbour_frame];
+
+            let context: Retained<AnyObject> =
+                objc2::msg_send![objc2::class!(NSAnimationContext), currentContext];
+            let duration: f64 = objc2::msg_send![&*context, duration];
+            DeferredCallback::new(self.split_view.mtm(), Box::new(finish)).schedule_after(duration);
+        }
+
+        Ok(())
+    }
+
+    fn apply_pane_constraints(
+        &self,
+        index: usize,
+        constraints: PaneConstraints,
+    ) -> Result<(), Error> {
+        trace_operation!("apply_pane_constraints", self.label, index, constraints);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        if let Some(min) = constraints.min {
+            self.set_pane_min_size(index, min);
+        }
+        if let Some(max) = constraints.max {
+            self.set_pane_max_size(index, max);
+        }
+        if let Some(tag) = &constraints.tag {
+            self.set_pane_tag(index, tag)?;
+        }
+        if let Some(collapsible) = constraints.collapsible {
+            self.set_pane_collapsible(index, collapsible);
+        }
+        if let Some(priority) = constraints.holding_priority {
+            unsafe {
+                // Holding priorities are kept by arranged position, which overlay panes lack
+                let arranged: Retained<NSArray<NSView>> =
+                    objc2::msg_send![&*self.split_view, arrangedSubviews];
+                let count: usize = objc2::msg_send![&*arranged, count];
+                let position: usize = objc2::msg_send![&*arranged, indexOfObject: &*pane];
+                if position < count {
+                    let _: () = objc2::msg_send![
+                        &*self.split_view,
+                        setHoldingPriority: priority,
+                        forSubviewAtIndex: position as isize
+                    ];
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn set_pane_collapse_header(
+        &self,
+        index: usize,
+        header_view: Retained<NSView>,
+        height: f64,
+    ) -> Result<(), Error> {
+        trace_operation!("set_pane_collapse_header", self.label, index, height);
+        self.set_pane_edge_accessory(index, Edge::Top, header_view, height)?;
+        if let Some(key) = self.pane_key(index) {
+            self.collapse_headers.borrow_mut().insert(key, height);
+        }
+        Ok(())
+    }
+
+    fn set_pane_min_size(&self, index: usize, size: f64) {
+        trace_operation!("set_pane_min_size", self.label, index, size);
+        // Enforced by the delegate's constrainMinCoordinate/constrainMaxCoordinate methods
+        self.ensure_delegate();
+        if let Some(key) = self.setting_key(index, "minimum size") {
+            self.layout.min_sizes.borrow_mut().insert(key, size);
         }
     }
 
-    fn set_pane_min_size(&self, _index: usize, _size: f64) {
-        // This would be handled by NSSplitViewDelegate's constrainMinCoordinate method
-        // For now, this is a placeholder
+    fn set_pane_max_size(&self, index: usize, size: f64) {
+        trace_operation!("set_pane_max_size", self.label, index, size);
+        // Enforced by the delegate's constrainMinCoordinate/constrainMaxCoordinate methods
+        self.ensure_delegate();
+        if let Some(key) = self.setting_key(index, "maximum size") {
+            self.layout.max_sizes.borrow_mut().insert(key, size);
+        }
     }
 
-    fn set_pane_max_size(&self, _index: usize, _size: f64) {
-        // This would be handled by NSSplitViewDelegate's constrainMaxCoordinate method
-        // For now, this is a placeholder
+    fn set_pane_min_aspect(&self, index: usize, min_ratio: f64) {
+        trace_operation!("set_pane_min_aspect", self.label, index, min_ratio);
+        // Enforced by the delegate's constrainMinCoordinate/constrainMaxCoordinate methods
+        self.ensure_delegate();
+        if let Some(key) = self.setting_key(index, "minimum aspect ratio") {
+            self.layout
+                .aspect_bounds
+                .borrow_mut()
+                .entry(key)
+                .or_default()
+                .0 = Some(min_ratio);
+        }
+    }
+
+    fn set_pane_max_aspect(&self, index: usize, max_ratio: f64) {
+        trace_operation!("set_pane_max_aspect", self.label, index, max_ratio);
+        // Enforced by the delegate's constrainMinCoordinate/constrainMaxCoordinate methods
+        self.ensure_delegate();
+        if let Some(key) = self.setting_key(index, "maximum aspect ratio") {
+            self.layout
+                .aspect_bounds
+                .borrow_mut()
+                .entry(key)
+                .or_default()
+                .1 = Some(max_ratio);
+        }
+    }
+
+    fn set_pane_pixel_aligned(&self, index: usize, aligned: bool) {
+        trace_operation!("set_pane_pixel_aligned", self.label, index, aligned);
+        // Enforced by the delegate's constrainSplitPosition method
+        self.ensure_delegate();
+        let Some(key) = self.setting_key(index, "pixel alignment") else {
+            return;
+        };
+        let mut pixel_aligned = self.layout.pixel_aligned.borrow_mut();
+        if aligned {
+            pixel_aligned.insert(key);
+        } else {
+            pixel_aligned.remove(&key);
+        }
+    }
+
+    fn minimum_content_extent(&self) -> f64 {
+        check_main_thread("minimum_content_extent");
+        let count = self.pane_count();
+        let min_sizes = self.layout.min_sizes.borrow();
+        let panes: f64 = (0..count)
+            .filter_map(|index| self.pane_key(index))
+            .map(|key| min_sizes.get(&key).copied().unwrap_or(0.0))
+            .sum();
+
+        panes + self.divider_thickness() * count.saturating_sub(1) as f64
+    }
+
+    fn maximum_content_extent(&self) -> Option<f64> {
+        check_main_thread("maximum_content_extent");
+        let count = self.pane_count();
+        let max_sizes = self.layout.max_sizes.borrow();
+        let panes = (0..count)
+            .map(|index| max_sizes.get(&self.pane_key(index)?).copied())
+            .sum::<Option<f64>>()?;
+
+        Some(panes + self.divider_thickness() * count.saturating_sub(1) as f64)
+    }
+
+    fn recommended_pane_width(&self, index: usize) -> Option<f64> {
+        check_main_thread("recommended_pane_width");
+        let pane = self.pane_at_index(index)?;
+        let mut extent = fitting_extent(&pane, self.is_vertical())?;
+        let key = PaneKey::new(&pane);
+        if let Some(&min) = self.layout.min_sizes.borrow().get(&key) {
+            extent = extent.max(min);
+        }
+        if let Some(&max) = self.layout.max_sizes.borrow().get(&key) {
+            extent = extent.min(max);
+        }
+        Some(extent)
+    }
+
+    fn set_pane_ignores_intrinsic_size(&self, index: usize, ignore: bool) -> Result<(), Error> {
+        trace_operation!("set_pane_ignores_intrinsic_size", self.label, index, ignore);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        let orientation = if self.is_vertical() {
+            NSLayoutConstraintOrientation::Horizontal
+        } else {
+            NSLayoutConstraintOrientation::Vertical
+        };
+        let (hugging, compression) = if ignore {
+            (1.0, 1.0)
+        } else {
+            (NSLayoutPriorityDefaultLow, NSLayoutPriorityDefaultHigh)
+        };
+
+        unsafe {
+            // Apply to the pane and its direct content, which is where intrinsic sizes come from
+            let subviews: Retained<NSArray<NSView>> = objc2::msg_send![&*pane, subviews];
+            let count: usize = objc2::msg_send![&*subviews, count];
+            let content = (0..count).map(|i| -> Retained<NSView> {
+                objc2::msg_send![&*subviews, objectAtIndex: i]
+            });
+
+            for view in std::iter::once(pane.clone()).chain(content) {
+                let _: () = objc2::msg_send![
+                    &*view,
+                    setContentHuggingPriority: hugging,
+                    forOrientation: orientation
+                ];
+                let _: () = objc2::msg_send![
+                    &*view,
+                    setContentCompressionResistancePriority: compression,
+                    forOrientation: orientation
+                ];
+            }
+        }
+
+        Ok(())
+    }
+
+    fn focus_pane(&self, index: usize) -> Result<(), Error> {
+        trace_operation!("focus_pane", self.label, index);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        let window = self.require_window()?;
+
+        // Container panes usually refuse focus, so target the first descendant that accepts it
+        let responder = first_key_view(&pane).unwrap_or(pane);
+
+        let accepted: bool =
+            unsafe { objc2::msg_send![&*window, makeFirstResponder: &*responder] };
+        if accepted {
+            Ok(())
+        } else {
+            Err(Error::ObjCOperation(
+                "`makeFirstResponder:` refused the pane".to_string(),
+            ))
+        }
+    }
+
+    fn focused_pane_index(&self) -> Option<usize> {
+        check_main_thread("focused_pane_index");
+        let window = self.window()?;
+        let responder: Option<Retained<NSResponder>> =
+            unsafe { objc2::msg_send![&*window, firstResponder] };
+        let responder = responder?;
+        let view = responder.downcast_ref::<NSView>()?;
+
+        (0..self.pane_count()).find(|&index| {
+            self.pane_at_index(index).is_some_and(|pane| unsafe {
+                objc2::msg_send![view, isDescendantOf: &*pane]
+            })
+        })
+    }
+
+    fn is_pane_focused(&self, index: usize) -> bool {
+        check_main_thread("is_pane_focused");
+        self.focused_pane_index() == Some(index)
+    }
+
+    fn set_accepts_first_responder(&self, accepts: bool) {
+        trace_operation!("set_accepts_first_responder", self.label, accepts);
+        match self.tauri_split_view() {
+            Some(split_view) => split_view.ivars().accepts_first_responder.set(Some(accepts)),
+            None => log::warn!(
+                "Split view `{}` was not created by tauri-nssplitview, first responder can't be changed",
+                self.label
+            ),
+        }
     }
 
     fn window(&self) -> Option<Retained<NSWindow>> {
+        check_main_thread("window");
         unsafe { objc2::msg_send![&*self.split_view, window] }
     }
+
+    fn set_window_aspect_ratio_from_panes(&self, enabled: bool) {
+        trace_operation!("set_window_aspect_ratio_from_panes", self.label, enabled);
+        self.aspect_ratio_from_panes.set(enabled);
+
+        if enabled {
+            self.apply_window_aspect_ratio();
+        } else if let Some(window) = self.window() {
+            // Setting resize increments clears a content aspect ratio
+            unsafe {
+                let _: () = objc2::msg_send![
+                    &*window,
+                    setContentResizeIncrements: objc2_foundation::NSSize::new(1.0, 1.0)
+                ];
+            }
+        }
+    }
+
+    fn set_close_guard(&self, guard: Box<dyn Fn(&dyn SplitView<R>) -> bool + Send + 'static>) {
+        trace_operation!("set_close_guard", self.label);
+        let Some(window) = self.window() else {
+            log::warn!("Split view `{}` has no window to guard", self.label);
+            return;
+        };
+
+        // The guard looks the split view up when asked, since it can't hold a reference to it
+        let app_handle = self.app_handle.clone();
+        let label = self.label.clone();
+        let should_close = Box::new(move || match app_handle.get_split_view(&label) {
+            Ok(split_view) => guard(&*split_view),
+            Err(_) => true,
+        });
+
+        // Wrap the delegate the previous guard wrapped, rather than the previous guard
+        let original = match self.close_guard.borrow().as_ref() {
+            Some(previous) => previous.original(),
+            None => unsafe { window.delegate() },
+        };
+        let delegate = CloseGuardDelegate::new(self.split_view.mtm(), original, should_close);
+
+        unsafe {
+            let _: () = objc2::msg_send![&*window, setDelegate: &*delegate];
+        }
+        // The window only holds its delegate weakly
+        *self.close_guard.borrow_mut() = Some(delegate);
+    }
+
+    fn traffic_light_inset(&self) -> f64 {
+        check_main_thread("traffic_light_inset");
+        let Some(window) = self.window() else {
+            return 0.0;
+        };
+
+        unsafe {
+            let button: Option<Retained<NSView>> = objc2::msg_send![
+                &*window,
+                standardWindowButton: NSWindowButton::CloseButton
+            ];
+            // The buttons sit in the titlebar container, which spans the space they take up
+            let container = button.and_then(|button| -> Option<Retained<NSView>> {
+                objc2::msg_send![&*button, superview]
+            });
+            container.map_or(0.0, |container| {
+                let frame: NSRect = objc2::msg_send![&*container, frame];
+                frame.size.height
+            })
+        }
+    }
+
+    fn apply_traffic_light_inset_to_pane(&self, index: usize) -> Result<(), Error> {
+        trace_operation!("apply_traffic_light_inset_to_pane", self.label, index);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        let inset = self.traffic_light_inset();
+        let applied = self
+            .traffic_light_insets
+            .borrow_mut()
+            .insert(PaneKey::new(&pane), inset)
+            .unwrap_or(0.0);
+        self.inset_pane_content(&pane, Edge::Top, inset - applied);
+        Ok(())
+    }
+
+    fn set_full_height_content(&self, enabled: bool) {
+        trace_operation!("set_full_height_content", self.label, enabled);
+        let Some(window) = self.window() else {
+            log::warn!("Split view `{}` has no window to configure", self.label);
+            return;
+        };
+
+        let inset = unsafe {
+            let mut mask: NSWindowStyleMask = objc2::msg_send![&*window, styleMask];
+            mask.set(NSWindowStyleMask::FullSizeContentView, enabled);
+            // The content view, and a split view filling it, now reaches under the title bar
+            let _: () = objc2::msg_send![&*window, setStyleMask: mask];
+            let _: () = objc2::msg_send![&*window, setTitlebarAppearsTransparent: enabled];
+
+            let content: Option<Retained<NSView>> = objc2::msg_send![&*window, contentView];
+            let content_frame: NSRect =
+                content.map_or(NSRect::ZERO, |content| objc2::msg_send![&*content, frame]);
+            let layout_rect: NSRect = objc2::msg_send![&*window, contentLayoutRect];
+            (content_frame.size.height - layout_rect.size.height).max(0.0)
+        };
+        self.adjust_subviews();
+
+        // The first pane runs behind the title bar, the others start below it
+        for index in 0..self.pane_count() {
+            let Some(pane) = self.pane_at_index(index) else {
+                continue;
+            };
+            let target = if index == 0 { 0.0 } else { inset };
+            let applied = self
+                .title_bar_insets
+                .borrow_mut()
+                .insert(PaneKey::new(&pane), target)
+                .unwrap_or(0.0);
+            self.inset_pane_content(&pane, Edge::Top, target - applied);
+        }
+    }
+
+    fn zoom(&self) {
+        trace_operation!("zoom", self.label);
+        if let Some(window) = self.window() {
+            unsafe {
+                let _: () = objc2::msg_send![&*window, zoom: objc2::ffi::nil];
+            }
+        }
+    }
+
+    fn is_zoomed(&self) -> bool {
+        check_main_thread("is_zoomed");
+        self.window()
+            .is_some_and(|window| unsafe { objc2::msg_send![&*window, isZoomed] })
+    }
+
+    fn size_window_to_fit_panes(&self, cross_axis_extent: f64) {
+        trace_operation!("size_window_to_fit_panes", self.label, cross_axis_extent);
+        let Some(window) = self.window() else {
+            log::warn!("Split view `{}` has no window to resize", self.label);
+            return;
+        };
+
+        let vertical = self.is_vertical();
+        let extents: Vec<f64> = (0..self.pane_count())
+            .filter(|&index| !self.is_pane_collapsed(index))
+            .filter_map(|index| self.pane_at_index(index))
+            .map(|pane| unsafe {
+                // Panes without an intrinsic size, like webviews, report a zero fitting size
+                let fitting: NSSize = objc2::msg_send![&*pane, fittingSize];
+                let frame: NSRect = objc2::msg_send![&*pane, frame];
+                let (fitting, current) = if vertical {
+                    (fitting.width, frame.size.width)
+                } else {
+                    (fitting.height, frame.size.height)
+                };
+                if fitting > 0.0 {
+                    fitting
+                } else {
+                    current
+                }
+            })
+            .collect();
+
+        let dividers = extents.len().saturating_sub(1) as f64 * self.divider_thickness();
+        let total = extents.iter().sum::<f64>() + dividers;
+        let size = if vertical {
+            NSSize::new(total, cross_axis_extent)
+        } else {
+            NSSize::new(cross_axis_extent, total)
+        };
+
+        unsafe {
+            let _: () = objc2::msg_send![&*window, setContentSize: size];
+            let _: () = objc2::msg_send![&*window, center];
+        }
+    }
+
+    fn set_window_collection_behavior(&self, behavior: NSWindowCollectionBehavior) {
+        trace_operation!("set_window_collection_behavior", self.label, behavior);
+        match self.window() {
+            Some(window) => unsafe {
+                let _: () = objc2::msg_send![&*window, setCollectionBehavior: behavior];
+            },
+            None => log::warn!("Split view `{}` has no window to configure", self.label),
+        }
+    }
+
+    fn window_collection_behavior(&self) -> NSWindowCollectionBehavior {
+        check_main_thread("window_collection_behavior");
+        self.window()
+            .map_or(NSWindowCollectionBehavior::Default, |window| unsafe {
+                objc2::msg_send![&*window, collectionBehavior]
+            })
+    }
+
+    fn set_can_join_all_spaces(&self, enabled: bool) {
+        trace_operation!("set_can_join_all_spaces", self.label, enabled);
+        let mut behavior = self.window_collection_behavior();
+        behavior.set(NSWindowCollectionBehavior::CanJoinAllSpaces, enabled);
+        // Joining all Spaces and moving to the active Space are mutually exclusive
+        if enabled {
+            behavior.remove(NSWindowCollectionBehavior::MoveToActiveSpace);
+        }
+        self.set_window_collection_behavior(behavior);
+    }
+
+    fn set_full_screen_allows_tiling(&self, enabled: bool) {
+        trace_operation!("set_full_screen_allows_tiling", self.label, enabled);
+        let mut behavior = self.window_collection_behavior();
+        behavior.set(NSWindowCollectionBehavior::FullScreenAllowsTiling, enabled);
+        behavior.set(
+            NSWindowCollectionBehavior::FullScreenDisallowsTiling,
+            !enabled,
+        );
+        self.set_window_collection_behavior(behavior);
+    }
+
+    fn set_resize_mode(&self, mode: ResizeMode) {
+        trace_operation!("set_resize_mode", self.label, mode);
+        self.ensure_delegate();
+        self.layout.resize_mode.set(mode);
+    }
+
+    fn snapshot(&self) -> SplitViewSnapshot {
+        check_main_thread("snapshot");
+        SplitViewSnapshot {
+            vertical: self.is_vertical(),
+            divider_positions: (0..self.pane_count().saturating_sub(1))
+                .map(|divider_index| self.get_divider_position(divider_index))
+                .collect(),
+            collapsed: (0..self.pane_count())
+                .map(|index| self.is_pane_collapsed(index))
+                .collect(),
+        }
+    }
+
+    fn apply_snapshot(&self, snapshot: &SplitViewSnapshot) {
+        trace_operation!("apply_snapshot", self.label, snapshot);
+        self.apply_snapshot_positions(snapshot);
+        restore_collapsed(self, &snapshot.collapsed);
+    }
+
+    fn enable_autosave_to_path(&self, path: PathBuf) -> Result<(), Error> {
+        trace_operation!("enable_autosave_to_path", self.label, path);
+        // Scheduled by the delegate after each layout pass
+        self.ensure_delegate();
+
+        // The callback looks the split view up when it runs, since it can't hold a reference to it
+        let app_handle = self.app_handle.clone();
+        let label = self.label.clone();
+        let autosave = DeferredCallback::new(
+            self.split_view.mtm(),
+            Box::new(move || {
+                let Ok(split_view) = app_handle.get_split_view(&label) else {
+                    return;
+                };
+                if let Err(error) = write_snapshot(&path, &split_view.snapshot()) {
+                    log::warn!(
+                        "Failed to autosave split view `{}` to {}: {}",
+                        label,
+                        path.display(),
+                        error
+                    );
+                }
+            }),
+        );
+        *self.layout.autosave.borrow_mut() = Some(autosave);
+        Ok(())
+    }
+
+    fn restore_from_path(&self, path: &Path) -> Result<(), Error> {
+        trace_operation!("restore_from_path", self.label, path);
+        let json = std::fs::read(path)?;
+        let snapshot: SplitViewSnapshot = serde_json::from_slice(&json)
+            .map_err(|error| Error::InvalidSnapshot(error.to_string()))?;
+        self.apply_snapshot(&snapshot);
+        Ok(())
+    }
+
+    fn set_layout_locked(&self, locked: bool) {
+        trace_operation!("set_layout_locked", self.label, locked);
+        self.ensure_delegate();
+        *self.layout.locked_fractions.borrow_mut() =
+            locked.then(|| pane_fractions(&self.split_view));
+    }
+
+    fn equalize_panes(&self) {
+        trace_operation!("equalize_panes", self.label);
+        let fractions = equal_fractions(&self.split_view);
+        // Scaling from the current size leaves only the fractions to change
+        let bounds: NSRect = unsafe { objc2::msg_send![&*self.split_view, bounds] };
+        resize_proportionally(&self.split_view, bounds.size, Some(&fractions));
+    }
+
+    fn set_auto_equalize(&self, enabled: bool) {
+        trace_operation!("set_auto_equalize", self.label, enabled);
+        // Applied by the delegate's resizeSubviewsWithOldSize method
+        self.ensure_delegate();
+        self.layout.auto_equalize.set(enabled);
+        if enabled {
+            self.equalize_panes();
+        }
+    }
+
+    fn set_layout_margins(&self, top: f64, left: f64, bottom: f64, right: f64) {
+        trace_operation!("set_layout_margins", self.label, top, left, bottom, right);
+        let Some(container) = self.ensure_margin_container() else {
+            log::warn!(
+                "Split view `{}` has no superview to hold its margins",
+                self.label
+            );
+            return;
+        };
+        self.layout_margins.set((top, left, bottom, right));
+
+        unsafe {
+            let bounds: NSRect = objc2::msg_send![&*container, bounds];
+            let flipped: bool = objc2::msg_send![&*container, isFlipped];
+            let origin_y = if flipped { top } else { bottom };
+            let frame = NSRect::new(
+                NSPoint::new(bounds.origin.x + left, bounds.origin.y + origin_y),
+                NSSize::new(
+                    (bounds.size.width - left - right).max(0.0),
+                    (bounds.size.height - top - bottom).max(0.0),
+                ),
+            );
+            let _: () = objc2::msg_send![&*self.split_view, setFrame: frame];
+        }
+        self.adjust_subviews();
+    }
+
+    fn layout_margins(&self) -> (f64, f64, f64, f64) {
+        check_main_thread("layout_margins");
+        self.layout_margins.get()
+    }
+
+    fn suppress_animations(&self) -> AnimationSuppression {
+        check_main_thread("suppress_animations");
+        AnimationSuppression::new(self.animations_suppressed.clone())
+    }
+
+    fn set_panes_reversed(&self, reversed: bool) {
+        trace_operation!("set_panes_reversed", self.label, reversed);
+        if self.layout.panes_reversed.get() == reversed {
+            return;
+        }
+
+        let result = catch_objc_exception(|| unsafe {
+            let arranged: Retained<NSArray<NSView>> =
+                objc2::msg_send![&*self.split_view, arrangedSubviews];
+            let arranges_all: bool = objc2::msg_send![&*self.split_view, arrangesAllSubviews];
+
+            // A recorded layout order is reversed instead, keeping the drawing order
+            if self.layout.pane_order.borrow().is_empty() {
+                let subviews: Retained<NSArray<NSView>> =
+                    objc2::msg_send![&*self.split_view, subviews];
+                let mut order = subviews.to_vec();
+                order.reverse();
+                let reversed_subviews = NSArray::from_retained_slice(&order);
+                let _: () = objc2::msg_send![&*self.split_view, setSubviews: &*reversed_subviews];
+            }
+
+            // Overlay panes leave the arranged subviews in an order of their own
+            if !arranges_all {
+                let arranged = arranged.to_vec();
+                for view in &arranged {
+                    let _: () = objc2::msg_send![&*self.split_view, removeArrangedSubview: &**view];
+                }
+                for (position, view) in arranged.iter().rev().enumerate() {
+                    let _: () = objc2::msg_send![
+                        &*self.split_view,
+                        insertArrangedSubview: &**view,
+                        atIndex: position as isize
+                    ];
+                }
+            }
+            self.adjust_subviews();
+        });
+
+        match result {
+            Ok(()) => {
+                self.layout.pane_order.borrow_mut().reverse();
+                self.layout.panes_reversed.set(reversed);
+            }
+            Err(error) => log::warn!(
+                "Failed to reverse the panes of split view `{}`: {}",
+                self.label,
+                error
+            ),
+        }
+    }
+
+    fn set_orientation_animated(&self, orientation: SplitViewOrientation, duration_secs: f64) {
+        trace_operation!(
+            "set_orientation_animated",
+            self.label,
+            orientation,
+            duration_secs
+        );
+        if self.is_vertical() == orientation.is_vertical() {
+            return;
+        }
+
+        let fractions: Vec<f64> = self
+            .divider_info()
+            .iter()
+            .map(|info| info.fraction)
+            .collect();
+
+        let animated = duration_secs > 0.0 && self.animations_enabled();
+        let start_frames: Vec<(Retained<NSView>, NSRect)> = unsafe {
+            let arranged: Retained<NSArray<NSView>> =
+                objc2::msg_send![&*self.split_view, arrangedSubviews];
+            arranged
+                .to_vec()
+                .into_iter()
+                .map(|pane| {
+                    let frame: NSRect = objc2::msg_send![&*pane, frame];
+                    (pane, frame)
+                })
+                .collect()
+        };
+
+        unsafe {
+            let _: () = objc2::msg_send![&*self.split_view, setVertical: orientation.is_vertical()];
+        }
+        self.adjust_subviews();
+        self.emit(SplitViewEvent::OrientationChanged {
+            vertical: orientation.is_vertical(),
+        });
+
+        for (index, fraction) in fractions.into_iter().enumerate() {
+            self.set_divider(index, DividerPosition::Fraction(fraction));
+        }
+
+        if !animated {
+            return;
+        }
+
+        unsafe {
+            // AppKit can't animate the axis change, so move each pane from its old frame
+            // to the one laid out for the new axis
+            let wanted_layer: bool = objc2::msg_send![&*self.split_view, wantsLayer];
+            let _: () = objc2::msg_send![&*self.split_view, setWantsLayer: true];
+
+            let _: () = objc2::msg_send![objc2::class!(NSAnimationContext), beginGrouping];
+            let context: Retained<AnyObject> =
+                objc2::msg_send![objc2::class!(NSAnimationContext), currentContext];
+            let _: () = objc2::msg_send![&*context, setDuration: duration_secs];
+            for (pane, start_frame) in &start_frames {
+                let end_frame: NSRect = objc2::msg_send![&**pane, frame];
+                let _: () = objc2::msg_send![&**pane, setFrame: *start_frame];
+                let animator: Retained<AnyObject> = objc2::msg_send![&**pane, animator];
+                let _: () = objc2::msg_send![&*animator, setFrame: end_frame];
+            }
+            let _: () = objc2::msg_send![objc2::class!(NSAnimationContext), endGrouping];
+
+            let split_view = objc2::rc::Weak::new(&*self.split_view);
+            let restore_layer = move || {
+                if let Some(split_view) = split_view.load() {
+                    let _: () = objc2::msg_send![&*split_view, setWantsLayer: wanted_layer];
+                }
+            };
+            DeferredCallback::new(self.split_view.mtm(), Box::new(restore_layer))
+                .schedule_after(duration_secs);
+        }
+    }
+
+    fn last_layout_duration(&self) -> Option<Duration> {
+        check_main_thread("last_layout_duration");
+        self.last_layout_duration.get()
+    }
+
+    fn on_first_layout(&self, callback: Box<dyn FnOnce(&dyn SplitView<R>) + Send + 'static>) {
+        trace_operation!("on_first_layout", self.label);
+        self.ensure_delegate();
+
+        // The callback looks the split view up when it runs, since it can't hold a reference to it
+        let app_handle = self.app_handle.clone();
+        let label = self.label.clone();
+        let callback = Box::new(move || {
+            if let Ok(split_view) = app_handle.get_split_view(&label) {
+                callback(&*split_view);
+            }
+        });
+
+        // The delegate is installed lazily, so it misses layout passes from before this call
+        if !self.layout.did_layout.get() && self.window().is_some() && panes_sized(&self.split_view)
+        {
+            self.layout.did_layout.set(true);
+        }
+
+        if self.layout.did_layout.get() {
+            callback();
+        } else {
+            self.layout
+                .first_layout_callbacks
+                .borrow_mut()
+                .push(callback);
+        }
+    }
+
+    fn set_opaque(&self, opaque: bool) {
+        trace_operation!("set_opaque", self.label, opaque);
+        match self.tauri_split_view() {
+            Some(split_view) => split_view.ivars().opaque.set(opaque),
+            None => log::warn!(
+                "Split view `{}` was not created by tauri-nssplitview, only its layer is marked opaque",
+                self.label
+            ),
+        }
+
+        unsafe {
+            let _: () = objc2::msg_send![&*self.split_view, setWantsLayer: true];
+            let layer: Option<Retained<AnyObject>> = objc2::msg_send![&*self.split_view, layer];
+            if let Some(layer) = layer {
+                let _: () = objc2::msg_send![&*layer, setOpaque: opaque];
+            }
+            let _: () = objc2::msg_send![&*self.split_view, setNeedsDisplay: true];
+        }
+    }
+
+    fn set_layer_contents_scale(&self, scale: f64) {
+        trace_operation!("set_layer_contents_scale", self.label, scale);
+        unsafe {
+            let _: () = objc2::msg_send![&*self.split_view, setWantsLayer: true];
+            let layer: Option<Retained<AnyObject>> = objc2::msg_send![&*self.split_view, layer];
+            if let Some(layer) = layer {
+                let _: () = objc2::msg_send![&*layer, setContentsScale: scale];
+            }
+        }
+    }
+
+    fn set_pane_background_color(
+        &self,
+        index: usize,
+        red: f64,
+        green: f64,
+        blue: f64,
+        alpha: f64,
+    ) -> Result<(), Error> {
+        trace_operation!(
+            "set_pane_background_color",
+            self.label,
+            index,
+            red,
+            green,
+            blue,
+            alpha
+        );
+
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+        set_layer_background_color(&pane, (red, green, blue, alpha));
+        Ok(())
+    }
+
+    fn pane_background_color(&self, index: usize) -> Option<(f64, f64, f64, f64)> {
+        check_main_thread("pane_background_color");
+        let pane = self.pane_at_index(index)?;
+
+        unsafe {
+            let layer: Option<Retained<AnyObject>> = objc2::msg_send![&*pane, layer];
+            let cg_color: *mut AnyObject = objc2::msg_send![&*layer?, backgroundColor];
+            if cg_color.is_null() {
+                return None;
+            }
+
+            let color: Option<Retained<NSColor>> =
+                objc2::msg_send![NSColor::class(), colorWithCGColor: cg_color];
+            let color_space: Retained<NSColorSpace> =
+                objc2::msg_send![NSColorSpace::class(), deviceRGBColorSpace];
+            let color: Option<Retained<NSColor>> =
+                objc2::msg_send![&*color?, colorUsingColorSpace: &*color_space];
+            let color = color?;
+
+            Some((
+                objc2::msg_send![&*color, redComponent],
+                objc2::msg_send![&*color, greenComponent],
+                objc2::msg_send![&*color, blueComponent],
+                objc2::msg_send![&*color, alphaComponent],
+            ))
+        }
+    }
+
+    fn set_pane_clips_to_bounds(&self, index: usize, clips: bool) -> Result<(), Error> {
+        trace_operation!("set_pane_clips_to_bounds", self.label, index, clips);
+        let pane = self
+            .pane_at_index(index)
+            .ok_or(Error::InvalidPaneIndex(index))?;
+
+        unsafe {
+            let _: () = objc2::msg_send![&*pane, setWantsLayer: true];
+            let layer: Option<Retained<AnyObject>> = objc2::msg_send![&*pane, layer];
+            let layer = layer.ok_or(Error::PaneNotLayerBacked(index))?;
+            let _: () = objc2::msg_send![&*layer, setMasksToBounds: clips];
+        }
+        Ok(())
+    }
+
+    fn pane_backing_scale_factor(&self, index: usize) -> Option<f64> {
+        check_main_thread("pane_backing_scale_factor");
+        let pane = self.pane_at_index(index)?;
+        unsafe {
+            let window: Option<Retained<NSWindow>> = objc2::msg_send![&*pane, window];
+            let window = window?;
+
+            // A layer-backed pane may render at its own contents scale
+            let layer: Option<Retained<AnyObject>> = objc2::msg_send![&*pane, layer];
+            if let Some(layer) = layer {
+                let scale: f64 = objc2::msg_send![&*layer, contentsScale];
+                if scale > 0.0 {
+                    return Some(scale);
+                }
+            }
+            Some(objc2::msg_send![&*window, backingScaleFactor])
+        }
+    }
+}
+
+impl<R: Runtime> Drop for BasicSplitView<R> {
+    fn drop(&mut self) {
+        // Tracking areas stay on their views but don't retain their owners
+        let hover_handlers = self.pane_hover_handlers.take();
+        if MainThreadMarker::new().is_none() {
+            // The areas can only be removed on the main thread, so keep their owners alive
+            std::mem::forget(self.split_view_delegate.take());
+            std::mem::forget(hover_handlers);
+            return;
+        }
+
+        remove_divider_tracking_areas(&self.split_view, &self.layout);
+        for (pane, (tracking_area, _target)) in hover_handlers {
+            unsafe {
+                let _: () = objc2::msg_send![pane.view(), removeTrackingArea: &*tracking_area];
+            }
+        }
+    }
 }
 
 impl<R: Runtime> FromWindow<R> for BasicSplitView<R> {
     fn from_window(window: WebviewWindow<R>, label: String) -> tauri::Result<Self> {
-        unsafe {
+        check_main_thread("from_window");
+        // Raised exceptions surface as errors rather than aborting the app
+        catch_objc_exception(|| unsafe {
             // Get the NSWindow as a raw pointer
             let ns_window_ptr = window.ns_window().map_err(|e| {
-                tauri::Error::Io(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!("Failed to get NSWindow: {:?}", e),
-                ))
+                tauri::Error::Io(std::io::Error::other(format!(
+                    "Failed to get NSWindow: {:?}",
+                    e
+                )))
             })?;
             let ns_window = ns_window_ptr as *mut AnyObject;
 
             // Get the current content view before we replace it
-            let original_content_view: *mut AnyObject = objc2::msg_send![ns_window, contentView];
+            let original_content_view: Option<Retained<NSView>> =
+                objc2::msg_send![ns_window, contentView];
+            let original_content_view = non_nil(original_content_view, "contentView")?;
 
             // Create an NSSplitView
-            let content_frame: NSRect = objc2::msg_send![original_content_view, frame];
+            let content_frame: NSRect = objc2::msg_send![&*original_content_view, frame];
 
             // Allocate and initialize the split view
-            let alloc: *mut AnyObject = objc2::msg_send![NSSplitView::class(), alloc];
-            let init: *mut AnyObject = objc2::msg_send![alloc, initWithFrame: content_frame];
-            let split_view = Retained::retain(init as *mut NSSplitView).unwrap();
+            let split_view =
+                TauriSplitView::new(original_content_view.mtm(), content_frame).into_super();
 
             // Set vertical orientation by default
             let _: () = objc2::msg_send![&*split_view, setVertical: true];
@@ -270,13 +3843,54 @@ impl<R: Runtime> FromWindow<R> for BasicSplitView<R> {
             let _: () = objc2::msg_send![ns_window, setContentView: &*split_view];
 
             // Add the original content view as the first pane
-            let _: () = objc2::msg_send![&*split_view, addSubview: original_content_view];
+            let _: () = objc2::msg_send![&*split_view, addSubview: &*original_content_view];
 
-            Ok(BasicSplitView::new(
-                split_view,
-                label,
-                window.app_handle().clone(),
-            ))
-        }
+            let split_view = BasicSplitView::new(split_view, label, window.app_handle().clone());
+
+            // The original content view shows the window's webview
+            split_view.track_webview_pane(original_content_view, window.label().to_string());
+
+            Ok(split_view)
+        })?
+    }
+}
+
+#[cfg(all(test, target_os = "macos"))]
+mod tests {
+    use objc2::rc::Retained;
+    use objc2::runtime::AnyObject;
+    use objc2_foundation::{NSArray, NSObject, NSSize};
+
+    use super::DividerPosition;
+
+    #[test]
+    fn divider_fractions_convert_along_the_split_axis() {
+        let size = NSSize::new(800.0, 400.0);
+        let half = DividerPosition::Fraction(0.5);
+
+        assert_eq!(half.to_points(size, true, false, 1.0), 400.0);
+        assert_eq!(half.to_points(size, false, false, 1.0), 200.0);
+        assert_eq!(
+            DividerPosition::Fraction(0.25).to_points(size, true, true, 1.0),
+            599.0
+        );
+        assert_eq!(
+            DividerPosition::Points(120.0).to_points(size, true, true, 1.0),
+            120.0
+        );
+    }
+
+    #[test]
+    fn objc_exceptions_become_errors() {
+        let result = super::catch_objc_exception(|| unsafe {
+            let empty = NSArray::<NSObject>::new();
+            let _: Retained<AnyObject> = objc2::msg_send![&*empty, objectAtIndex: 3usize];
+        });
+        let Err(crate::Error::ObjCOperation(reason)) = &result else {
+            panic!("expected an Objective-C error, got {:?}", result);
+        };
+        assert!(reason.contains("objectAtIndex:"), "{}", reason);
+
+        assert_eq!(super::catch_objc_exception(|| 7).unwrap(), 7);
     }
 }
@@ -0,0 +1,302 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{define_class, msg_send, ClassType, DefinedClass, MainThreadOnly};
+use objc2_app_kit::{
+    NSColor, NSCompositingOperation, NSEvent, NSImage, NSResponder, NSSplitView, NSView, NSWindow,
+};
+use objc2_foundation::{
+    MainThreadMarker, NSArray, NSObject, NSObjectProtocol, NSPoint, NSRect, NSSize,
+};
+
+pub(crate) struct TauriSplitViewIvars {
+    pub dividers_visible: Cell<bool>,
+    pub opaque: Cell<bool>,
+    /// sRGB components of the divider color, if overridden
+    pub divider_color: Cell<Option<(f64, f64, f64, f64)>>,
+    /// Index of the divider being dragged, set for the duration of the drag
+    pub active_divider: Cell<Option<usize>>,
+    /// Divider thickness in points, if overridden
+    pub divider_thickness: Cell<Option<f64>>,
+    /// Images drawn over dividers, keyed by divider index
+    pub divider_images: RefCell<HashMap<usize, Retained<NSImage>>>,
+    /// Whether the split view can become first responder, if overridden
+    pub accepts_first_responder: Cell<Option<bool>>,
+    /// Points moved per arrow key press, keyed by divider index
+    pub keyboard_dividers: RefCell<HashMap<usize, f64>>,
+    /// Index of the divider the arrow keys move
+    pub focused_divider: Cell<Option<usize>>,
+}
+
+/// Virtual key codes of the arrow keys
+const LEFT_ARROW_KEY: u16 = 123;
+const RIGHT_ARROW_KEY: u16 = 124;
+const DOWN_ARROW_KEY: u16 = 125;
+const UP_ARROW_KEY: u16 = 126;
+
+define_class!(
+    /// NSSplitView subclass created by the crate to customize divider drawing
+    #[unsafe(super(NSSplitView, NSView, NSResponder, NSObject))]
+    #[name = "TauriSplitView"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = TauriSplitViewIvars]
+    pub(crate) struct TauriSplitView;
+
+    unsafe impl NSObjectProtocol for TauriSplitView {}
+
+    impl TauriSplitView {
+        #[unsafe(method(drawDividerInRect:))]
+        fn draw_divider_in_rect(&self, rect: NSRect) {
+            // Hidden dividers keep their thickness so the layout doesn't change
+            if self.ivars().dividers_visible.get() {
+                unsafe {
+                    let _: () = msg_send![super(self), drawDividerInRect: rect];
+                }
+
+                let center = NSPoint::new(
+                    rect.origin.x + rect.size.width / 2.0,
+                    rect.origin.y + rect.size.height / 2.0,
+                );
+                let image = self
+                    .divider_at_point(center)
+                    .and_then(|index| self.ivars().divider_images.borrow().get(&index).cloned());
+                if let Some(image) = image {
+                    draw_image_centered(&image, rect);
+                }
+            }
+        }
+
+        #[unsafe(method_id(dividerColor))]
+        fn divider_color(&self) -> Retained<NSColor> {
+            match self.ivars().divider_color.get() {
+                Some((red, green, blue, alpha)) => unsafe {
+                    msg_send![
+                        NSColor::class(),
+                        colorWithSRGBRed: red,
+                        green: green,
+                        blue: blue,
+                        alpha: alpha
+                    ]
+                },
+                None => unsafe { msg_send![super(self), dividerColor] },
+            }
+        }
+
+        #[unsafe(method(dividerThickness))]
+        fn divider_thickness(&self) -> f64 {
+            match self.ivars().divider_thickness.get() {
+                Some(thickness) => thickness,
+                None => unsafe { msg_send![super(self), dividerThickness] },
+            }
+        }
+
+        #[unsafe(method(mouseDown:))]
+        fn mouse_down(&self, event: &NSEvent) {
+            unsafe {
+                let window_point: NSPoint = msg_send![event, locationInWindow];
+                let point: NSPoint =
+                    msg_send![self, convertPoint: window_point, fromView: std::ptr::null::<NSView>()];
+                let divider = self.divider_at_point(point);
+                self.ivars().active_divider.set(divider);
+
+                // Clicking a keyboard resizable divider gives it keyboard focus
+                let keyboard = divider.filter(|index| {
+                    self.ivars().keyboard_dividers.borrow().contains_key(index)
+                });
+                if keyboard.is_some() {
+                    self.ivars().focused_divider.set(keyboard);
+                    let window: Option<Retained<NSWindow>> = msg_send![self, window];
+                    if let Some(window) = window {
+                        let _: bool = msg_send![&*window, makeFirstResponder: self];
+                    }
+                }
+
+                // NSSplitView tracks the drag until the mouse goes up before returning
+                let _: () = msg_send![super(self), mouseDown: event];
+            }
+            self.ivars().active_divider.set(None);
+        }
+
+        #[unsafe(method(acceptsFirstResponder))]
+        fn accepts_first_responder(&self) -> bool {
+            match self.ivars().accepts_first_responder.get() {
+                Some(accepts) => accepts,
+                None if !self.ivars().keyboard_dividers.borrow().is_empty() => true,
+                None => unsafe { msg_send![super(self), acceptsFirstResponder] },
+            }
+        }
+
+        #[unsafe(method(becomeFirstResponder))]
+        fn become_first_responder(&self) -> bool {
+            // Tabbing into the split view focuses the first keyboard resizable divider
+            if self.ivars().focused_divider.get().is_none() {
+                let first = self.ivars().keyboard_dividers.borrow().keys().min().copied();
+                self.ivars().focused_divider.set(first);
+            }
+            unsafe { msg_send![super(self), becomeFirstResponder] }
+        }
+
+        #[unsafe(method(keyDown:))]
+        fn key_down(&self, event: &NSEvent) {
+            if !self.move_focused_divider(event) {
+                unsafe {
+                    let _: () = msg_send![super(self), keyDown: event];
+                }
+            }
+        }
+
+        #[unsafe(method(isOpaque))]
+        fn is_opaque(&self) -> bool {
+            self.ivars().opaque.get()
+        }
+    }
+);
+
+impl TauriSplitView {
+    /// Create a new split view with the given frame
+    pub(crate) fn new(mtm: MainThreadMarker, frame: NSRect) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(TauriSplitViewIvars {
+            dividers_visible: Cell::new(true),
+            opaque: Cell::new(false),
+            divider_color: Cell::new(None),
+            divider_thickness: Cell::new(None),
+            active_divider: Cell::new(None),
+            divider_images: RefCell::new(HashMap::new()),
+            accepts_first_responder: Cell::new(None),
+            keyboard_dividers: RefCell::new(HashMap::new()),
+            focused_divider: Cell::new(None),
+        });
+        unsafe { msg_send![super(this), initWithFrame: frame] }
+    }
+
+    /// Find the divider whose gap between arranged panes contains `point`
+    fn divider_at_point(&self, point: NSPoint) -> Option<usize> {
+        let vertical: bool = unsafe { msg_send![self, isVertical] };
+        let coordinate = if vertical { point.x } else { point.y };
+        let count: usize = unsafe {
+            let subviews: Retained<NSArray<NSView>> = msg_send![self, arrangedSubviews];
+            msg_send![&*subviews, count]
+        };
+
+        (0..count.saturating_sub(1)).find(|&index| {
+            self.divider_gap(index)
+                .is_some_and(|(start, end)| coordinate >= start && coordinate <= end)
+        })
+    }
+
+    /// Get the start and end of the gap between the arranged panes around a divider
+    fn divider_gap(&self, index: usize) -> Option<(f64, f64)> {
+        unsafe {
+            let subviews: Retained<NSArray<NSView>> = msg_send![self, arrangedSubviews];
+            let count: usize = msg_send![&*subviews, count];
+            if index + 1 >= count {
+                return None;
+            }
+
+            let vertical: bool = msg_send![self, isVertical];
+            let leading: Retained<NSView> = msg_send![&*subviews, objectAtIndex: index];
+            let trailing: Retained<NSView> = msg_send![&*subviews, objectAtIndex: index + 1];
+            let leading: NSRect = msg_send![&*leading, frame];
+            let trailing: NSRect = msg_send![&*trailing, frame];
+
+            // Right-to-left layouts put the trailing pane on the left
+            Some(if !vertical {
+                (leading.origin.y + leading.size.height, trailing.origin.y)
+            } else if trailing.origin.x < leading.origin.x {
+                (trailing.origin.x + trailing.size.width, leading.origin.x)
+            } else {
+                (leading.origin.x + leading.size.width, trailing.origin.x)
+            })
+        }
+    }
+
+    /// Move the focused divider for an arrow key press, returning whether it moved
+    fn move_focused_divider(&self, event: &NSEvent) -> bool {
+        let Some(index) = self.ivars().focused_divider.get() else {
+            return false;
+        };
+        let Some(step) = self.ivars().keyboard_dividers.borrow().get(&index).copied() else {
+            return false;
+        };
+        let Some((position, _)) = self.divider_gap(index) else {
+            return false;
+        };
+
+        unsafe {
+            let vertical: bool = msg_send![self, isVertical];
+            let key_code: u16 = msg_send![event, keyCode];
+            let Some(delta) = arrow_key_delta(vertical, key_code, step) else {
+                return false;
+            };
+            let _: () = msg_send![
+                self,
+                setPosition: position + delta,
+                ofDividerAtIndex: index as isize
+            ];
+        }
+        true
+    }
+}
+
+/// Get how far an arrow key moves a divider along the split axis, if it moves it at all
+fn arrow_key_delta(vertical: bool, key_code: u16, step: f64) -> Option<f64> {
+    // The split view is flipped, so down moves towards larger coordinates
+    match (vertical, key_code) {
+        (true, LEFT_ARROW_KEY) | (false, UP_ARROW_KEY) => Some(-step),
+        (true, RIGHT_ARROW_KEY) | (false, DOWN_ARROW_KEY) => Some(step),
+        _ => None,
+    }
+}
+
+/// Draw an image centered in `rect`, scaled down to fit if it's larger
+fn draw_image_centered(image: &NSImage, rect: NSRect) {
+    unsafe {
+        let size: NSSize = msg_send![image, size];
+        if size.width <= 0.0 || size.height <= 0.0 {
+            return;
+        }
+
+        let scale = (rect.size.width / size.width)
+            .min(rect.size.height / size.height)
+            .min(1.0);
+        let (width, height) = (size.width * scale, size.height * scale);
+        let target = NSRect::new(
+            NSPoint::new(
+                rect.origin.x + (rect.size.width - width) / 2.0,
+                rect.origin.y + (rect.size.height - height) / 2.0,
+            ),
+            NSSize::new(width, height),
+        );
+
+        // A zero source rect draws the whole image
+        let _: () = msg_send![
+            image,
+            drawInRect: target,
+            fromRect: NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(0.0, 0.0)),
+            operation: NSCompositingOperation::SourceOver,
+            fraction: 1.0f64,
+            respectFlipped: true,
+            hints: std::ptr::null::<AnyObject>()
+        ];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{arrow_key_delta, DOWN_ARROW_KEY, LEFT_ARROW_KEY, RIGHT_ARROW_KEY, UP_ARROW_KEY};
+
+    #[test]
+    fn arrow_keys_move_along_the_split_axis() {
+        assert_eq!(arrow_key_delta(true, LEFT_ARROW_KEY, 8.0), Some(-8.0));
+        assert_eq!(arrow_key_delta(true, RIGHT_ARROW_KEY, 8.0), Some(8.0));
+        assert_eq!(arrow_key_delta(false, UP_ARROW_KEY, 8.0), Some(-8.0));
+        assert_eq!(arrow_key_delta(false, DOWN_ARROW_KEY, 8.0), Some(8.0));
+
+        // Keys across the split axis and other keys are left to the responder chain
+        assert_eq!(arrow_key_delta(true, UP_ARROW_KEY, 8.0), None);
+        assert_eq!(arrow_key_delta(false, RIGHT_ARROW_KEY, 8.0), None);
+        assert_eq!(arrow_key_delta(true, 0, 8.0), None);
+    }
+}
@@ -0,0 +1,24 @@
+/// Check the thread and emit a tracing event for a mutating split view operation
+///
+/// Panics off the main thread when main thread checks are enabled, see
+/// [`set_main_thread_checks`](crate::main_thread::set_main_thread_checks). The event is
+/// only emitted when the `tracing` feature is enabled. Fields are recorded with their
+/// `Debug` representation.
+///
+/// ```ignore
+/// trace_operation!("set_divider_position", self.label, divider_index, position);
+/// ```
+macro_rules! trace_operation {
+    ($operation:literal, $label:expr $(, $field:ident)* $(,)?) => {
+        $crate::main_thread::check_main_thread($operation);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            operation = $operation,
+            label = %$label,
+            $($field = ?$field,)*
+            "split view operation"
+        );
+    };
+}
+
+pub(crate) use trace_operation;
@@ -0,0 +1,99 @@
+use std::any::Any;
+use std::convert::Infallible;
+use std::marker::PhantomData;
+
+use tauri::{AppHandle, Runtime, WebviewWindow};
+
+use crate::{Error, FromWindow};
+
+/// Common trait for all split view types
+///
+/// Only the platform-independent queries are available outside macOS.
+pub trait SplitView<R: tauri::Runtime = tauri::Wry>: Send + Sync {
+    /// Show the split view
+    fn show(&self);
+
+    /// Hide the split view
+    fn hide(&self);
+
+    /// Convert split view back to a regular Tauri window
+    fn to_window(&self) -> Option<WebviewWindow<R>>;
+
+    /// Get the app handle the split view belongs to
+    fn app_handle(&self) -> AppHandle<R>;
+
+    /// Get the split view label
+    fn label(&self) -> &str;
+
+    /// Downcast to concrete type
+    fn as_any(&self) -> &dyn Any;
+
+    /// Check if the split view is visible
+    fn is_visible(&self) -> bool;
+
+    /// Check if split view is vertical (true) or horizontal (false)
+    fn is_vertical(&self) -> bool;
+
+    /// Get number of panes
+    fn pane_count(&self) -> usize;
+
+    /// Get divider thickness
+    fn divider_thickness(&self) -> f64;
+}
+
+/// Split view that can't be created outside macOS
+///
+/// Converting a window always fails with [`Error::UnsupportedPlatform`], so
+/// cross-platform apps can call the crate unconditionally and handle the error.
+pub struct BasicSplitView<R: Runtime = tauri::Wry> {
+    never: Infallible,
+    _runtime: PhantomData<fn() -> R>,
+}
+
+impl<R: Runtime> SplitView<R> for BasicSplitView<R> {
+    fn show(&self) {
+        match self.never {}
+    }
+
+    fn hide(&self) {
+        match self.never {}
+    }
+
+    fn to_window(&self) -> Option<WebviewWindow<R>> {
+        match self.never {}
+    }
+
+    fn app_handle(&self) -> AppHandle<R> {
+        match self.never {}
+    }
+
+    fn label(&self) -> &str {
+        match self.never {}
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        match self.never {}
+    }
+
+    fn is_visible(&self) -> bool {
+        match self.never {}
+    }
+
+    fn is_vertical(&self) -> bool {
+        match self.never {}
+    }
+
+    fn pane_count(&self) -> usize {
+        match self.never {}
+    }
+
+    fn divider_thickness(&self) -> f64 {
+        match self.never {}
+    }
+}
+
+impl<R: Runtime> FromWindow<R> for BasicSplitView<R> {
+    fn from_window(_window: WebviewWindow<R>, _label: String) -> tauri::Result<Self> {
+        Err(Error::UnsupportedPlatform.into())
+    }
+}
@@ -0,0 +1,191 @@
+use objc2::rc::Retained;
+use objc2::MainThreadMarker;
+use objc2_app_kit::{NSAutoresizingMaskOptions, NSView};
+use objc2_foundation::{NSObject, NSPoint, NSRect, NSSize};
+use tauri::AppHandle;
+use tauri_nssplitview::{BasicSplitView, Error, PaneKind, SplitView};
+
+use crate::split_view_with_panes;
+
+tests![
+    adding_past_the_maximum_pane_count_fails,
+    panes_containing_the_split_view_are_rejected,
+    raw_subviews_are_not_tracked_as_panes,
+    pane_kinds_tell_crate_panes_from_raw_subviews,
+    deferred_panes_wait_for_the_layout_commit,
+    hosting_panes_fill_their_pane_and_must_be_views,
+    made_views_keep_their_frame_and_color,
+    layout_passes_record_their_duration,
+    hierarchy_descriptions_indent_each_level,
+    split_views_outside_a_window_are_not_content_views,
+];
+
+fn adding_past_the_maximum_pane_count_fails(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    split_view.set_max_panes(Some(3));
+    let mtm = MainThreadMarker::new().unwrap();
+    let frame = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(100.0, 100.0));
+
+    let extra = vec![
+        BasicSplitView::make_view(mtm, frame),
+        BasicSplitView::make_view(mtm, frame),
+    ];
+    assert!(matches!(
+        split_view.add_panes(extra),
+        Err(Error::MaxPanesReached(3))
+    ));
+    assert_eq!(split_view.pane_count(), 2);
+
+    let pane = BasicSplitView::make_view(mtm, frame);
+    assert_eq!(split_view.add_pane(&pane).unwrap(), 2);
+    let pane = BasicSplitView::make_view(mtm, frame);
+    assert!(matches!(
+        split_view.add_pane(&pane),
+        Err(Error::MaxPanesReached(3))
+    ));
+
+    split_view.set_max_panes(None);
+    let pane = BasicSplitView::make_view(mtm, frame);
+    assert_eq!(split_view.add_pane(&pane).unwrap(), 3);
+}
+
+fn panes_containing_the_split_view_are_rejected(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 1);
+    let mtm = MainThreadMarker::new().unwrap();
+    let container = BasicSplitView::make_view(mtm, NSRect::ZERO);
+    container.addSubview(split_view.as_split_view());
+
+    assert!(matches!(
+        split_view.add_pane(split_view.as_split_view()),
+        Err(Error::InvalidHierarchy)
+    ));
+    assert!(matches!(
+        split_view.add_pane(&container),
+        Err(Error::InvalidHierarchy)
+    ));
+
+    // A batch with one bad view adds none of them
+    let batch = vec![BasicSplitView::make_view(mtm, NSRect::ZERO), container];
+    assert!(matches!(
+        split_view.add_panes(batch),
+        Err(Error::InvalidHierarchy)
+    ));
+    assert_eq!(split_view.pane_count(), 1);
+}
+
+fn raw_subviews_are_not_tracked_as_panes(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    assert_eq!(split_view.tracked_pane_count(), 2);
+    assert_eq!(split_view.pane_count(), 2);
+
+    let mtm = MainThreadMarker::new().unwrap();
+    let raw = BasicSplitView::make_view(mtm, NSRect::ZERO);
+    split_view.as_split_view().addSubview(&raw);
+    assert_eq!(split_view.tracked_pane_count(), 2);
+    assert_eq!(split_view.pane_count(), 3);
+
+    // Adding through the crate picks the raw subview up again
+    let pane = BasicSplitView::make_view(mtm, NSRect::ZERO);
+    split_view.add_pane(&pane).unwrap();
+    assert_eq!(split_view.tracked_pane_count(), 4);
+    assert_eq!(split_view.pane_count(), 4);
+}
+
+fn pane_kinds_tell_crate_panes_from_raw_subviews(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 1);
+    let raw = BasicSplitView::make_view(MainThreadMarker::new().unwrap(), NSRect::ZERO);
+    split_view.as_split_view().addSubview(&raw);
+
+    assert_eq!(split_view.pane_kind(0), Some(PaneKind::Native));
+    assert_eq!(split_view.pane_kind(1), Some(PaneKind::Unknown));
+    assert_eq!(split_view.pane_kind(2), None);
+}
+
+fn deferred_panes_wait_for_the_layout_commit(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 0);
+    let mtm = MainThreadMarker::new().unwrap();
+    let panes = [
+        BasicSplitView::make_view(mtm, NSRect::ZERO),
+        BasicSplitView::make_view(mtm, NSRect::ZERO),
+    ];
+    for (index, pane) in panes.iter().enumerate() {
+        assert_eq!(split_view.add_pane_deferred(pane).unwrap(), index);
+    }
+    assert!(panes.iter().all(|pane| pane.frame().size.width == 0.0));
+
+    split_view.commit_layout();
+    let divider = split_view.divider_thickness();
+    let widths: f64 = panes.iter().map(|pane| pane.frame().size.width).sum();
+    assert_eq!(widths + divider, 600.0);
+    assert!(panes.iter().all(|pane| pane.frame().size.height == 400.0));
+}
+
+fn hosting_panes_fill_their_pane_and_must_be_views(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 1);
+    let hosting_view = BasicSplitView::make_view(MainThreadMarker::new().unwrap(), NSRect::ZERO);
+    assert_eq!(
+        split_view.add_hosting_pane(hosting_view.clone()).unwrap(),
+        1
+    );
+    assert_eq!(
+        hosting_view.autoresizingMask(),
+        NSAutoresizingMaskOptions::ViewWidthSizable | NSAutoresizingMaskOptions::ViewHeightSizable
+    );
+
+    // SAFETY: The object is only sent isKindOfClass: before it's rejected
+    let not_a_view = unsafe { Retained::cast_unchecked::<NSView>(NSObject::new()) };
+    assert!(matches!(
+        split_view.add_hosting_pane(not_a_view),
+        Err(Error::ObjCOperation(_))
+    ));
+    assert_eq!(split_view.pane_count(), 2);
+}
+
+fn made_views_keep_their_frame_and_color(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let frame = NSRect::new(NSPoint::new(10.0, 20.0), NSSize::new(300.0, 200.0));
+    let plain = BasicSplitView::make_view(mtm, frame);
+    assert_eq!(plain.frame(), frame);
+    assert!(!plain.wantsLayer());
+
+    let colored = BasicSplitView::make_colored_view(mtm, frame, (0.5, 0.5, 0.5, 0.25));
+    assert_eq!(colored.frame(), frame);
+    assert!(colored.wantsLayer());
+
+    let (split_view, _) = split_view_with_panes(app, 0);
+    split_view.add_pane(&colored).unwrap();
+    let (_, _, _, alpha) = split_view.pane_background_color(0).unwrap();
+    assert!((alpha - 0.25).abs() < 1e-6);
+}
+
+fn layout_passes_record_their_duration(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 0);
+    assert_eq!(split_view.last_layout_duration(), None);
+
+    let pane = BasicSplitView::make_view(MainThreadMarker::new().unwrap(), NSRect::ZERO);
+    split_view.add_pane(&pane).unwrap();
+    assert!(split_view.last_layout_duration().is_some());
+}
+
+fn hierarchy_descriptions_indent_each_level(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    let container = BasicSplitView::make_view(MainThreadMarker::new().unwrap(), NSRect::ZERO);
+    container.addSubview(split_view.as_split_view());
+
+    let description = split_view.describe_hierarchy();
+    let lines: Vec<&str> = description.lines().collect();
+    assert_eq!(lines.len(), 4, "{}", description);
+    assert!(lines[0].starts_with("NSView (0, 0, 0, 0)"));
+    assert!(lines[1].starts_with("  NSSplitView (0, 0, 600, 400)"));
+    assert!(lines[2].starts_with("    pane 0: NSView"));
+    assert!(lines[3].starts_with("    pane 1: NSView"));
+}
+
+fn split_views_outside_a_window_are_not_content_views(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    assert!(!split_view.is_content_view());
+
+    let container = BasicSplitView::make_view(MainThreadMarker::new().unwrap(), NSRect::ZERO);
+    container.addSubview(split_view.as_split_view());
+    assert!(!split_view.is_content_view());
+}
@@ -0,0 +1,73 @@
+use objc2::MainThreadMarker;
+use objc2_app_kit::NSView;
+use tauri::{AppHandle, WebviewUrl, WebviewWindowBuilder};
+use tauri_nssplitview::{
+    BasicSplitView, ControllerSplitView, Error, ManagerExt, SplitViewSnapshot, WebviewWindowExt,
+};
+
+use crate::run_main_loop;
+
+tests![
+    autosave_writes_the_layout_once_resizing_settles,
+    controller_autosave_is_unsupported,
+];
+
+fn autosave_writes_the_layout_once_resizing_settles(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let window = WebviewWindowBuilder::new(app, "autosave", WebviewUrl::App("index.html".into()))
+        .inner_size(600.0, 400.0)
+        .build()
+        .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+    split_view
+        .add_panes(vec![NSView::new(mtm), NSView::new(mtm)])
+        .unwrap();
+
+    let path = std::env::temp_dir().join(format!(
+        "tauri-nssplitview-autosave-{}.json",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    split_view.enable_autosave_to_path(path.clone()).unwrap();
+
+    split_view.set_divider_position(0, 150.0);
+    split_view.set_divider_position(1, 400.0);
+    // Nothing is written while resizing is still going on
+    run_main_loop(0.05);
+    assert!(!path.exists());
+
+    run_main_loop(0.5);
+    let saved: SplitViewSnapshot = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+    assert_eq!(saved, split_view.snapshot());
+    assert_eq!(saved.divider_positions.len(), 2);
+    assert_eq!(saved.divider_positions[0], 150.0);
+
+    // Restoring puts moved dividers back
+    split_view.set_divider_position(0, 250.0);
+    split_view.restore_from_path(&path).unwrap();
+    assert_eq!(split_view.get_divider_position(0), 150.0);
+
+    std::fs::remove_file(&path).unwrap();
+    app.remove_split_view("autosave");
+    window.destroy().unwrap();
+}
+
+fn controller_autosave_is_unsupported(app: &AppHandle) {
+    let window = WebviewWindowBuilder::new(
+        app,
+        "autosave-controller",
+        WebviewUrl::App("index.html".into()),
+    )
+    .build()
+    .unwrap();
+    let split_view = window.to_split_view::<ControllerSplitView>().unwrap();
+
+    let path = std::env::temp_dir().join("tauri-nssplitview-unused.json");
+    assert!(matches!(
+        split_view.enable_autosave_to_path(path),
+        Err(Error::Unsupported("enable_autosave_to_path"))
+    ));
+
+    app.remove_split_view("autosave-controller");
+    window.destroy().unwrap();
+}
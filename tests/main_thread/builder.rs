@@ -0,0 +1,102 @@
+use objc2::MainThreadMarker;
+use objc2_app_kit::{NSTextField, NSView};
+use objc2_foundation::{NSRect, NSString};
+use tauri::{AppHandle, LogicalSize, Manager};
+use tauri_nssplitview::{BasicSplitView, ManagerExt, PaneKind, SplitViewBuilder};
+
+tests![
+    initial_focus_goes_to_the_configured_pane,
+    native_pane_closures_add_their_views_in_order,
+    wrapped_original_content_becomes_pane_zero,
+    hidden_builds_stay_ordered_out_until_shown,
+];
+
+/// Remove the split view labelled `label` and destroy its window
+fn destroy(app: &AppHandle, label: &str) {
+    app.remove_split_view(label);
+    app.get_webview_window(label).unwrap().destroy().unwrap();
+}
+
+fn initial_focus_goes_to_the_configured_pane(app: &AppHandle) {
+    let split_view = SplitViewBuilder::<_, BasicSplitView>::new(app, "initial-focus")
+        .size(LogicalSize::new(600.0, 400.0).into())
+        .add_native_pane_with(|mtm| {
+            let pane = NSView::new(mtm);
+            pane.addSubview(&NSTextField::textFieldWithString(&NSString::new(), mtm));
+            pane
+        })
+        .initial_focus_pane(1)
+        .build()
+        .unwrap();
+    assert_eq!(split_view.focused_pane_index(), Some(1));
+
+    // An index past the last pane is ignored rather than failing the build
+    let unfocused = SplitViewBuilder::<_, BasicSplitView>::new(app, "initial-focus-missing")
+        .initial_focus_pane(5)
+        .build()
+        .unwrap();
+    assert_eq!(unfocused.pane_count(), 1);
+
+    destroy(app, "initial-focus");
+    destroy(app, "initial-focus-missing");
+}
+
+fn native_pane_closures_add_their_views_in_order(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let (first, second) = (NSView::new(mtm), NSView::new(mtm));
+    let split_view = SplitViewBuilder::<_, BasicSplitView>::new(app, "native-pane-closures")
+        .add_native_pane_with({
+            let first = first.clone();
+            move |_| first
+        })
+        .add_native_pane_with({
+            let second = second.clone();
+            move |_| second
+        })
+        .build()
+        .unwrap();
+
+    assert_eq!(split_view.pane_count(), 3);
+    assert_eq!(split_view.pane_at_index(1), Some(first));
+    assert_eq!(split_view.pane_at_index(2), Some(second));
+    assert_eq!(split_view.pane_kind(1), Some(PaneKind::Native));
+
+    destroy(app, "native-pane-closures");
+}
+
+fn wrapped_original_content_becomes_pane_zero(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let container = BasicSplitView::make_colored_view(mtm, NSRect::ZERO, (0.1, 0.1, 0.1, 1.0));
+    let split_view = SplitViewBuilder::<_, BasicSplitView>::new(app, "wrapped-content")
+        .wrap_original_content({
+            let container = container.clone();
+            move |content| {
+                container.setFrame(content.frame());
+                container.addSubview(&content);
+                container
+            }
+        })
+        .build()
+        .unwrap();
+
+    assert_eq!(split_view.pane_at_index(0), Some(container.clone()));
+    assert_eq!(container.subviews().len(), 1);
+    assert_eq!(split_view.pane_count(), 1);
+
+    destroy(app, "wrapped-content");
+}
+
+fn hidden_builds_stay_ordered_out_until_shown(app: &AppHandle) {
+    let split_view = SplitViewBuilder::<_, BasicSplitView>::new(app, "hidden-build")
+        .add_native_pane_with(NSView::new)
+        .visible(false)
+        .build()
+        .unwrap();
+    assert!(!split_view.is_visible());
+    assert_eq!(split_view.pane_count(), 2);
+
+    split_view.show();
+    assert!(split_view.is_visible());
+
+    destroy(app, "hidden-build");
+}
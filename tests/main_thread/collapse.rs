@@ -0,0 +1,120 @@
+use objc2::MainThreadMarker;
+use objc2_app_kit::NSView;
+use objc2_foundation::NSRect;
+use tauri::{AppHandle, WebviewUrl, WebviewWindowBuilder};
+use tauri_nssplitview::{
+    BasicSplitView, ManagerExt, SplitView, SplitViewSnapshot, WebviewWindowExt,
+};
+
+use crate::split_view_with_panes;
+
+tests![
+    applying_a_snapshot_restores_collapsed_panes,
+    collapse_states_past_the_last_pane_are_ignored,
+    toggled_sidebars_collapse_and_restore_their_width,
+    collapsed_panes_keep_their_header_visible,
+];
+
+fn applying_a_snapshot_restores_collapsed_panes(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let window = WebviewWindowBuilder::new(app, "collapse", WebviewUrl::App("index.html".into()))
+        .inner_size(600.0, 400.0)
+        .build()
+        .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+    split_view
+        .add_panes(vec![NSView::new(mtm), NSView::new(mtm)])
+        .unwrap();
+    split_view.set_divider_position(0, 200.0);
+
+    split_view.collapse_pane(1).unwrap();
+    assert!(split_view.is_pane_collapsed(1));
+    let snapshot = split_view.snapshot();
+    assert_eq!(snapshot.collapsed, vec![false, true, false]);
+
+    split_view.expand_pane(1).unwrap();
+    assert!(!split_view.is_pane_collapsed(1));
+    let width = split_view.pane_at_index(1).unwrap().frame().size.width;
+    assert!(width > 0.0);
+
+    split_view.apply_snapshot(&snapshot);
+    assert!(split_view.is_pane_collapsed(1));
+    assert!(!split_view.is_pane_collapsed(0));
+    assert!(!split_view.is_pane_collapsed(2));
+
+    app.remove_split_view("collapse");
+    window.destroy().unwrap();
+}
+
+fn collapse_states_past_the_last_pane_are_ignored(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let window = WebviewWindowBuilder::new(
+        app,
+        "collapse-mismatched",
+        WebviewUrl::App("index.html".into()),
+    )
+    .inner_size(600.0, 400.0)
+    .build()
+    .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+    split_view.add_pane(&NSView::new(mtm)).unwrap();
+
+    // Snapshots saved before collapse states were recorded have none
+    let snapshot: SplitViewSnapshot =
+        serde_json::from_str(r#"{"vertical":true,"dividerPositions":[300.0]}"#).unwrap();
+    assert!(snapshot.collapsed.is_empty());
+    split_view.apply_snapshot(&snapshot);
+    assert!(!split_view.is_pane_collapsed(0));
+    assert!(!split_view.is_pane_collapsed(1));
+
+    let snapshot = SplitViewSnapshot {
+        collapsed: vec![false, true, true, true],
+        ..split_view.snapshot()
+    };
+    split_view.apply_snapshot(&snapshot);
+    assert!(!split_view.is_pane_collapsed(0));
+    assert!(split_view.is_pane_collapsed(1));
+    assert_eq!(split_view.pane_count(), 2);
+
+    app.remove_split_view("collapse-mismatched");
+    window.destroy().unwrap();
+}
+
+fn toggled_sidebars_collapse_and_restore_their_width(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    let _suppression = split_view.suppress_animations();
+    split_view.set_divider_position(0, 150.0);
+
+    split_view.toggle_sidebar(0).unwrap();
+    assert!(panes[0].isHidden());
+    assert!(split_view.as_split_view().isSubviewCollapsed(&panes[0]));
+
+    split_view.toggle_sidebar(0).unwrap();
+    assert!(!panes[0].isHidden());
+    assert!((panes[0].frame().size.width - 150.0).abs() < 1.0);
+    assert!(split_view.toggle_sidebar(2).is_err());
+}
+
+fn collapsed_panes_keep_their_header_visible(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    let _suppression = split_view.suppress_animations();
+    split_view.as_split_view().setVertical(false);
+    split_view.commit_layout();
+
+    let header = BasicSplitView::make_view(MainThreadMarker::new().unwrap(), NSRect::ZERO);
+    split_view
+        .set_pane_collapse_header(0, header.clone(), 30.0)
+        .unwrap();
+    split_view.toggle_sidebar(0).unwrap();
+
+    assert!(split_view.is_pane_collapsed(0));
+    assert!(!panes[0].isHidden());
+    assert!((panes[0].frame().size.height - 30.0).abs() < 1.0);
+    let superview = unsafe { header.superview() };
+    assert_eq!(superview.as_ref(), Some(&panes[0]));
+    assert_eq!(header.frame().size.height, 30.0);
+
+    split_view.toggle_sidebar(0).unwrap();
+    assert!(!split_view.is_pane_collapsed(0));
+    assert!(panes[0].frame().size.height > 30.0);
+}
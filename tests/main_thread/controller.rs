@@ -0,0 +1,237 @@
+use std::sync::mpsc;
+
+use objc2::MainThreadMarker;
+use objc2_app_kit::NSView;
+use objc2_foundation::NSSize;
+use tauri::{AppHandle, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
+use tauri_nssplitview::{
+    ControllerSplitView, Error, ManagerExt, PaneConfig, PaneConstraints, PanePresentation,
+    SplitView, SplitViewHandle, SplitViewItemBehavior, SplitViewOrientation, WebviewWindowExt,
+};
+
+use crate::run_main_loop;
+
+tests![
+    controller_items_follow_webview_panes,
+    native_panes_get_items_that_move_with_them,
+    pane_limits_and_collapsing_go_through_the_items,
+    operations_the_controller_owns_are_unsupported,
+    first_layout_callbacks_run_once_with_sized_panes,
+];
+
+/// Convert a new 600x400 window labelled `label` to a controller split view
+fn controller_window(app: &AppHandle, label: &str) -> (WebviewWindow, SplitViewHandle<tauri::Wry>) {
+    let window = WebviewWindowBuilder::new(app, label, WebviewUrl::App("index.html".into()))
+        .inner_size(600.0, 400.0)
+        .build()
+        .unwrap();
+    let handle = window.to_split_view::<ControllerSplitView>().unwrap();
+    (window, handle)
+}
+
+fn controller(handle: &SplitViewHandle<tauri::Wry>) -> &ControllerSplitView {
+    handle
+        .as_any()
+        .downcast_ref::<ControllerSplitView>()
+        .unwrap()
+}
+
+fn controller_items_follow_webview_panes(app: &AppHandle) {
+    let window = WebviewWindowBuilder::new(app, "controller", WebviewUrl::App("index.html".into()))
+        .build()
+        .unwrap();
+    let handle = window.to_split_view::<ControllerSplitView>().unwrap();
+    let split_view = handle
+        .as_any()
+        .downcast_ref::<ControllerSplitView>()
+        .unwrap();
+
+    assert_eq!(split_view.pane_count(), 1);
+    assert_eq!(
+        split_view.item_behavior(0),
+        Some(SplitViewItemBehavior::Default)
+    );
+
+    let index = split_view
+        .add_webview_pane(WebviewUrl::App("sidebar.html".into()))
+        .unwrap();
+    assert_eq!(index, 1);
+    assert!(split_view.split_view_item(1).is_some());
+    assert_eq!(split_view.webview_panes().len(), 2);
+
+    split_view
+        .set_item_behavior(1, SplitViewItemBehavior::Sidebar)
+        .unwrap();
+    assert_eq!(
+        split_view.item_behavior(1),
+        Some(SplitViewItemBehavior::Sidebar)
+    );
+    // Replacing the item keeps the pane's view
+    assert_eq!(split_view.pane_count(), 2);
+    assert_eq!(split_view.webview_panes().len(), 2);
+
+    app.remove_split_view("controller");
+    for (_, webview) in split_view.webview_panes() {
+        webview.destroy().unwrap();
+    }
+}
+
+/// Get the view of the view controller of the item at `index`
+fn item_view(split_view: &ControllerSplitView, index: usize) -> objc2::rc::Retained<NSView> {
+    let mtm = MainThreadMarker::new().unwrap();
+    let item = split_view.split_view_item(index).unwrap();
+    item.viewController(mtm).view()
+}
+
+fn native_panes_get_items_that_move_with_them(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let (window, handle) = controller_window(app, "controller-native");
+    let split_view = controller(&handle);
+    let first = NSView::new(mtm);
+    let second = NSView::new(mtm);
+
+    assert_eq!(
+        split_view
+            .add_panes(vec![first.clone(), second.clone()])
+            .unwrap(),
+        [1, 2]
+    );
+    assert_eq!(split_view.pane_count(), 3);
+    assert_eq!(item_view(split_view, 1), first);
+
+    split_view.move_pane(2, 1).unwrap();
+    assert_eq!(split_view.pane_at_index(1), Some(second.clone()));
+    assert_eq!(item_view(split_view, 1), second);
+    assert!(matches!(
+        split_view.move_pane(1, 3),
+        Err(Error::InvalidPaneIndex(3))
+    ));
+    assert!(split_view.split_view_item(3).is_none());
+
+    app.remove_split_view("controller-native");
+    window.destroy().unwrap();
+}
+
+fn pane_limits_and_collapsing_go_through_the_items(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let (window, handle) = controller_window(app, "controller-items");
+    let split_view = controller(&handle);
+    split_view.add_pane(&NSView::new(mtm)).unwrap();
+    let item = split_view.split_view_item(1).unwrap();
+
+    split_view
+        .apply_pane_constraints(
+            1,
+            PaneConstraints {
+                min: Some(120.0),
+                max: Some(300.0),
+                collapsible: Some(true),
+                holding_priority: Some(260.0),
+                tag: Some("inspector".to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(item.minimumThickness(), 120.0);
+    assert_eq!(item.maximumThickness(), 300.0);
+    assert!(item.canCollapse());
+    assert_eq!(item.holdingPriority(), 260.0);
+    assert_eq!(split_view.pane_index_for_tag("inspector"), Some(1));
+
+    split_view.collapse_pane(1).unwrap();
+    assert!(split_view.is_pane_collapsed(1));
+    split_view.expand_pane(1).unwrap();
+    assert!(!split_view.is_pane_collapsed(1));
+
+    split_view
+        .without_animations(|split_view| split_view.toggle_sidebar(1))
+        .unwrap();
+    assert!(item.isCollapsed());
+    assert!(matches!(
+        split_view.toggle_sidebar(2),
+        Err(Error::InvalidPaneIndex(2))
+    ));
+
+    app.remove_split_view("controller-items");
+    window.destroy().unwrap();
+}
+
+fn operations_the_controller_owns_are_unsupported(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let (window, handle) = controller_window(app, "controller-unsupported");
+    let split_view = controller(&handle);
+    split_view.add_pane(&NSView::new(mtm)).unwrap();
+
+    assert!(matches!(
+        split_view.set_pane_presentation(1, PanePresentation::Overlay),
+        Err(Error::Unsupported(_))
+    ));
+    assert_eq!(
+        split_view.pane_presentation(1),
+        Some(PanePresentation::Inline)
+    );
+    assert!(matches!(
+        split_view.set_pane_collapse_header(1, NSView::new(mtm), 30.0),
+        Err(Error::Unsupported(_))
+    ));
+    assert!(matches!(
+        split_view.enable_autosave_to_path(std::env::temp_dir().join("controller.json")),
+        Err(Error::Unsupported(_))
+    ));
+    assert!(matches!(
+        split_view.split_pane_preserving_webview(
+            0,
+            SplitViewOrientation::Horizontal,
+            PaneConfig::Webview {
+                url: WebviewUrl::App("detail.html".into())
+            }
+        ),
+        Err(Error::Unsupported(_))
+    ));
+
+    // The controller's view is the split view, so margins have nowhere to go
+    split_view.set_layout_margins(10.0, 10.0, 10.0, 10.0);
+    assert_eq!(split_view.layout_margins(), (0.0, 0.0, 0.0, 0.0));
+
+    app.remove_split_view("controller-unsupported");
+    window.destroy().unwrap();
+}
+
+fn first_layout_callbacks_run_once_with_sized_panes(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let (window, handle) = controller_window(app, "controller-first-layout");
+    let split_view = controller(&handle);
+    split_view.add_pane(&NSView::new(mtm)).unwrap();
+
+    let pane_sizes = |split_view: &dyn SplitView<tauri::Wry>| -> Vec<NSSize> {
+        (0..split_view.pane_count())
+            .map(|index| split_view.pane_at_index(index).unwrap().frame().size)
+            .collect()
+    };
+    let (sender, receiver) = mpsc::channel();
+    split_view.on_first_layout(Box::new(move |split_view| {
+        sender.send(pane_sizes(split_view)).unwrap();
+    }));
+    run_main_loop(0.3);
+
+    let sizes: Vec<Vec<NSSize>> = receiver.try_iter().collect();
+    assert_eq!(sizes.len(), 1);
+    let bounds = split_view.as_split_view().bounds().size;
+    let widths: f64 = sizes[0].iter().map(|size| size.width).sum();
+    assert_eq!(widths + split_view.divider_thickness(), bounds.width);
+    assert!(sizes[0].iter().all(|size| size.height == bounds.height));
+
+    // Later layout passes don't run the callback again
+    split_view.set_divider_position(0, 200.0);
+    run_main_loop(0.1);
+    assert!(receiver.try_recv().is_err());
+
+    // Once the panes are sized, callbacks run at once
+    let (sender, receiver) = mpsc::channel();
+    split_view.on_first_layout(Box::new(move |split_view| {
+        sender.send(split_view.pane_count()).unwrap();
+    }));
+    assert_eq!(receiver.try_recv(), Ok(2));
+
+    app.remove_split_view("controller-first-layout");
+    window.destroy().unwrap();
+}
@@ -0,0 +1,180 @@
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::ClassType;
+use objc2_app_kit::{NSEvent, NSEventModifierFlags, NSEventType};
+use objc2_foundation::{NSPoint, NSString};
+use tauri::AppHandle;
+use tauri_nssplitview::{Density, DividerStyle, Error, ManagerExt, SplitView};
+
+use crate::{split_view_with_panes, window_split_view_with_panes};
+
+tests![
+    hidden_dividers_keep_their_thickness,
+    divider_style_and_color_round_trip,
+    divider_images_need_the_subclass_and_valid_image_data,
+    densities_set_their_documented_thickness,
+    opacity_and_contents_scale_reach_the_layer,
+    first_responder_acceptance_can_be_overridden,
+    arrow_key_presses_move_the_focused_divider,
+];
+
+/// A 1x1 opaque red PNG
+const PIXEL_PNG: [u8; 70] = [
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4,
+    0x89, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0xf8, 0xcf, 0xc0, 0xf0,
+    0x1f, 0x00, 0x05, 0x00, 0x01, 0xff, 0x89, 0x99, 0x3d, 0x1d, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45,
+    0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+fn hidden_dividers_keep_their_thickness(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "hidden-dividers", 2);
+    let thickness = split_view.divider_thickness();
+    assert!(split_view.dividers_visible());
+
+    split_view.set_dividers_visible(false);
+    assert!(!split_view.dividers_visible());
+    assert_eq!(split_view.divider_thickness(), thickness);
+
+    // Split views the crate didn't create always draw their dividers
+    let (plain, _) = split_view_with_panes(app, 2);
+    plain.set_dividers_visible(false);
+    assert!(plain.dividers_visible());
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn divider_style_and_color_round_trip(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "divider-style", 2);
+    for style in [
+        DividerStyle::Thick,
+        DividerStyle::PaneSplitter,
+        DividerStyle::Thin,
+    ] {
+        split_view.set_divider_style(style);
+        assert_eq!(split_view.divider_style(), style);
+    }
+
+    assert_eq!(split_view.divider_color(), None);
+    split_view.set_divider_color(0.2, 0.4, 0.6, 1.0);
+    assert_eq!(split_view.divider_color(), Some((0.2, 0.4, 0.6, 1.0)));
+
+    // The subclass draws with the overridden color
+    let red = split_view.as_split_view().dividerColor().redComponent();
+    assert!((red - 0.2).abs() < 1e-6);
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn divider_images_need_the_subclass_and_valid_image_data(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "divider-images", 2);
+
+    split_view.set_divider_image(0, &PIXEL_PNG).unwrap();
+    assert!(split_view.set_divider_image(0, b"not an image").is_err());
+    split_view.clear_divider_image(0);
+
+    let (plain, _) = split_view_with_panes(app, 2);
+    assert!(matches!(
+        plain.set_divider_image(0, &PIXEL_PNG),
+        Err(Error::Unsupported(_))
+    ));
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn densities_set_their_documented_thickness(app: &AppHandle) {
+    let (window, split_view, panes) = window_split_view_with_panes(app, "densities", 2);
+    for (density, thickness) in [
+        (Density::Compact, 1.0),
+        (Density::Regular, 4.0),
+        (Density::Spacious, 8.0),
+    ] {
+        split_view.set_density(density);
+        assert_eq!(split_view.divider_thickness(), thickness);
+
+        let widths = panes[0].frame().size.width + panes[1].frame().size.width;
+        assert_eq!(widths + thickness, 600.0);
+    }
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn opacity_and_contents_scale_reach_the_layer(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "opacity", 2);
+    split_view.set_opaque(true);
+    split_view.set_layer_contents_scale(2.0);
+
+    assert!(split_view.as_split_view().isOpaque());
+    unsafe {
+        let layer: Retained<AnyObject> = objc2::msg_send![split_view.as_split_view(), layer];
+        let layer_opaque: bool = objc2::msg_send![&*layer, isOpaque];
+        assert!(layer_opaque);
+        let scale: f64 = objc2::msg_send![&*layer, contentsScale];
+        assert_eq!(scale, 2.0);
+    }
+
+    split_view.set_opaque(false);
+    assert!(!split_view.as_split_view().isOpaque());
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn first_responder_acceptance_can_be_overridden(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "first-responder", 2);
+    let accepts = || split_view.as_split_view().acceptsFirstResponder();
+
+    // Keyboard resizable dividers need key events unless told otherwise
+    split_view.set_divider_keyboard_resizable(0, 10.0);
+    assert!(accepts());
+    split_view.set_accepts_first_responder(false);
+    assert!(!accepts());
+    split_view.set_accepts_first_responder(true);
+    assert!(accepts());
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn key_down(key_code: u16) -> Retained<NSEvent> {
+    let characters = NSString::from_str("");
+    let event: Option<Retained<NSEvent>> = unsafe {
+        objc2::msg_send![
+            NSEvent::class(),
+            keyEventWithType: NSEventType::KeyDown,
+            location: NSPoint::new(0.0, 0.0),
+            modifierFlags: NSEventModifierFlags::empty(),
+            timestamp: 0.0f64,
+            windowNumber: 0isize,
+            context: Option::<&AnyObject>::None,
+            characters: &*characters,
+            charactersIgnoringModifiers: &*characters,
+            isARepeat: false,
+            keyCode: key_code
+        ]
+    };
+    event.unwrap()
+}
+
+fn arrow_key_presses_move_the_focused_divider(app: &AppHandle) {
+    const RIGHT_ARROW_KEY: u16 = 124;
+
+    let (window, split_view, _) = window_split_view_with_panes(app, "arrow-keys", 2);
+    split_view.set_divider_position(0, 200.0);
+    split_view.set_divider_keyboard_resizable(0, 10.0);
+
+    // Becoming first responder focuses the first keyboard resizable divider
+    let ns_window = split_view.window().unwrap();
+    assert!(ns_window.makeFirstResponder(Some(split_view.as_split_view().as_ref())));
+    split_view
+        .as_split_view()
+        .keyDown(&key_down(RIGHT_ARROW_KEY));
+    assert_eq!(split_view.get_divider_position(0), 210.0);
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
@@ -0,0 +1,203 @@
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::MainThreadMarker;
+use objc2_foundation::{NSPoint, NSRect, NSSize};
+use tauri::AppHandle;
+use tauri_nssplitview::{BasicSplitView, DividerPosition, DividerStyle, SplitView};
+
+use crate::split_view_with_panes;
+
+tests![
+    fraction_divider_positions_match_divider_info,
+    divider_drag_ranges_span_the_neighbouring_panes,
+    divider_drag_range_respects_pane_size_limits,
+    aspect_bounds_limit_the_divider_drag_range,
+    dividers_snap_to_nearby_positions,
+    pixel_aligned_panes_get_whole_pixel_sizes,
+    cursor_region_padding_widens_the_divider_hit_area,
+    divider_moves_return_the_clamped_position,
+    right_to_left_fractions_start_at_the_right_edge,
+    content_extents_add_pane_limits_and_dividers,
+    system_divider_thickness_follows_the_style,
+];
+
+/// Ask the split view's delegate where a divider dragged to `position` should go
+fn constrain_split_position(
+    split_view: &BasicSplitView,
+    divider_index: usize,
+    position: f64,
+) -> f64 {
+    unsafe {
+        let delegate: Retained<AnyObject> = objc2::msg_send![split_view.as_split_view(), delegate];
+        objc2::msg_send![
+            &*delegate,
+            splitView: split_view.as_split_view(),
+            constrainSplitPosition: position,
+            ofSubviewAt: divider_index as isize
+        ]
+    }
+}
+
+fn fraction_divider_positions_match_divider_info(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    split_view.set_divider(0, DividerPosition::Fraction(0.25));
+    let info = split_view.divider_info();
+    assert!((info[0].fraction - 0.25).abs() < 0.01);
+}
+
+fn divider_drag_ranges_span_the_neighbouring_panes(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 3);
+    let thickness = split_view.divider_thickness();
+    let (leading, trailing) = (panes[1].frame(), panes[2].frame());
+
+    assert_eq!(
+        split_view.divider_drag_range(1),
+        Some((
+            leading.origin.x,
+            trailing.origin.x + trailing.size.width - thickness
+        ))
+    );
+    assert_eq!(split_view.divider_drag_range(2), None);
+}
+
+fn divider_drag_range_respects_pane_size_limits(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    let (start, end) = split_view.divider_drag_range(0).unwrap();
+
+    split_view.set_pane_min_size(0, 100.0);
+    split_view.set_pane_max_size(0, 300.0);
+    split_view.set_pane_min_size(1, 150.0);
+    assert_eq!(
+        split_view.divider_drag_range(0),
+        Some((start + 100.0, (start + 300.0).min(end - 150.0)))
+    );
+
+    split_view.set_pane_max_size(0, 1000.0);
+    assert_eq!(
+        split_view.divider_drag_range(0),
+        Some((start + 100.0, end - 150.0))
+    );
+}
+
+fn aspect_bounds_limit_the_divider_drag_range(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    let (start, _) = split_view.divider_drag_range(0).unwrap();
+
+    // The panes are 400 points tall
+    split_view.set_pane_min_aspect(0, 0.5);
+    split_view.set_pane_max_aspect(0, 0.75);
+    assert_eq!(
+        split_view.divider_drag_range(0),
+        Some((start + 200.0, start + 300.0))
+    );
+
+    // Equal bounds pin the pane to a single ratio
+    split_view.set_pane_min_aspect(0, 0.6);
+    split_view.set_pane_max_aspect(0, 0.6);
+    assert_eq!(
+        split_view.divider_drag_range(0),
+        Some((start + 240.0, start + 240.0))
+    );
+}
+
+fn dividers_snap_to_nearby_positions(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    split_view.set_divider_snap_positions(0, vec![100.0, 300.0], 10.0);
+
+    assert_eq!(constrain_split_position(&split_view, 0, 95.0), 100.0);
+    assert_eq!(constrain_split_position(&split_view, 0, 305.0), 300.0);
+    assert_eq!(constrain_split_position(&split_view, 0, 150.0), 150.0);
+
+    split_view.set_divider_snap_positions(0, Vec::new(), 10.0);
+    assert_eq!(constrain_split_position(&split_view, 0, 95.0), 95.0);
+}
+
+fn pixel_aligned_panes_get_whole_pixel_sizes(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    split_view.set_pane_pixel_aligned(0, false);
+    assert_eq!(constrain_split_position(&split_view, 0, 150.4), 150.4);
+
+    // Outside a window there is one pixel per point
+    split_view.set_pane_pixel_aligned(1, true);
+    let trailing = panes[1].frame();
+    let end = trailing.origin.x + trailing.size.width;
+    let thickness = split_view.divider_thickness();
+    let position = constrain_split_position(&split_view, 0, 150.4);
+    let extent = end - thickness - position;
+    assert!((extent - extent.round()).abs() < 1e-9, "{}", extent);
+
+    // The leading pane wins when both are aligned
+    split_view.set_pane_pixel_aligned(0, true);
+    assert_eq!(constrain_split_position(&split_view, 0, 150.4), 150.0);
+}
+
+fn cursor_region_padding_widens_the_divider_hit_area(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    split_view.set_cursor_region_padding(3.0);
+
+    let drawn = NSRect::new(NSPoint::new(299.0, 0.0), NSSize::new(1.0, 400.0));
+    let effective: NSRect = unsafe {
+        let delegate: Retained<AnyObject> = objc2::msg_send![split_view.as_split_view(), delegate];
+        objc2::msg_send![
+            &*delegate,
+            splitView: split_view.as_split_view(),
+            effectiveRect: drawn,
+            forDrawnRect: drawn,
+            ofDividerAtIndex: 0isize
+        ]
+    };
+    assert_eq!(
+        effective,
+        NSRect::new(NSPoint::new(296.0, 0.0), NSSize::new(7.0, 400.0))
+    );
+}
+
+fn divider_moves_return_the_clamped_position(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    assert_eq!(split_view.set_divider_position(0, 250.0), 250.0);
+
+    // The minimum size keeps the divider from reaching the requested position
+    split_view.set_pane_min_size(0, 100.0);
+    assert_eq!(split_view.set_divider_position(0, 50.0), 100.0);
+
+    // Without a divider at the index there is nothing to move
+    assert_eq!(split_view.set_divider_position(1, 300.0), 0.0);
+}
+
+fn right_to_left_fractions_start_at_the_right_edge(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    split_view.set_user_interface_layout_direction(true);
+    assert!(panes[0].frame().origin.x > panes[1].frame().origin.x);
+
+    // Pane 0 leads from the right edge
+    split_view.set_divider(0, DividerPosition::Fraction(0.25));
+    let leading = panes[0].frame();
+    assert!((leading.origin.x - 450.0).abs() < 1.0);
+    assert!((leading.size.width - 150.0).abs() < 1.0);
+    assert!((split_view.divider_info()[0].fraction - 0.25).abs() < 0.01);
+}
+
+fn content_extents_add_pane_limits_and_dividers(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    let thickness = split_view.divider_thickness();
+    assert_eq!(split_view.minimum_content_extent(), thickness);
+    assert_eq!(split_view.maximum_content_extent(), None);
+
+    split_view.set_pane_min_size(0, 100.0);
+    split_view.set_pane_min_size(1, 150.0);
+    split_view.set_pane_max_size(0, 300.0);
+    assert_eq!(split_view.minimum_content_extent(), 250.0 + thickness);
+
+    // Any pane without a maximum leaves the content unbounded
+    assert_eq!(split_view.maximum_content_extent(), None);
+    split_view.set_pane_max_size(1, 400.0);
+    assert_eq!(split_view.maximum_content_extent(), Some(700.0 + thickness));
+}
+
+fn system_divider_thickness_follows_the_style(_app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let thin = BasicSplitView::system_divider_thickness(DividerStyle::Thin, mtm);
+    let thick = BasicSplitView::system_divider_thickness(DividerStyle::Thick, mtm);
+    assert!(thin > 0.0);
+    assert!(thick > thin);
+}
@@ -0,0 +1,191 @@
+use std::sync::mpsc;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::MainThreadMarker;
+use objc2_app_kit::NSView;
+use objc2_foundation::{NSPoint, NSRect, NSSize, NSString};
+use tauri::AppHandle;
+use tauri_nssplitview::{
+    BasicSplitView, DividerPosition, ManagerExt, ResizeMode, SplitView, SplitViewEvent,
+    SplitViewOrientation,
+};
+
+use crate::{run_main_loop, split_view_with_panes, window_split_view_with_panes};
+
+tests![
+    proportional_mode_keeps_pane_fractions_on_resize,
+    locked_layouts_return_to_their_fractions_on_resize,
+    auto_equalized_panes_stay_equal_on_resize,
+    orientation_changes_keep_divider_fractions,
+    animated_orientation_changes_restore_the_layer_setting,
+    layout_margins_inset_the_split_view_in_a_container,
+    animations_resume_once_every_suppression_ends,
+    first_layout_callbacks_run_at_once_after_layout,
+];
+
+/// The leading pane's share of the width of the first two panes
+fn leading_fraction(panes: &[Retained<NSView>]) -> f64 {
+    let widths: Vec<f64> = panes.iter().map(|pane| pane.frame().size.width).collect();
+    widths[0] / (widths[0] + widths[1])
+}
+
+fn proportional_mode_keeps_pane_fractions_on_resize(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    split_view.set_resize_mode(ResizeMode::Proportional);
+    split_view.set_divider_position(0, 150.0);
+    let before = leading_fraction(&panes);
+
+    split_view
+        .as_split_view()
+        .setFrameSize(NSSize::new(1200.0, 400.0));
+    assert!((leading_fraction(&panes) - before).abs() < 1e-9);
+    assert!(panes[1].frame().size.width > 450.0);
+}
+
+fn locked_layouts_return_to_their_fractions_on_resize(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    split_view.set_divider_position(0, 150.0);
+    let locked = leading_fraction(&panes);
+    split_view.set_layout_locked(true);
+
+    split_view.set_divider_position(0, 400.0);
+    split_view
+        .as_split_view()
+        .setFrameSize(NSSize::new(1200.0, 400.0));
+    assert!((leading_fraction(&panes) - locked).abs() < 1e-3);
+
+    // Unlocked layouts keep the divider where it's moved
+    split_view.set_layout_locked(false);
+    split_view.set_divider_position(0, 900.0);
+    split_view
+        .as_split_view()
+        .setFrameSize(NSSize::new(1300.0, 400.0));
+    assert!((leading_fraction(&panes) - locked).abs() > 0.1);
+}
+
+fn auto_equalized_panes_stay_equal_on_resize(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 3);
+    let equal = || {
+        let widths: Vec<f64> = panes.iter().map(|pane| pane.frame().size.width).collect();
+        widths.iter().all(|width| (width - widths[0]).abs() <= 1.0)
+    };
+    split_view.set_divider_position(0, 100.0);
+    assert!(!equal());
+
+    split_view.set_auto_equalize(true);
+    assert!(equal());
+    split_view.set_divider_position(0, 100.0);
+    split_view
+        .as_split_view()
+        .setFrameSize(NSSize::new(900.0, 400.0));
+    assert!(equal());
+}
+
+fn orientation_changes_keep_divider_fractions(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    split_view.set_divider(0, DividerPosition::Fraction(0.25));
+    let events = split_view.events();
+
+    split_view.set_orientation_animated(SplitViewOrientation::Horizontal, 0.0);
+
+    assert!(!split_view.is_vertical());
+    assert!((split_view.divider_info()[0].fraction - 0.25).abs() < 0.01);
+    assert!(events
+        .try_iter()
+        .any(|event| event == SplitViewEvent::OrientationChanged { vertical: false }));
+}
+
+fn animated_orientation_changes_restore_the_layer_setting(app: &AppHandle) {
+    let (window, split_view, panes) = window_split_view_with_panes(app, "orientation", 2);
+    let ns_split_view = split_view.as_split_view();
+    split_view.set_divider(0, DividerPosition::Fraction(0.25));
+    assert!(!ns_split_view.wantsLayer());
+
+    split_view.set_orientation_animated(SplitViewOrientation::Horizontal, 0.2);
+    assert!(!split_view.is_vertical());
+    assert!(ns_split_view.wantsLayer());
+
+    // Once the animation is over the panes are stacked at the same fraction
+    run_main_loop(0.5);
+    assert!(!ns_split_view.wantsLayer());
+    let heights = panes[0].frame().size.height + panes[1].frame().size.height;
+    assert_eq!(heights + split_view.divider_thickness(), 400.0);
+    assert!(panes
+        .iter()
+        .all(|pane| pane.frame().size.width == ns_split_view.frame().size.width));
+    assert!((split_view.divider_info()[0].fraction - 0.25).abs() < 0.01);
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn layout_margins_inset_the_split_view_in_a_container(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    let ns_split_view = split_view.as_split_view();
+
+    // Without a superview there is nowhere to put the container
+    split_view.set_layout_margins(10.0, 20.0, 30.0, 40.0);
+    assert_eq!(split_view.layout_margins(), (0.0, 0.0, 0.0, 0.0));
+
+    let frame = ns_split_view.frame();
+    let parent = BasicSplitView::make_view(MainThreadMarker::new().unwrap(), frame);
+    parent.addSubview(ns_split_view);
+    split_view.set_layout_margins(10.0, 20.0, 30.0, 40.0);
+    assert_eq!(split_view.layout_margins(), (10.0, 20.0, 30.0, 40.0));
+
+    let container = unsafe { ns_split_view.superview() }.unwrap();
+    assert_eq!(parent.subviews().to_vec(), [container.clone()]);
+    assert_eq!(container.frame(), frame);
+
+    // The container isn't flipped, so the bottom margin sets the origin
+    assert_eq!(
+        ns_split_view.frame(),
+        NSRect::new(NSPoint::new(20.0, 30.0), NSSize::new(540.0, 360.0))
+    );
+}
+
+fn animations_resume_once_every_suppression_ends(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 1);
+    panes[0].setWantsLayer(true);
+
+    // Pane zooms only add their animation while animations are enabled
+    let animates = || -> bool {
+        let key = NSString::from_str("tauriPaneZoom");
+        unsafe {
+            let layer: Retained<AnyObject> = objc2::msg_send![&*panes[0], layer];
+            let _: () = objc2::msg_send![&*layer, removeAllAnimations];
+            split_view.animate_pane_zoom(0, 0.9, 1.0, 0.2).unwrap();
+            let animation: Option<Retained<AnyObject>> =
+                objc2::msg_send![&*layer, animationForKey: &*key];
+            animation.is_some()
+        }
+    };
+
+    let outer = split_view.suppress_animations();
+    let inner = split_view.suppress_animations();
+    drop(outer);
+    assert!(!animates());
+    drop(inner);
+    assert!(animates());
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        split_view.without_animations(|_| panic!("restore failed"))
+    }));
+    assert!(result.is_err());
+    assert!(animates());
+}
+
+fn first_layout_callbacks_run_at_once_after_layout(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "first-layout", 2);
+    run_main_loop(0.2);
+
+    let (sender, receiver) = mpsc::channel();
+    split_view.on_first_layout(Box::new(move |split_view| {
+        sender.send(split_view.pane_count()).unwrap();
+    }));
+    assert_eq!(receiver.try_recv(), Ok(2));
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
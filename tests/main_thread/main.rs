@@ -0,0 +1,233 @@
+//! Tests that need AppKit's main thread
+//!
+//! libtest runs each test on a thread of its own, where windows and views can't be
+//! used, so this binary is built without the harness and runs its tests one after
+//! another on the main thread. Arguments filter the tests by name like libtest does.
+
+#[cfg(target_os = "macos")]
+use tauri::AppHandle;
+
+/// A test run on the main thread with the shared app
+#[cfg(target_os = "macos")]
+pub struct Test {
+    pub name: &'static str,
+    pub run: fn(&AppHandle),
+}
+
+/// Declare the tests of a module as its `TESTS`
+#[cfg(target_os = "macos")]
+macro_rules! tests {
+    ($($test:ident),* $(,)?) => {
+        pub const TESTS: &[$crate::Test] = &[$($crate::Test {
+            name: concat!(module_path!(), "::", stringify!($test)),
+            run: $test,
+        }),*];
+    };
+}
+
+/// Run the main run loop for `secs`, so work deferred to a later turn happens
+#[cfg(target_os = "macos")]
+pub fn run_main_loop(secs: f64) {
+    use objc2::rc::Retained;
+    use objc2::runtime::AnyObject;
+
+    unsafe {
+        let date: Retained<AnyObject> =
+            objc2::msg_send![objc2::class!(NSDate), dateWithTimeIntervalSinceNow: secs];
+        let run_loop: Retained<AnyObject> =
+            objc2::msg_send![objc2::class!(NSRunLoop), currentRunLoop];
+        let _: () = objc2::msg_send![&*run_loop, runUntilDate: &*date];
+    }
+}
+
+/// Build a split view holding `count` plain 600x400 panes side by side, outside of any window
+#[cfg(target_os = "macos")]
+pub fn split_view_with_panes(
+    app: &AppHandle,
+    count: usize,
+) -> (
+    tauri_nssplitview::BasicSplitView,
+    Vec<objc2::rc::Retained<objc2_app_kit::NSView>>,
+) {
+    use objc2::rc::Retained;
+    use objc2::{MainThreadMarker, MainThreadOnly};
+    use objc2_app_kit::NSSplitView;
+    use objc2_foundation::{NSPoint, NSRect, NSSize};
+    use tauri_nssplitview::{BasicSplitView, SplitView};
+
+    let mtm = MainThreadMarker::new().unwrap();
+    let frame = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(600.0, 400.0));
+    let ns_split_view: Retained<NSSplitView> =
+        unsafe { objc2::msg_send![NSSplitView::alloc(mtm), initWithFrame: frame] };
+    ns_split_view.setVertical(true);
+
+    let split_view = BasicSplitView::new(ns_split_view, "test".into(), app.clone());
+    let panes = (0..count)
+        .map(|_| {
+            let pane = BasicSplitView::make_view(mtm, frame);
+            split_view.add_pane(&pane).unwrap();
+            pane
+        })
+        .collect();
+    (split_view, panes)
+}
+
+/// Build a split view in a 600x400 window of its own, backed by the crate's subclass
+///
+/// The window's webview is pane 0, followed by `count - 1` plain panes. Callers remove
+/// the split view and destroy the window when they're done.
+#[cfg(target_os = "macos")]
+pub fn window_split_view_with_panes(
+    app: &AppHandle,
+    label: &str,
+    count: usize,
+) -> (
+    tauri::WebviewWindow,
+    tauri_nssplitview::SplitViewHandle<tauri::Wry>,
+    Vec<objc2::rc::Retained<objc2_app_kit::NSView>>,
+) {
+    use tauri::{WebviewUrl, WebviewWindowBuilder};
+    use tauri_nssplitview::{BasicSplitView, WebviewWindowExt};
+
+    let mtm = objc2::MainThreadMarker::new().unwrap();
+    let window = WebviewWindowBuilder::new(app, label, WebviewUrl::App("index.html".into()))
+        .inner_size(600.0, 400.0)
+        .build()
+        .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+    let extra = (1..count)
+        .map(|_| objc2_app_kit::NSView::new(mtm))
+        .collect::<Vec<_>>();
+    split_view.add_panes(extra).unwrap();
+    let panes = (0..count)
+        .map(|index| split_view.pane_at_index(index).unwrap())
+        .collect();
+    (window, split_view, panes)
+}
+
+#[cfg(target_os = "macos")]
+mod adding_panes;
+#[cfg(target_os = "macos")]
+mod autosave;
+#[cfg(target_os = "macos")]
+mod builder;
+#[cfg(target_os = "macos")]
+mod collapse;
+#[cfg(target_os = "macos")]
+mod controller;
+#[cfg(target_os = "macos")]
+mod divider_drawing;
+#[cfg(target_os = "macos")]
+mod dividers;
+#[cfg(target_os = "macos")]
+mod layout;
+#[cfg(target_os = "macos")]
+mod main_thread_bound;
+#[cfg(target_os = "macos")]
+mod main_thread_checks;
+#[cfg(target_os = "macos")]
+mod manager;
+#[cfg(target_os = "macos")]
+mod pane_appearance;
+#[cfg(target_os = "macos")]
+mod pane_callbacks;
+#[cfg(target_os = "macos")]
+mod pane_changes;
+#[cfg(target_os = "macos")]
+mod pane_constraints;
+#[cfg(target_os = "macos")]
+mod pane_order;
+#[cfg(target_os = "macos")]
+mod pane_tags;
+#[cfg(target_os = "macos")]
+mod split_panes;
+#[cfg(all(target_os = "macos", feature = "tracing"))]
+mod tracing_events;
+#[cfg(target_os = "macos")]
+mod tracking_areas;
+#[cfg(target_os = "macos")]
+mod webview_panes;
+#[cfg(target_os = "macos")]
+mod window;
+
+#[cfg(target_os = "macos")]
+fn main() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use tauri::test::{mock_context, noop_assets};
+
+    assert!(
+        objc2::MainThreadMarker::new().is_some(),
+        "main thread tests must run on the main thread"
+    );
+
+    let app = tauri::Builder::default()
+        .plugin(tauri_nssplitview::init())
+        .build(mock_context(noop_assets()))
+        .expect("failed to build the test app");
+
+    let filters: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .collect();
+    let selected = |test: &&Test| {
+        filters.is_empty() || filters.iter().any(|filter| test.name.contains(&**filter))
+    };
+    #[allow(unused_mut)]
+    let mut modules: Vec<&[Test]> = vec![
+        adding_panes::TESTS,
+        autosave::TESTS,
+        builder::TESTS,
+        collapse::TESTS,
+        controller::TESTS,
+        divider_drawing::TESTS,
+        dividers::TESTS,
+        layout::TESTS,
+        main_thread_bound::TESTS,
+        main_thread_checks::TESTS,
+        manager::TESTS,
+        pane_appearance::TESTS,
+        pane_callbacks::TESTS,
+        pane_changes::TESTS,
+        pane_constraints::TESTS,
+        pane_order::TESTS,
+        pane_tags::TESTS,
+        split_panes::TESTS,
+        tracking_areas::TESTS,
+        webview_panes::TESTS,
+        window::TESTS,
+    ];
+    #[cfg(feature = "tracing")]
+    modules.push(tracing_events::TESTS);
+    let tests = modules
+        .iter()
+        .flat_map(|tests| tests.iter())
+        .filter(selected);
+
+    let mut passed = 0;
+    let mut failed = Vec::new();
+    for test in tests {
+        match catch_unwind(AssertUnwindSafe(|| (test.run)(app.handle()))) {
+            Ok(()) => {
+                println!("test {} ... ok", test.name);
+                passed += 1;
+            }
+            Err(_) => {
+                println!("test {} ... FAILED", test.name);
+                failed.push(test.name);
+            }
+        }
+    }
+
+    println!();
+    for name in &failed {
+        println!("failed: {}", name);
+    }
+    println!("{} passed; {} failed", passed, failed.len());
+    if !failed.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn main() {}
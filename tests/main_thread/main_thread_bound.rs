@@ -0,0 +1,44 @@
+use std::rc::Rc;
+use std::thread;
+
+use objc2::MainThreadMarker;
+use tauri::AppHandle;
+use tauri_nssplitview::MainThreadBound;
+
+tests![
+    bound_values_cross_threads_but_are_claimed_on_the_main_thread,
+    bound_values_dropped_off_the_main_thread_panic_and_leak,
+];
+
+fn bound_values_cross_threads_but_are_claimed_on_the_main_thread(_app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let value = Rc::new(7);
+    let bound = MainThreadBound::new(value.clone(), mtm);
+
+    // Moving the wrapper leaves the value untouched
+    let bound = thread::spawn(move || bound).join().unwrap();
+    assert_eq!(**bound.claim(mtm), 7);
+    drop(bound);
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let bound = MainThreadBound::new(value.clone(), mtm);
+    assert!(Rc::ptr_eq(&bound.into_inner(mtm), &value));
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+fn bound_values_dropped_off_the_main_thread_panic_and_leak(_app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let value = Rc::new(7);
+    let bound = MainThreadBound::new(value.clone(), mtm);
+
+    let result = thread::spawn(move || drop(bound)).join();
+    let message = *result.unwrap_err().downcast::<&str>().unwrap();
+    assert!(
+        message.contains("dropped on the main thread"),
+        "{}",
+        message
+    );
+
+    // The clone was leaked rather than dropped on the other thread
+    assert_eq!(Rc::strong_count(&value), 2);
+}
@@ -0,0 +1,66 @@
+use std::any::Any;
+use std::thread;
+
+use tauri::{AppHandle, WebviewUrl, WebviewWindowBuilder};
+use tauri_nssplitview::{
+    set_main_thread_checks, ControllerSplitView, ManagerExt, SplitView, WebviewWindowExt,
+};
+
+use crate::split_view_with_panes;
+
+tests![
+    split_view_calls_off_the_main_thread_panic_with_their_name,
+    controller_calls_off_the_main_thread_panic_with_their_name,
+];
+
+/// Get the message of the panic that ended a thread
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    *payload.downcast::<String>().unwrap()
+}
+
+fn split_view_calls_off_the_main_thread_panic_with_their_name(app: &AppHandle) {
+    set_main_thread_checks(true);
+    let (split_view, _) = split_view_with_panes(app, 2);
+
+    thread::scope(|scope| {
+        let query = scope.spawn(|| split_view.pane_count()).join();
+        let message = panic_message(query.unwrap_err());
+        assert!(message.contains("`pane_count`"), "{}", message);
+
+        let change = scope
+            .spawn(|| split_view.set_divider_position(0, 150.0))
+            .join();
+        let message = panic_message(change.unwrap_err());
+        assert!(message.contains("`set_divider_position`"), "{}", message);
+    });
+    assert_eq!(split_view.pane_count(), 2);
+}
+
+fn controller_calls_off_the_main_thread_panic_with_their_name(app: &AppHandle) {
+    set_main_thread_checks(true);
+    let window = WebviewWindowBuilder::new(
+        app,
+        "controller-checks",
+        WebviewUrl::App("index.html".into()),
+    )
+    .build()
+    .unwrap();
+    let handle = window.to_split_view::<ControllerSplitView>().unwrap();
+    let split_view = handle
+        .as_any()
+        .downcast_ref::<ControllerSplitView>()
+        .unwrap();
+
+    thread::scope(|scope| {
+        let item = scope.spawn(|| split_view.split_view_item(0)).join();
+        let message = panic_message(item.unwrap_err());
+        assert!(message.contains("`split_view_item`"), "{}", message);
+
+        let collapsed = scope.spawn(|| split_view.is_pane_collapsed(0)).join();
+        let message = panic_message(collapsed.unwrap_err());
+        assert!(message.contains("`is_pane_collapsed`"), "{}", message);
+    });
+
+    app.remove_split_view("controller-checks");
+    window.destroy().unwrap();
+}
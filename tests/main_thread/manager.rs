@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use objc2::rc::Retained;
+use objc2::MainThreadMarker;
+use objc2_foundation::{NSPoint, NSRect, NSSize, NSString};
+use tauri::{AppHandle, Manager};
+use tauri_nssplitview::{
+    BasicSplitView, Error, ManagerExt, PaneKind, SplitViewNode, SplitViewOrientation,
+};
+
+use crate::window_split_view_with_panes;
+
+tests![
+    region_split_views_nest_in_their_pane_and_register,
+    split_view_trees_follow_the_view_hierarchy,
+    weak_handles_do_not_keep_removed_split_views_alive,
+    aliases_share_the_handle_until_every_key_is_removed,
+    raw_split_view_closures_see_the_wrapped_view,
+    user_data_downcasts_to_what_was_stored,
+    duplicates_recreate_the_panes_and_orientation,
+    existing_split_views_are_adopted_rather_than_wrapped_again,
+];
+
+fn region_split_views_nest_in_their_pane_and_register(app: &AppHandle) {
+    let (window, split_view, panes) = window_split_view_with_panes(app, "region", 2);
+    let region = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(200.0, 100.0));
+    let nested = split_view
+        .add_region_split_view(1, "region-nested", region, SplitViewOrientation::Horizontal)
+        .unwrap();
+
+    assert!(!nested.is_vertical());
+    let superview = unsafe { nested.as_split_view().superview() };
+    assert_eq!(superview.as_ref(), Some(&panes[1]));
+
+    let registered = app.get_split_view("region-nested").unwrap();
+    assert!(std::ptr::eq(
+        registered.as_split_view(),
+        nested.as_split_view()
+    ));
+    assert!(matches!(
+        split_view.add_region_split_view(2, "other", region, SplitViewOrientation::Vertical),
+        Err(Error::InvalidPaneIndex(2))
+    ));
+
+    app.remove_split_view("region-nested");
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn split_view_trees_follow_the_view_hierarchy(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "tree", 3);
+    let region = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(100.0, 100.0));
+    let vertical = SplitViewOrientation::Vertical;
+    split_view
+        .add_region_split_view(2, "tree-b", region, vertical)
+        .unwrap();
+    let nested = split_view
+        .add_region_split_view(1, "tree-a", region, vertical)
+        .unwrap();
+    let pane = BasicSplitView::make_view(MainThreadMarker::new().unwrap(), region);
+    nested.add_pane(&pane).unwrap();
+    nested
+        .add_region_split_view(0, "tree-a-inner", region, vertical)
+        .unwrap();
+
+    let node = |label: &str, pane_count, children| SplitViewNode {
+        label: label.to_string(),
+        pane_count,
+        children,
+    };
+    assert_eq!(
+        app.split_view_tree("tree"),
+        Some(node(
+            "tree",
+            3,
+            vec![
+                node("tree-a", 1, vec![node("tree-a-inner", 0, vec![])]),
+                node("tree-b", 0, vec![]),
+            ]
+        ))
+    );
+    assert_eq!(app.split_view_tree("missing"), None);
+
+    for label in ["tree-a-inner", "tree-a", "tree-b", "tree"] {
+        app.remove_split_view(label);
+    }
+    window.destroy().unwrap();
+}
+
+fn weak_handles_do_not_keep_removed_split_views_alive(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "weak", 1);
+    drop(split_view);
+
+    let weak = app.get_split_view_weak("weak").unwrap();
+    assert!(weak.upgrade().is_some());
+    assert!(app.get_split_view_weak("missing").is_none());
+
+    app.remove_split_view("weak").unwrap();
+    assert!(weak.upgrade().is_none());
+    window.destroy().unwrap();
+}
+
+fn aliases_share_the_handle_until_every_key_is_removed(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "aliased", 1);
+    drop(split_view);
+    app.alias_split_view("aliased", "editor").unwrap();
+
+    let by_label = app.get_split_view("aliased").unwrap();
+    let by_alias = app.get_split_view("editor").unwrap();
+    assert!(Arc::ptr_eq(&by_label, &by_alias));
+    assert!(matches!(
+        app.alias_split_view("missing", "other"),
+        Err(Error::SplitViewNotFound)
+    ));
+
+    let weak = Arc::downgrade(&by_label);
+    drop((by_label, by_alias));
+    app.remove_split_view("aliased").unwrap();
+    assert!(weak.upgrade().is_some());
+    app.remove_split_view("editor").unwrap();
+    assert!(weak.upgrade().is_none());
+    window.destroy().unwrap();
+}
+
+fn raw_split_view_closures_see_the_wrapped_view(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "raw", 2);
+    split_view.set_divider_thickness(6.0);
+
+    let mtm = MainThreadMarker::new().unwrap();
+    let thickness = split_view.with_raw_split_view(mtm, |raw| raw.dividerThickness());
+    assert_eq!(thickness, 6.0);
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn user_data_downcasts_to_what_was_stored(app: &AppHandle) {
+    #[derive(Debug, PartialEq)]
+    struct Document {
+        id: u32,
+    }
+
+    let (window, split_view, _) = window_split_view_with_panes(app, "user-data", 1);
+    assert!(split_view.user_data().is_none());
+
+    split_view.set_user_data(Box::new(Document { id: 7 }));
+    let data = split_view.user_data().unwrap();
+    assert_eq!(data.downcast_ref::<Document>(), Some(&Document { id: 7 }));
+    assert!(data.downcast_ref::<String>().is_none());
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn duplicates_recreate_the_panes_and_orientation(app: &AppHandle) {
+    let (window, split_view, panes) = window_split_view_with_panes(app, "original", 2);
+    split_view.set_orientation_animated(SplitViewOrientation::Horizontal, 0.0);
+    let identifier = NSString::from_str("inspector");
+    unsafe {
+        let _: () = objc2::msg_send![&*panes[1], setIdentifier: &*identifier];
+    }
+
+    let copy = app.duplicate_split_view("original", "copy").unwrap();
+    assert_eq!(copy.label(), "copy");
+    assert!(!copy.is_vertical());
+    assert_eq!(copy.pane_count(), 2);
+    assert_eq!(copy.pane_kind(0), Some(PaneKind::Webview));
+
+    // The native pane is a new view with the same identifier
+    let pane = copy.pane_at_index(1).unwrap();
+    assert_ne!(pane, panes[1]);
+    let copied: Option<Retained<NSString>> = unsafe { objc2::msg_send![&*pane, identifier] };
+    assert_eq!(copied.as_deref(), Some(&*identifier));
+
+    app.remove_split_view("copy");
+    app.get_webview_window("copy").unwrap().destroy().unwrap();
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn existing_split_views_are_adopted_rather_than_wrapped_again(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "adopted", 2);
+    let ns_split_view = split_view.as_split_view().retain();
+
+    // A hot reload resets the manager while the window keeps showing the split view
+    app.remove_split_view("adopted");
+    drop(split_view);
+
+    let adopted = app.adopt_existing_split_view("adopted").unwrap();
+    assert!(std::ptr::eq(adopted.as_split_view(), &*ns_split_view));
+    assert_eq!(adopted.pane_count(), 2);
+    let registered = app.get_split_view("adopted").unwrap();
+    assert!(Arc::ptr_eq(&registered, &adopted));
+
+    app.remove_split_view("adopted");
+    window.destroy().unwrap();
+}
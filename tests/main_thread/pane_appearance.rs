@@ -0,0 +1,175 @@
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::MainThreadMarker;
+use objc2_app_kit::{NSImageView, NSView};
+use objc2_foundation::{NSPoint, NSRect, NSSize, NSString};
+use tauri::AppHandle;
+use tauri_nssplitview::{BasicSplitView, Edge, Error, SplitView};
+
+use crate::{run_main_loop, split_view_with_panes};
+
+tests![
+    pane_zoom_animates_layer_backed_panes,
+    pane_background_colors_round_trip,
+    pane_clipping_masks_the_layer,
+    pane_tooltips_round_trip_and_clear,
+    frozen_panes_are_covered_by_a_snapshot,
+    mirror_panes_refresh_from_their_source,
+    edge_accessories_take_a_strip_from_the_pane_content,
+    panes_outside_a_window_have_no_backing_scale,
+];
+
+fn pane_zoom_animates_layer_backed_panes(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    assert!(matches!(
+        split_view.animate_pane_zoom(0, 0.9, 1.0, 0.2),
+        Err(Error::PaneNotLayerBacked(0))
+    ));
+
+    panes[0].setWantsLayer(true);
+    let zoom_animation = || -> Option<Retained<AnyObject>> {
+        let key = NSString::from_str("tauriPaneZoom");
+        unsafe {
+            let layer: Retained<AnyObject> = objc2::msg_send![&*panes[0], layer];
+            objc2::msg_send![&*layer, animationForKey: &*key]
+        }
+    };
+
+    // Suppressed animations leave the pane at its identity transform
+    split_view
+        .without_animations(|split_view| split_view.animate_pane_zoom(0, 0.9, 1.0, 0.2))
+        .unwrap();
+    assert!(zoom_animation().is_none());
+
+    split_view.animate_pane_zoom(0, 0.9, 1.0, 0.2).unwrap();
+    assert!(zoom_animation().is_some());
+}
+
+fn pane_background_colors_round_trip(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    assert_eq!(split_view.pane_background_color(0), None);
+
+    split_view
+        .set_pane_background_color(1, 0.1, 0.3, 0.5, 0.7)
+        .unwrap();
+    let (red, green, blue, alpha) = split_view.pane_background_color(1).unwrap();
+    for (actual, expected) in [(red, 0.1), (green, 0.3), (blue, 0.5), (alpha, 0.7)] {
+        assert!((actual - expected).abs() < 1e-3);
+    }
+
+    assert!(matches!(
+        split_view.set_pane_background_color(2, 0.0, 0.0, 0.0, 1.0),
+        Err(Error::InvalidPaneIndex(2))
+    ));
+}
+
+fn pane_clipping_masks_the_layer(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    let masks = || -> bool {
+        unsafe {
+            let layer: Retained<AnyObject> = objc2::msg_send![&*panes[0], layer];
+            objc2::msg_send![&*layer, masksToBounds]
+        }
+    };
+
+    split_view.set_pane_clips_to_bounds(0, true).unwrap();
+    assert!(masks());
+    split_view.set_pane_clips_to_bounds(0, false).unwrap();
+    assert!(!masks());
+    assert!(split_view.set_pane_clips_to_bounds(2, true).is_err());
+}
+
+fn pane_tooltips_round_trip_and_clear(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    assert_eq!(split_view.pane_tooltip(1), None);
+
+    split_view.set_pane_tooltip(1, "Preview").unwrap();
+    assert_eq!(split_view.pane_tooltip(1).as_deref(), Some("Preview"));
+    split_view.set_pane_tooltip(1, "").unwrap();
+    assert_eq!(split_view.pane_tooltip(1), None);
+
+    assert!(split_view.set_pane_tooltip(2, "Missing").is_err());
+}
+
+fn frozen_panes_are_covered_by_a_snapshot(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    let pane_subviews = || panes[0].subviews().to_vec();
+
+    split_view.freeze_pane(0).unwrap();
+    // Freezing again replaces the snapshot rather than stacking another
+    split_view.freeze_pane(0).unwrap();
+    let overlays = pane_subviews();
+    assert_eq!(overlays.len(), 1);
+    assert!(overlays[0].downcast_ref::<NSImageView>().is_some());
+    assert_eq!(overlays[0].frame(), panes[0].bounds());
+
+    split_view.unfreeze_pane(0).unwrap();
+    assert!(pane_subviews().is_empty());
+    assert!(split_view.freeze_pane(2).is_err());
+}
+
+fn mirror_panes_refresh_from_their_source(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    let index = split_view.add_mirror_pane(0).unwrap();
+    assert_eq!(index, 2);
+
+    let mirror = split_view.pane_at_index(index).unwrap();
+    let image = || {
+        let image: Option<Retained<AnyObject>> = unsafe { objc2::msg_send![&*mirror, image] };
+        image.unwrap()
+    };
+    let first = image();
+
+    split_view
+        .set_pane_background_color(0, 0.0, 0.0, 1.0, 1.0)
+        .unwrap();
+    run_main_loop(0.2);
+    assert!(!Retained::ptr_eq(&image(), &first));
+
+    assert!(split_view.add_mirror_pane(3).is_err());
+}
+
+fn edge_accessories_take_a_strip_from_the_pane_content(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    let mtm = MainThreadMarker::new().unwrap();
+    let bounds = panes[0].bounds();
+    let content = BasicSplitView::make_view(mtm, bounds);
+    panes[0].addSubview(&content);
+    let header = BasicSplitView::make_view(mtm, NSRect::ZERO);
+
+    split_view
+        .set_pane_edge_accessory(0, Edge::Top, header.clone(), 30.0)
+        .unwrap();
+
+    // Panes aren't flipped, so the top strip sits at the largest y
+    let height = bounds.size.height;
+    assert_eq!(
+        header.frame(),
+        NSRect::new(
+            NSPoint::new(0.0, height - 30.0),
+            NSSize::new(bounds.size.width, 30.0)
+        )
+    );
+    assert_eq!(
+        content.frame(),
+        NSRect::new(
+            NSPoint::new(0.0, 0.0),
+            NSSize::new(bounds.size.width, height - 30.0)
+        )
+    );
+
+    split_view.clear_pane_edge_accessory(0, Edge::Top).unwrap();
+    assert_eq!(content.frame(), bounds);
+    let superview: Option<Retained<NSView>> = unsafe { header.superview() };
+    assert!(superview.is_none());
+}
+
+fn panes_outside_a_window_have_no_backing_scale(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 1);
+    // Even a layer-backed pane needs a window to know what it renders at
+    split_view
+        .set_pane_background_color(0, 1.0, 1.0, 1.0, 1.0)
+        .unwrap();
+    assert_eq!(split_view.pane_backing_scale_factor(0), None);
+    assert_eq!(split_view.pane_backing_scale_factor(1), None);
+}
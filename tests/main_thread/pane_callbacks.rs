@@ -0,0 +1,77 @@
+use std::sync::mpsc;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::MainThreadMarker;
+use objc2_app_kit::NSView;
+use objc2_foundation::{NSArray, NSRect, NSSize};
+use tauri::AppHandle;
+use tauri_nssplitview::{BasicSplitView, SplitView, SplitViewEvent};
+
+use crate::split_view_with_panes;
+
+tests![
+    pane_clicks_reach_the_latest_callback,
+    content_size_changes_reach_the_observer,
+    event_streams_report_added_panes_and_moved_dividers,
+];
+
+fn gesture_recognizers(view: &NSView) -> Vec<Retained<AnyObject>> {
+    let recognizers: Retained<NSArray<AnyObject>> =
+        unsafe { objc2::msg_send![view, gestureRecognizers] };
+    recognizers.to_vec()
+}
+
+fn pane_clicks_reach_the_latest_callback(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    let (sender, receiver) = mpsc::channel();
+    split_view.on_pane_click(1, Box::new(|_| {})).unwrap();
+    split_view
+        .on_pane_click(1, Box::new(move |_| sender.send(()).unwrap()))
+        .unwrap();
+
+    // Replacing the callback replaces the recognizer too
+    let recognizers = gesture_recognizers(&panes[1]);
+    assert_eq!(recognizers.len(), 1);
+    unsafe {
+        let target: Retained<AnyObject> = objc2::msg_send![&*recognizers[0], target];
+        let _: () = objc2::msg_send![&*target, handleClick: &*recognizers[0]];
+    }
+    assert_eq!(receiver.try_iter().count(), 1);
+
+    split_view.clear_pane_click(1).unwrap();
+    assert!(gesture_recognizers(&panes[1]).is_empty());
+}
+
+fn content_size_changes_reach_the_observer(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    let content = BasicSplitView::make_view(MainThreadMarker::new().unwrap(), NSRect::ZERO);
+    panes[0].addSubview(&content);
+
+    let (sender, receiver) = mpsc::channel();
+    split_view
+        .observe_pane_content_size(0, Box::new(move |size| sender.send(size).unwrap()))
+        .unwrap();
+    content.setFrameSize(NSSize::new(120.0, 80.0));
+    assert_eq!(receiver.try_recv(), Ok(NSSize::new(120.0, 80.0)));
+
+    assert!(split_view
+        .observe_pane_content_size(2, Box::new(|_| {}))
+        .is_err());
+}
+
+fn event_streams_report_added_panes_and_moved_dividers(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 1);
+    let events = split_view.events();
+
+    let pane = BasicSplitView::make_view(MainThreadMarker::new().unwrap(), NSRect::ZERO);
+    split_view.add_pane(&pane).unwrap();
+    split_view.set_divider_position(0, 150.0);
+
+    let events: Vec<SplitViewEvent> = events.try_iter().collect();
+    assert!(events.contains(&SplitViewEvent::PaneAdded(1)));
+    assert!(events.contains(&SplitViewEvent::DividerMoved {
+        divider_index: 0,
+        position: 150.0,
+    }));
+}
@@ -0,0 +1,71 @@
+use objc2::rc::Retained;
+use objc2::MainThreadMarker;
+use objc2_app_kit::NSView;
+use tauri::{AppHandle, WebviewUrl, WebviewWindowBuilder};
+use tauri_nssplitview::{
+    BasicSplitView, ControllerSplitView, Error, FromWindow, ManagerExt, WebviewWindowExt,
+};
+
+use crate::run_main_loop;
+
+tests![
+    rapid_replacements_show_only_the_last_view,
+    rapid_replacements_show_only_the_last_view_in_a_controller,
+    navigating_a_native_pane_fails,
+];
+
+/// Replace pane 1 three times within one turn of the run loop
+fn replace_rapidly<S: FromWindow<tauri::Wry> + 'static>(app: &AppHandle, label: &str) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let window = WebviewWindowBuilder::new(app, label, WebviewUrl::App("index.html".into()))
+        .build()
+        .unwrap();
+    let split_view = window.to_split_view::<S>().unwrap();
+    let original = NSView::new(mtm);
+    split_view.add_pane(&original).unwrap();
+
+    let views: Vec<Retained<NSView>> = (0..3).map(|_| NSView::new(mtm)).collect();
+    for view in &views {
+        split_view.replace_pane_view(1, view).unwrap();
+    }
+    // Nothing is swapped until the run loop turns
+    assert_eq!(split_view.pane_at_index(1), Some(original));
+
+    run_main_loop(0.1);
+    assert_eq!(split_view.pane_count(), 2);
+    assert_eq!(split_view.pane_at_index(1).as_ref(), views.last());
+
+    app.remove_split_view(label);
+    window.destroy().unwrap();
+}
+
+fn rapid_replacements_show_only_the_last_view(app: &AppHandle) {
+    replace_rapidly::<BasicSplitView>(app, "pane-changes");
+}
+
+fn rapid_replacements_show_only_the_last_view_in_a_controller(app: &AppHandle) {
+    replace_rapidly::<ControllerSplitView>(app, "pane-changes-controller");
+}
+
+fn navigating_a_native_pane_fails(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let window = WebviewWindowBuilder::new(
+        app,
+        "pane-changes-navigate",
+        WebviewUrl::App("index.html".into()),
+    )
+    .build()
+    .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+    split_view.add_pane(&NSView::new(mtm)).unwrap();
+
+    let url: tauri::Url = "https://tauri.app".parse().unwrap();
+    assert!(matches!(
+        split_view.navigate_pane(1, url.clone()),
+        Err(Error::NotAWebviewPane(1))
+    ));
+    assert!(split_view.navigate_pane(0, url).is_ok());
+
+    app.remove_split_view("pane-changes-navigate");
+    window.destroy().unwrap();
+}
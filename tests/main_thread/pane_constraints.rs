@@ -0,0 +1,92 @@
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{AnyThread, MainThreadMarker, MainThreadOnly};
+use objc2_app_kit::{NSImage, NSImageView, NSLayoutConstraintOrientation, NSView};
+use objc2_foundation::{NSRect, NSSize};
+use tauri::AppHandle;
+use tauri_nssplitview::{Error, PaneConstraints, SplitView};
+
+use crate::split_view_with_panes;
+
+tests![
+    apply_pane_constraints_sets_every_field,
+    ignoring_intrinsic_size_drops_priorities_along_the_split_axis,
+    recommended_widths_fit_the_content_within_the_limits,
+];
+
+fn apply_pane_constraints_sets_every_field(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    let (start, end) = split_view.divider_drag_range(0).unwrap();
+    let constraints = PaneConstraints {
+        min: Some(120.0),
+        max: Some(480.0),
+        collapsible: Some(true),
+        holding_priority: Some(260.0),
+        tag: Some("sidebar".to_string()),
+    };
+    split_view.apply_pane_constraints(1, constraints).unwrap();
+
+    // Pane 1's limits hold back the divider in front of it
+    assert_eq!(
+        split_view.divider_drag_range(0),
+        Some(((end - 480.0).max(start), end - 120.0))
+    );
+    assert_eq!(split_view.pane_index_for_tag("sidebar"), Some(1));
+    let split = split_view.as_split_view();
+    unsafe {
+        let delegate: Retained<AnyObject> = objc2::msg_send![split, delegate];
+        let can_collapse: bool = objc2::msg_send![
+            &*delegate,
+            splitView: split,
+            canCollapseSubview: &*panes[1]
+        ];
+        assert!(can_collapse);
+    }
+    assert_eq!(split.holdingPriorityForSubviewAtIndex(1), 260.0);
+}
+
+fn ignoring_intrinsic_size_drops_priorities_along_the_split_axis(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    let priorities = |view: &NSView| -> (f32, f32) {
+        let orientation = NSLayoutConstraintOrientation::Horizontal;
+        (
+            view.contentHuggingPriorityForOrientation(orientation),
+            view.contentCompressionResistancePriorityForOrientation(orientation),
+        )
+    };
+
+    split_view.set_pane_ignores_intrinsic_size(1, true).unwrap();
+    assert_eq!(priorities(&panes[1]), (1.0, 1.0));
+    assert_ne!(priorities(&panes[0]), (1.0, 1.0));
+
+    split_view
+        .set_pane_ignores_intrinsic_size(1, false)
+        .unwrap();
+    assert_eq!(priorities(&panes[1]), (250.0, 750.0));
+    assert!(matches!(
+        split_view.set_pane_ignores_intrinsic_size(2, true),
+        Err(Error::InvalidPaneIndex(2))
+    ));
+}
+
+fn recommended_widths_fit_the_content_within_the_limits(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    let mtm = MainThreadMarker::new().unwrap();
+    let content = unsafe {
+        let image: Retained<NSImage> =
+            objc2::msg_send![NSImage::alloc(), initWithSize: NSSize::new(180.0, 50.0)];
+        let content: Retained<NSImageView> =
+            objc2::msg_send![NSImageView::alloc(mtm), initWithFrame: NSRect::ZERO];
+        content.setImage(Some(&image));
+        content
+    };
+    panes[0].addSubview(&content);
+    assert_eq!(split_view.recommended_pane_width(0), Some(180.0));
+
+    split_view.set_pane_min_size(0, 200.0);
+    assert_eq!(split_view.recommended_pane_width(0), Some(200.0));
+    split_view.set_pane_min_size(0, 0.0);
+    split_view.set_pane_max_size(0, 150.0);
+    assert_eq!(split_view.recommended_pane_width(0), Some(150.0));
+    assert_eq!(split_view.recommended_pane_width(2), None);
+}
@@ -0,0 +1,128 @@
+use objc2::rc::Retained;
+use objc2::MainThreadMarker;
+use objc2_app_kit::NSView;
+use tauri::AppHandle;
+use tauri_nssplitview::{BasicSplitView, NSRect, PanePresentation, SplitView};
+
+use crate::{run_main_loop, split_view_with_panes};
+
+tests![
+    drawing_order_changes_keep_layout_and_indices,
+    overlay_pane_draws_above_inline_panes,
+    moving_and_reversing_follow_the_layout_order,
+    ensure_pane_visible_grows_reversed_panes,
+    per_pane_settings_move_with_the_pane,
+    added_panes_are_arranged_in_order,
+];
+
+fn subviews(split_view: &BasicSplitView) -> Vec<Retained<NSView>> {
+    split_view.as_split_view().subviews().to_vec()
+}
+
+fn arranged_subviews(split_view: &BasicSplitView) -> Vec<Retained<NSView>> {
+    split_view.as_split_view().arrangedSubviews().to_vec()
+}
+
+fn drawing_order_changes_keep_layout_and_indices(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 3);
+
+    split_view.bring_pane_to_front(0).unwrap();
+    assert_eq!(subviews(&split_view).last(), Some(&panes[0]));
+    split_view.send_pane_to_back(2).unwrap();
+    assert_eq!(subviews(&split_view).first(), Some(&panes[2]));
+
+    assert_eq!(arranged_subviews(&split_view), panes);
+    for (index, pane) in panes.iter().enumerate() {
+        assert_eq!(split_view.pane_at_index(index).as_ref(), Some(pane));
+    }
+}
+
+fn overlay_pane_draws_above_inline_panes(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 3);
+    let frame = panes[1].frame();
+
+    split_view
+        .set_pane_presentation(1, PanePresentation::Overlay)
+        .unwrap();
+    let neighbours = [panes[0].frame(), panes[2].frame()];
+
+    assert_eq!(subviews(&split_view).last(), Some(&panes[1]));
+    assert_eq!(
+        arranged_subviews(&split_view),
+        [panes[0].clone(), panes[2].clone()]
+    );
+    assert_eq!(split_view.pane_at_index(1).as_ref(), Some(&panes[1]));
+
+    // The neighbours share the whole width, and keep it while the overlay slides in
+    let widths = neighbours[0].size.width + neighbours[1].size.width;
+    assert_eq!(widths + split_view.divider_thickness(), 600.0);
+    run_main_loop(0.5);
+    assert_eq!(panes[1].frame(), frame);
+    assert_eq!([panes[0].frame(), panes[2].frame()], neighbours);
+}
+
+fn moving_and_reversing_follow_the_layout_order(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 3);
+    split_view.bring_pane_to_front(0).unwrap();
+
+    split_view.move_pane(0, 2).unwrap();
+    assert_eq!(split_view.pane_at_index(2).as_ref(), Some(&panes[0]));
+    assert_eq!(split_view.pane_at_index(0).as_ref(), Some(&panes[1]));
+
+    split_view.set_panes_reversed(true);
+    assert_eq!(split_view.pane_at_index(2).as_ref(), Some(&panes[0]));
+    assert_eq!(
+        arranged_subviews(&split_view),
+        [panes[0].clone(), panes[2].clone(), panes[1].clone()]
+    );
+}
+
+fn ensure_pane_visible_grows_reversed_panes(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 3);
+    split_view.set_panes_reversed(true);
+
+    // Pane 0 is arranged last while panes are reversed
+    split_view.set_divider_position(1, 560.0);
+    split_view.ensure_pane_visible(0, 200.0, false).unwrap();
+
+    let width = panes[0].frame().size.width;
+    assert!(width >= 199.0, "pane 0 is {} wide", width);
+}
+
+fn per_pane_settings_move_with_the_pane(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 3);
+    split_view.set_pane_min_size(0, 120.0);
+
+    split_view.move_pane(0, 2).unwrap();
+
+    // The minimum follows the pane to index 2, so only the divider before it is held back
+    let thickness = split_view.divider_thickness();
+    let span_end = |pane: &NSView| pane.frame().origin.x + pane.frame().size.width - thickness;
+    assert_eq!(
+        split_view.divider_drag_range(1).map(|(_, end)| end),
+        Some(span_end(&panes[0]) - 120.0)
+    );
+    assert_eq!(
+        split_view.divider_drag_range(0),
+        Some((panes[1].frame().origin.x, span_end(&panes[2])))
+    );
+}
+
+fn added_panes_are_arranged_in_order(app: &AppHandle) {
+    let (split_view, mut panes) = split_view_with_panes(app, 1);
+    let mtm = MainThreadMarker::new().unwrap();
+
+    let batch = vec![
+        BasicSplitView::make_view(mtm, NSRect::ZERO),
+        BasicSplitView::make_view(mtm, NSRect::ZERO),
+    ];
+    assert_eq!(split_view.add_panes(batch.clone()).unwrap(), [1, 2]);
+    panes.extend(batch);
+
+    let pane = BasicSplitView::make_view(mtm, NSRect::ZERO);
+    assert_eq!(split_view.add_pane(&pane).unwrap(), 3);
+    panes.push(pane);
+
+    assert_eq!(arranged_subviews(&split_view), panes);
+    assert_eq!(split_view.pane_count(), 4);
+}
@@ -0,0 +1,46 @@
+use objc2::rc::Retained;
+use objc2::MainThreadMarker;
+use objc2_app_kit::NSView;
+use tauri::{AppHandle, WebviewUrl, WebviewWindowBuilder};
+use tauri_nssplitview::{BasicSplitView, ManagerExt, WebviewWindowExt};
+
+tests![tags_follow_their_pane];
+
+fn tags_follow_their_pane(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let window = WebviewWindowBuilder::new(app, "pane-tags", WebviewUrl::App("index.html".into()))
+        .build()
+        .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+    let panes: Vec<Retained<NSView>> = (0..2).map(|_| NSView::new(mtm)).collect();
+    split_view.add_panes(panes.clone()).unwrap();
+    split_view.set_pane_tag(1, "sidebar").unwrap();
+
+    // Move the tagged pane to the end
+    unsafe {
+        let _: () = objc2::msg_send![&*panes[0], removeFromSuperview];
+        let _: () = objc2::msg_send![split_view.as_split_view(), addSubview: &*panes[0]];
+    }
+
+    assert_eq!(split_view.pane_index_for_tag("sidebar"), Some(2));
+    assert_eq!(
+        split_view.pane_view_for_tag("sidebar").as_ref(),
+        Some(&panes[0])
+    );
+    assert_eq!(split_view.pane_view_for_tag("inspector"), None);
+
+    let mut hidden = None;
+    split_view.with_pane_for_tag("sidebar", |view| hidden = Some(view.isHidden()));
+    assert_eq!(hidden, Some(false));
+
+    // Tagging another pane moves the tag
+    split_view.set_pane_tag(1, "sidebar").unwrap();
+    assert_eq!(
+        split_view.pane_view_for_tag("sidebar").as_ref(),
+        Some(&panes[1])
+    );
+    assert_eq!(split_view.pane_index_for_tag("sidebar"), Some(1));
+
+    app.remove_split_view("pane-tags");
+    window.destroy().unwrap();
+}
@@ -0,0 +1,60 @@
+use objc2_app_kit::NSUserInterfaceItemIdentification;
+use tauri::{AppHandle, WebviewUrl, WebviewWindowBuilder};
+use tauri_nssplitview::{
+    BasicSplitView, ManagerExt, PaneConfig, SplitView, SplitViewOrientation, WebviewWindowExt,
+};
+
+tests![splitting_a_webview_pane_keeps_its_webview];
+
+fn splitting_a_webview_pane_keeps_its_webview(app: &AppHandle) {
+    let window = WebviewWindowBuilder::new(app, "split-pane", WebviewUrl::App("index.html".into()))
+        .build()
+        .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+    let webview_pane = split_view.pane_at_index(0).unwrap();
+
+    let nested = split_view
+        .split_pane_preserving_webview(
+            0,
+            SplitViewOrientation::Horizontal,
+            PaneConfig::Native {
+                identifier: "details".into(),
+            },
+        )
+        .unwrap();
+
+    // The nested split view takes the pane's place and holds the same webview view
+    assert_eq!(split_view.pane_count(), 1);
+    assert_eq!(
+        &*split_view.pane_at_index(0).unwrap(),
+        &**nested.as_split_view()
+    );
+    assert!(!nested.is_vertical());
+    assert_eq!(nested.pane_count(), 2);
+    assert_eq!(nested.pane_at_index(0).unwrap(), webview_pane);
+    let details = nested.pane_at_index(1).unwrap();
+    assert_eq!(details.identifier().unwrap().to_string(), "details");
+
+    // The webview is still registered under its window's label
+    let webview_panes = nested.webview_panes();
+    assert_eq!(webview_panes.len(), 1);
+    assert_eq!(webview_panes[0].0, 0);
+    assert_eq!(webview_panes[0].1.label(), "split-pane");
+    assert!(split_view.webview_panes().is_empty());
+    assert!(app.get_split_view(nested.label()).is_ok());
+
+    // Only webview panes can be split this way
+    assert!(split_view
+        .split_pane_preserving_webview(
+            0,
+            SplitViewOrientation::Vertical,
+            PaneConfig::Native {
+                identifier: "more".into(),
+            },
+        )
+        .is_err());
+
+    app.remove_split_view(nested.label());
+    app.remove_split_view("split-pane");
+    window.destroy().unwrap();
+}
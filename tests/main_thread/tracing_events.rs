@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use tauri::AppHandle;
+use tauri_nssplitview::SplitView;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+use crate::split_view_with_panes;
+
+tests![set_divider_position_emits_its_fields];
+
+/// A layer recording the fields of every event, rendered as strings
+#[derive(Clone, Default)]
+struct RecordedEvents(Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+struct Fields<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for Fields<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+impl<S: Subscriber> Layer<S> for RecordedEvents {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut fields = HashMap::new();
+        event.record(&mut Fields(&mut fields));
+        self.0.lock().unwrap().push(fields);
+    }
+}
+
+fn set_divider_position_emits_its_fields(app: &AppHandle) {
+    let (split_view, _) = split_view_with_panes(app, 2);
+    let recorded = RecordedEvents::default();
+    let subscriber = tracing_subscriber::registry().with(recorded.clone());
+
+    tracing::subscriber::with_default(subscriber, || {
+        split_view.set_divider_position(0, 150.0);
+    });
+
+    let events = recorded.0.lock().unwrap();
+    let event = events
+        .iter()
+        .find(|fields| fields.get("operation").map(String::as_str) == Some("set_divider_position"))
+        .unwrap();
+    assert_eq!(event["label"], "test");
+    assert_eq!(event["divider_index"], "0");
+    assert_eq!(event["position"], "Points(150.0)");
+}
@@ -0,0 +1,155 @@
+use std::sync::mpsc;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{ClassType, MainThreadMarker};
+use objc2_app_kit::{
+    NSEvent, NSEventModifierFlags, NSEventType, NSTrackingArea, NSTrackingAreaOptions, NSView,
+};
+use objc2_foundation::NSPoint;
+use tauri::{AppHandle, LogicalSize, WebviewUrl, WebviewWindowBuilder};
+use tauri_nssplitview::{BasicSplitView, ManagerExt, ResizeMode, SplitView, WebviewWindowExt};
+
+use crate::{run_main_loop, split_view_with_panes};
+
+tests![
+    divider_areas_follow_the_dividers_after_a_resize,
+    clearing_tracking_areas_removes_hover_areas,
+    hover_callback_reports_enter_and_exit,
+    hover_tracking_areas_stay_with_their_pane,
+];
+
+/// Get the divider cursor areas installed on the split view
+fn divider_areas(split_view: &BasicSplitView) -> Vec<Retained<NSTrackingArea>> {
+    split_view
+        .as_split_view()
+        .trackingAreas()
+        .iter()
+        .filter(|area| area.options().contains(NSTrackingAreaOptions::CursorUpdate))
+        .collect()
+}
+
+/// Check there is one area per divider, starting at that divider's position
+fn assert_areas_match_dividers(split_view: &BasicSplitView) {
+    let areas = divider_areas(split_view);
+    let dividers = split_view.divider_info();
+    assert_eq!(areas.len(), dividers.len());
+    for (area, divider) in areas.iter().zip(&dividers) {
+        assert_eq!(area.rect().origin.x, divider.position_points);
+        assert_eq!(area.rect().size.width, divider.thickness);
+    }
+}
+
+fn divider_areas_follow_the_dividers_after_a_resize(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let window =
+        WebviewWindowBuilder::new(app, "tracking-areas", WebviewUrl::App("index.html".into()))
+            .inner_size(600.0, 400.0)
+            .build()
+            .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+    split_view
+        .add_panes(vec![NSView::new(mtm), NSView::new(mtm)])
+        .unwrap();
+    // Proportional resizing installs the crate's delegate and moves every divider on resize
+    split_view.set_resize_mode(ResizeMode::Proportional);
+    split_view.set_divider_position(0, 150.0);
+    split_view.set_divider_position(1, 400.0);
+    assert_areas_match_dividers(&split_view);
+
+    window.set_size(LogicalSize::new(900.0, 500.0)).unwrap();
+    run_main_loop(0.1);
+    assert_ne!(split_view.get_divider_position(0), 150.0);
+    assert_areas_match_dividers(&split_view);
+
+    split_view.clear_tracking_areas();
+    assert!(divider_areas(&split_view).is_empty());
+
+    app.remove_split_view("tracking-areas");
+    window.destroy().unwrap();
+}
+
+fn clearing_tracking_areas_removes_hover_areas(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let window =
+        WebviewWindowBuilder::new(app, "hover-areas", WebviewUrl::App("index.html".into()))
+            .build()
+            .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+    split_view
+        .add_panes(vec![NSView::new(mtm), NSView::new(mtm)])
+        .unwrap();
+    split_view.on_pane_hover(1, Box::new(|_| {})).unwrap();
+    let hover_areas = |view: &NSView| {
+        view.trackingAreas()
+            .iter()
+            .filter(|area| {
+                area.options()
+                    .contains(NSTrackingAreaOptions::MouseEnteredAndExited)
+            })
+            .count()
+    };
+    let pane = split_view.pane_at_index(1).unwrap();
+    assert_eq!(hover_areas(&pane), 1);
+
+    split_view.clear_tracking_areas();
+    assert_eq!(hover_areas(&pane), 0);
+
+    app.remove_split_view("hover-areas");
+    window.destroy().unwrap();
+}
+
+/// Send the owner of `area` the event AppKit sends when the mouse enters or exits it
+fn send_enter_exit(area: &NSTrackingArea, entered: bool) {
+    unsafe {
+        let event_type = if entered {
+            NSEventType::MouseEntered
+        } else {
+            NSEventType::MouseExited
+        };
+        let event: Option<Retained<NSEvent>> = objc2::msg_send![
+            NSEvent::class(),
+            enterExitEventWithType: event_type,
+            location: NSPoint::new(0.0, 0.0),
+            modifierFlags: NSEventModifierFlags::empty(),
+            timestamp: 0.0f64,
+            windowNumber: 0isize,
+            context: Option::<&AnyObject>::None,
+            eventNumber: 0isize,
+            trackingNumber: 0isize,
+            userData: std::ptr::null_mut::<std::ffi::c_void>()
+        ];
+        let event = event.unwrap();
+        let owner: Retained<AnyObject> = objc2::msg_send![area, owner];
+        if entered {
+            let _: () = objc2::msg_send![&*owner, mouseEntered: &*event];
+        } else {
+            let _: () = objc2::msg_send![&*owner, mouseExited: &*event];
+        }
+    }
+}
+
+fn hover_callback_reports_enter_and_exit(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    let (sender, receiver) = mpsc::channel();
+    split_view
+        .on_pane_hover(1, Box::new(move |hovered| sender.send(hovered).unwrap()))
+        .unwrap();
+
+    let areas = panes[1].trackingAreas();
+    assert_eq!(areas.len(), 1);
+    send_enter_exit(&areas.objectAtIndex(0), true);
+    send_enter_exit(&areas.objectAtIndex(0), false);
+    assert_eq!(receiver.try_iter().collect::<Vec<_>>(), [true, false]);
+}
+
+fn hover_tracking_areas_stay_with_their_pane(app: &AppHandle) {
+    let (split_view, panes) = split_view_with_panes(app, 2);
+    split_view.on_pane_hover(0, Box::new(|_| {})).unwrap();
+    split_view.move_pane(0, 1).unwrap();
+
+    // Index 0 is now the other pane, so the moved pane keeps its area
+    split_view.on_pane_hover(0, Box::new(|_| {})).unwrap();
+    assert_eq!(panes[0].trackingAreas().len(), 1);
+    assert_eq!(panes[1].trackingAreas().len(), 1);
+}
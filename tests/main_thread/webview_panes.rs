@@ -0,0 +1,90 @@
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+use tauri_nssplitview::{BasicSplitView, ManagerExt, WebviewWindowExt};
+
+tests![
+    webview_panes_are_listed_with_their_index,
+    webview_pane_labels_skip_labels_in_use,
+];
+
+fn webview_panes_are_listed_with_their_index(app: &AppHandle) {
+    let window =
+        WebviewWindowBuilder::new(app, "webview-panes", WebviewUrl::App("index.html".into()))
+            .build()
+            .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+
+    let left = split_view
+        .add_webview_pane(WebviewUrl::App("left.html".into()))
+        .unwrap();
+    let right = split_view
+        .add_webview_pane(WebviewUrl::App("right.html".into()))
+        .unwrap();
+    assert_eq!((left, right), (1, 2));
+
+    let webview_panes: Vec<(usize, String)> = split_view
+        .webview_panes()
+        .into_iter()
+        .map(|(index, webview)| (index, webview.label().to_string()))
+        .collect();
+    assert_eq!(
+        webview_panes,
+        [
+            (0, "webview-panes".to_string()),
+            (1, "webview-panes-pane-1".to_string()),
+            (2, "webview-panes-pane-2".to_string()),
+        ]
+    );
+
+    // The webviews stay registered with the app under their labels
+    assert!(app.get_webview_window("webview-panes-pane-1").is_some());
+    split_view.reload_all_webview_panes();
+
+    app.remove_split_view("webview-panes");
+    for (_, webview) in split_view.webview_panes() {
+        webview.destroy().unwrap();
+    }
+}
+
+fn webview_pane_labels_skip_labels_in_use(app: &AppHandle) {
+    let window =
+        WebviewWindowBuilder::new(app, "pane-labels", WebviewUrl::App("index.html".into()))
+            .build()
+            .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+    // Take the label the first added pane would get
+    let taken = WebviewWindowBuilder::new(
+        app,
+        "pane-labels-pane-1",
+        WebviewUrl::App("index.html".into()),
+    )
+    .visible(false)
+    .build()
+    .unwrap();
+
+    let first = split_view
+        .add_webview_pane(WebviewUrl::App("first.html".into()))
+        .unwrap();
+    let second = split_view
+        .add_webview_pane(WebviewUrl::App("second.html".into()))
+        .unwrap();
+
+    assert_eq!(
+        split_view.webview_pane_label(first).as_deref(),
+        Some("pane-labels-pane-2")
+    );
+    assert_eq!(
+        split_view.webview_pane_label(second).as_deref(),
+        Some("pane-labels-pane-3")
+    );
+    assert_eq!(
+        split_view.webview_pane_label(0).as_deref(),
+        Some("pane-labels")
+    );
+    assert_eq!(split_view.webview_pane_label(3), None);
+
+    taken.destroy().unwrap();
+    app.remove_split_view("pane-labels");
+    for (_, webview) in split_view.webview_panes() {
+        webview.destroy().unwrap();
+    }
+}
@@ -0,0 +1,252 @@
+use std::sync::mpsc;
+
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::{define_class, MainThreadMarker, MainThreadOnly};
+use objc2_app_kit::{
+    NSTextField, NSView, NSWindowCollectionBehavior, NSWindowDelegate, NSWindowStyleMask,
+};
+use objc2_foundation::{NSObject, NSObjectProtocol, NSSize, NSString};
+use tauri::{AppHandle, Listener, Manager, WebviewUrl, WebviewWindowBuilder};
+use tauri_nssplitview::{BasicSplitView, ManagerExt, WebviewWindowExt};
+
+use crate::{run_main_loop, window_split_view_with_panes};
+
+tests![
+    app_handles_find_the_split_view_window,
+    pane_aspect_ratios_constrain_the_window,
+    only_the_focused_pane_reports_focus,
+    traffic_light_insets_need_a_titled_window,
+    close_guards_veto_closing_the_window,
+    zooming_toggles_the_window_frame,
+    fitted_windows_hold_every_pane_side_by_side,
+    removing_the_event_handler_clears_a_delegate_added_to_none,
+    collection_behaviors_reach_the_window,
+    resizes_emit_coalesced_pane_geometry_events,
+    full_height_content_spans_the_title_bar,
+];
+
+fn app_handles_find_the_split_view_window(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "app-handle", 1);
+
+    let found = split_view
+        .app_handle()
+        .get_webview_window(split_view.label())
+        .unwrap();
+    assert_eq!(found.label(), window.label());
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn pane_aspect_ratios_constrain_the_window(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "aspect-ratio", 2);
+    split_view.set_window_aspect_ratio_from_panes(true);
+
+    let ratio = split_view.window().unwrap().contentAspectRatio();
+    assert!(ratio.width > 0.0 && ratio.height > 0.0);
+    let bounds = split_view.as_split_view().bounds().size;
+    assert!((ratio.width / ratio.height - bounds.width / bounds.height).abs() < 1e-9);
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn only_the_focused_pane_reports_focus(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let (window, split_view, panes) = window_split_view_with_panes(app, "pane-focus", 2);
+    panes[1].addSubview(&NSTextField::textFieldWithString(&NSString::new(), mtm));
+
+    split_view.focus_pane(1).unwrap();
+    assert!(split_view.is_pane_focused(1));
+    assert!(!split_view.is_pane_focused(0));
+    assert!(!split_view.is_pane_focused(2));
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn traffic_light_insets_need_a_titled_window(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "traffic-lights", 1);
+    assert!(split_view.traffic_light_inset() > 0.0);
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+
+    let window = WebviewWindowBuilder::new(app, "borderless", WebviewUrl::App("index.html".into()))
+        .decorations(false)
+        .build()
+        .unwrap();
+    let split_view = window.to_split_view::<BasicSplitView>().unwrap();
+    assert_eq!(split_view.traffic_light_inset(), 0.0);
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn close_guards_veto_closing_the_window(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "close-guard", 1);
+    let (sender, receiver) = mpsc::channel();
+    split_view.set_close_guard(Box::new(move |split_view| {
+        sender.send(split_view.label().to_string()).unwrap();
+        false
+    }));
+
+    let ns_window = split_view.window().unwrap();
+    let delegate = ns_window.delegate().unwrap();
+    let should_close: bool =
+        unsafe { objc2::msg_send![&*delegate, windowShouldClose: &*ns_window] };
+    assert!(!should_close);
+    assert_eq!(receiver.try_recv().as_deref(), Ok("close-guard"));
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn zooming_toggles_the_window_frame(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "zoom", 2);
+    let zoomed = split_view.is_zoomed();
+
+    split_view.zoom();
+    run_main_loop(0.5);
+    assert_eq!(split_view.is_zoomed(), !zoomed);
+
+    split_view.zoom();
+    run_main_loop(0.5);
+    assert_eq!(split_view.is_zoomed(), zoomed);
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn fitted_windows_hold_every_pane_side_by_side(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let (window, split_view, panes) = window_split_view_with_panes(app, "fit-panes", 3);
+
+    // The last pane's content needs 250 points, the others fit their current frames
+    let content = NSView::new(mtm);
+    content.setTranslatesAutoresizingMaskIntoConstraints(false);
+    panes[2].addSubview(&content);
+    for constraint in [
+        content.widthAnchor().constraintEqualToConstant(250.0),
+        content
+            .leadingAnchor()
+            .constraintEqualToAnchor(&panes[2].leadingAnchor()),
+        content
+            .trailingAnchor()
+            .constraintEqualToAnchor(&panes[2].trailingAnchor()),
+    ] {
+        constraint.setActive(true);
+    }
+    let expected = panes[0].frame().size.width
+        + panes[1].frame().size.width
+        + 250.0
+        + 2.0 * split_view.divider_thickness();
+
+    split_view.size_window_to_fit_panes(300.0);
+    let content_view = split_view.window().unwrap().contentView().unwrap();
+    assert_eq!(content_view.frame().size, NSSize::new(expected, 300.0));
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+define_class!(
+    /// A window delegate that handles nothing
+    #[unsafe(super(NSObject))]
+    #[name = "TestWindowDelegate"]
+    #[thread_kind = MainThreadOnly]
+    struct TestWindowDelegate;
+
+    unsafe impl NSObjectProtocol for TestWindowDelegate {}
+
+    unsafe impl NSWindowDelegate for TestWindowDelegate {}
+);
+
+fn removing_the_event_handler_clears_a_delegate_added_to_none(app: &AppHandle) {
+    let mtm = MainThreadMarker::new().unwrap();
+    let (window, split_view, _) = window_split_view_with_panes(app, "event-handler", 1);
+    let ns_window = split_view.window().unwrap();
+    let tauri_delegate = ns_window.delegate();
+
+    // Start from a window without a delegate
+    ns_window.setDelegate(None);
+    let handler: Retained<TestWindowDelegate> =
+        unsafe { objc2::msg_send![TestWindowDelegate::alloc(mtm), init] };
+    split_view.set_event_handler(Some(ProtocolObject::from_ref(&*handler)));
+    assert!(ns_window.delegate().is_some());
+
+    split_view.set_event_handler(None);
+    assert!(ns_window.delegate().is_none());
+
+    ns_window.setDelegate(tauri_delegate.as_deref());
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn collection_behaviors_reach_the_window(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "collection-behavior", 1);
+    let ns_window = split_view.window().unwrap();
+
+    let behavior =
+        NSWindowCollectionBehavior::Managed | NSWindowCollectionBehavior::FullScreenPrimary;
+    split_view.set_window_collection_behavior(behavior);
+    assert_eq!(ns_window.collectionBehavior(), behavior);
+    assert_eq!(split_view.window_collection_behavior(), behavior);
+
+    split_view.set_can_join_all_spaces(true);
+    let behavior = ns_window.collectionBehavior();
+    assert!(behavior.contains(NSWindowCollectionBehavior::CanJoinAllSpaces));
+    assert!(!behavior.contains(NSWindowCollectionBehavior::MoveToActiveSpace));
+
+    split_view.set_full_screen_allows_tiling(false);
+    let behavior = ns_window.collectionBehavior();
+    assert!(behavior.contains(NSWindowCollectionBehavior::FullScreenDisallowsTiling));
+    assert!(!behavior.contains(NSWindowCollectionBehavior::FullScreenAllowsTiling));
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn resizes_emit_coalesced_pane_geometry_events(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "pane-geometry", 3);
+    let (sender, receiver) = mpsc::channel();
+    let listener = app.listen("pane-geometry", move |event| {
+        sender.send(event.payload().to_string()).unwrap();
+    });
+
+    split_view.emit_pane_geometry_events("pane-geometry");
+    run_main_loop(0.1);
+    assert_eq!(receiver.try_iter().count(), 1);
+
+    // Several layout passes within one turn of the run loop report once
+    for position in [150.0, 200.0, 250.0] {
+        split_view.set_divider_position(0, position);
+    }
+    run_main_loop(0.1);
+    let payloads: Vec<String> = receiver.try_iter().collect();
+    assert_eq!(payloads.len(), 1);
+    let panes: Vec<serde_json::Value> = serde_json::from_str(&payloads[0]).unwrap();
+    assert_eq!(panes.len(), 3);
+    assert_eq!(panes[0]["windowFrame"][2], 250.0);
+
+    app.unlisten(listener);
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}
+
+fn full_height_content_spans_the_title_bar(app: &AppHandle) {
+    let (window, split_view, _) = window_split_view_with_panes(app, "full-height", 2);
+    let ns_window = split_view.window().unwrap();
+    let split_height = || split_view.as_split_view().frame().size.height;
+
+    split_view.set_full_height_content(true);
+    assert!(ns_window
+        .styleMask()
+        .contains(NSWindowStyleMask::FullSizeContentView));
+    assert_eq!(split_height(), ns_window.frame().size.height);
+
+    split_view.set_full_height_content(false);
+    assert!(split_height() < ns_window.frame().size.height);
+
+    app.remove_split_view(split_view.label());
+    window.destroy().unwrap();
+}